@@ -99,15 +99,8 @@ fn TransformAnimation() -> Element {
         }
     });
 
-    let transform_style = use_memo(move || {
-        format!(
-            "transform: translate({}px, {}px) scale({}) rotate({}deg);",
-            transform.get_value().x,
-            transform.get_value().y,
-            transform.get_value().scale,
-            transform.get_value().rotation * 180.0 / std::f32::consts::PI
-        )
-    });
+    let transform_style =
+        use_memo(move || format!("transform: {};", transform.get_value().to_css()));
 
     rsx! {
         div { class: "space-y-4 w-full text-center",
@@ -412,15 +405,8 @@ fn TransformAnimation() -> Element {
         }
     });
 
-    let transform_style = use_memo(move || {
-        format!(
-            "transform: translate({}px, {}px) scale({}) rotate({}deg);",
-            transform.get_value().x,
-            transform.get_value().y,
-            transform.get_value().scale,
-            transform.get_value().rotation * 180.0 / std::f32::consts::PI
-        )
-    });
+    let transform_style =
+        use_memo(move || format!("transform: {};", transform.get_value().to_css()));
 
     rsx! {
         div { class: "space-y-4 w-full text-center",
@@ -10,32 +10,17 @@ use easer::functions::Easing;
 
 const MAIN_CSS: Asset = asset!("/assets/main.css");
 
-fn route_index(route: &docs::utils::router::Route) -> i32 {
+fn route_index(route: &docs::utils::router::Route) -> Option<i32> {
     match route {
-        docs::utils::router::Route::Home { .. } => 0,
-        docs::utils::router::Route::DocsLanding { .. } => 1,
-        docs::utils::router::Route::ShowcaseGallery { .. } => 2,
-        _ => -1,
+        docs::utils::router::Route::Home { .. } => Some(0),
+        docs::utils::router::Route::DocsLanding { .. } => Some(1),
+        docs::utils::router::Route::ShowcaseGallery { .. } => Some(2),
+        _ => None,
     }
 }
 
 fn transition_resolver() -> TransitionVariantResolver<docs::utils::router::Route> {
-    std::rc::Rc::new(|from, to| {
-        let from_idx = route_index(from);
-        let to_idx = route_index(to);
-
-        if from_idx != -1 && to_idx != -1 {
-            if to_idx > from_idx {
-                TransitionVariant::SlideLeft
-            } else if to_idx < from_idx {
-                TransitionVariant::SlideRight
-            } else {
-                TransitionVariant::Fade
-            }
-        } else {
-            to.get_transition()
-        }
-    })
+    transition_resolver_by_depth(route_index)
 }
 
 #[component]
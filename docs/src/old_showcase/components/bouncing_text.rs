@@ -9,12 +9,7 @@ fn BouncingLetter(letter: char, delay: f32) -> Element {
     use_effect(move || {
         let delay = Duration::from_secs_f32(delay);
         transform.animate_to(
-            Transform {
-                y: -30.0,
-                scale: 1.5,
-                rotation: 5.0 * (std::f32::consts::PI / 180.0),
-                x: 0.0,
-            },
+            Transform::new(0.0, -30.0, 1.5, 5.0 * (std::f32::consts::PI / 180.0)),
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs(1),
                 easing: easer::functions::Sine::ease_in_out,
@@ -19,12 +19,7 @@ pub fn Card3DFlip() -> Element {
             );
         } else {
             transform.animate_to(
-                Transform {
-                    rotation: 180.0,
-                    scale: 1.0,
-                    x: 0.0,
-                    y: 0.0,
-                },
+                Transform::identity().rotate_y(std::f32::consts::PI),
                 AnimationConfig::new(AnimationMode::Spring(Spring {
                     stiffness: 200.0, // Increased for snappier response
                     damping: 20.0,    // Increased for less oscillation
@@ -40,9 +35,7 @@ pub fn Card3DFlip() -> Element {
         div { class: "perspective-1000",
             div {
                 class: "relative w-64 h-64 cursor-pointer",
-                style: "transform-style: preserve-3d;
-                        transform: rotateY({transform.get_value().rotation}deg)
-                                 scale({transform.get_value().scale});",
+                style: "transform-style: preserve-3d; transform: {transform.get_value().to_css()};",
                 onclick: animate_flip,
 
                 // Front
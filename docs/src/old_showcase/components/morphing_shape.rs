@@ -71,12 +71,7 @@ pub fn MorphingShape(shapes: Vec<&'static str>, duration: f32) -> Element {
     use_effect(move || {
         // Main rotation and scale animation
         transform.animate_to(
-            Transform {
-                rotation: 360.0,
-                scale: 1.2,
-                x: 0.0,
-                y: 0.0,
-            },
+            Transform::new(0.0, 0.0, 1.2, 360.0),
             AnimationConfig::new(AnimationMode::Spring(Spring {
                 stiffness: 35.0, // Reduced for more fluid motion
                 damping: 5.0,    // Lower damping for organic movement
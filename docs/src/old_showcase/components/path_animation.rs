@@ -4,18 +4,15 @@ use easer::functions::Easing;
 
 #[component]
 pub fn PathAnimation(path: &'static str, duration: f32) -> Element {
-    let mut dash_offset = use_motion(1000.0f32);
-
-    use_effect(move || {
-        dash_offset.animate_to(
-            0.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_secs_f32(duration),
-                easing: easer::functions::Cubic::ease_in_out,
-            }))
-            .with_loop(LoopMode::Infinite),
-        );
-    });
+    let draw = PathDraw::new(1000.0);
+    let dash_offset = use_path_draw(
+        draw.total_length(),
+        AnimationConfig::new(AnimationMode::Tween(Tween {
+            duration: Duration::from_secs_f32(duration),
+            easing: easer::functions::Cubic::ease_in_out,
+        }))
+        .with_loop(LoopMode::Infinite),
+    );
 
     rsx! {
         div { class: "w-full h-48 flex items-center justify-center rounded-xl",
@@ -25,8 +22,7 @@ pub fn PathAnimation(path: &'static str, duration: f32) -> Element {
                     fill: "none",
                     stroke: "url(#gradient)",
                     stroke_width: "4",
-                    stroke_dasharray: "1000",
-                    style: "stroke-dashoffset: {dash_offset.get_value()};
+                    style: "{draw.style(dash_offset.get_value())}
                             transition: stroke-dashoffset 0.1s linear;",
                 }
                 defs {
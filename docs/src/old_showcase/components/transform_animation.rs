@@ -36,11 +36,8 @@ pub fn TransformAnimationShowcase() -> Element {
 
     let transform_style = use_memo(move || {
         format!(
-            "transform: translate({}px, {}px) scale({}) rotate({}deg); transform-style: preserve-3d; will-change: transform;",
-            transform.get_value().x,
-            transform.get_value().y,
-            transform.get_value().scale,
-            transform.get_value().rotation * 180.0 / std::f32::consts::PI
+            "transform: {}; transform-style: preserve-3d; will-change: transform;",
+            transform.get_value().to_css()
         )
     });
 
@@ -4,14 +4,14 @@ use easer::functions::Easing;
 
 #[component]
 pub fn TypewriterEffect(text: &'static str) -> Element {
-    let mut char_count = use_motion(0.0f32);
+    let mut typed = use_motion(TypedText::new(text));
     let mut cursor_opacity = use_motion(1.0f32);
-    let text_len = text.len() as f32;
+    let text_len = text.chars().count() as f32;
 
     use_effect(move || {
         // Start typing animation
-        char_count.animate_to(
-            text_len,
+        typed.animate_to(
+            TypedText::new(text).revealed(),
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs_f32(text_len * 0.1), // 0.1s per character
                 easing: easer::functions::Linear::ease_in_out,
@@ -30,10 +30,8 @@ pub fn TypewriterEffect(text: &'static str) -> Element {
         );
     });
 
-    let visible_text = text
-        .chars()
-        .take(char_count.get_value() as usize)
-        .collect::<String>();
+    let typed_text = typed.get_value();
+    let visible_text = typed_text.visible();
 
     rsx! {
         div { class: "relative font-mono text-2xl text-blue-500",
@@ -1,20 +1,38 @@
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
-use syn::{Attribute, Data, DataEnum, DeriveInput, Fields, Meta, parse_macro_input};
+use quote::quote;
+use syn::{Attribute, Data, DataEnum, DeriveInput, Fields, Meta, Path, parse_macro_input};
 
-fn get_transition_from_attrs(attrs: &[Attribute]) -> Option<String> {
+fn get_transition_from_attrs(attrs: &[Attribute]) -> Option<proc_macro2::TokenStream> {
     attrs
         .iter()
         .find(|attr| attr.path().is_ident("transition"))
         .and_then(|attr| {
             if let Ok(Meta::Path(path)) = attr.parse_args::<Meta>() {
-                path.get_ident().map(|ident| ident.to_string())
+                Some(transition_from_path(&path))
             } else {
                 None
             }
         })
 }
 
+// `#[transition(Fade)]` names a built-in variant and `#[transition(DROP_IN)]` or
+// `#[transition(transitions::DropIn)]` a constant, typically a
+// `TransitionVariant::Custom`. A bare name is resolved with the variants glob-imported,
+// so it is a variant if one has that name and otherwise whatever constant is in scope,
+// however the constant is cased.
+fn transition_from_path(path: &Path) -> proc_macro2::TokenStream {
+    match path.get_ident() {
+        Some(ident) => quote! {
+            {
+                #[allow(unused_imports)]
+                use TransitionVariant::*;
+                #ident
+            }
+        },
+        None => quote! { #path },
+    }
+}
+
 // Helper to extract layout nesting information from enum variants
 fn get_layout_depth(variants: &[&syn::Variant]) -> Vec<(syn::Ident, usize)> {
     let mut layout_depth = Vec::new();
@@ -81,8 +99,7 @@ pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
     let transition_match_arms = variants.iter().map(|variant| {
         let variant_ident = &variant.ident;
         let transition = get_transition_from_attrs(&variant.attrs)
-            .map(|t| format_ident!("{}", t))
-            .unwrap_or(format_ident!("Fade"));
+            .unwrap_or_else(|| quote! { TransitionVariant::Fade });
 
         match &variant.fields {
             Fields::Named(fields) => {
@@ -91,14 +108,14 @@ pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
                     quote! { #name: _ }
                 });
                 quote! {
-                    Self::#variant_ident { #(#field_patterns,)* } => TransitionVariant::#transition
+                    Self::#variant_ident { #(#field_patterns,)* } => #transition
                 }
             }
             Fields::Unnamed(_) => {
-                quote! { Self::#variant_ident(..) => TransitionVariant::#transition }
+                quote! { Self::#variant_ident(..) => #transition }
             }
             Fields::Unit => {
-                quote! { Self::#variant_ident {} => TransitionVariant::#transition }
+                quote! { Self::#variant_ident {} => #transition }
             }
         }
     });
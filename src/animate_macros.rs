@@ -0,0 +1,184 @@
+/// Starts an animation on a motion with an inline spring or tween config.
+///
+/// Expands to `animate_to(target, config)` on the handle, so the handle must be bound
+/// mutably, just like a direct call:
+///
+/// - `spring(field: value, ...)` sets any of `stiffness`, `damping`, `mass` and
+///   `velocity`, keeping the [`Spring`](crate::animations::spring::Spring) defaults for
+///   the rest; `spring()` is the default spring.
+/// - `tween(300 ms)` or `tween(300 ms, easing)` builds a tween with an optional easing
+///   function; any [`Duration`](crate::Duration) expression works in place of `300 ms`.
+/// - A trailing `.loop(infinite)`, `.loop(alternate)`, `.loop(times(n))` or
+///   `.loop(alternate_times(n))` sets the [`LoopMode`](crate::animations::core::LoopMode).
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "dioxus")] {
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+/// use easer::functions::{Cubic, Easing};
+///
+/// fn app() -> Element {
+///     let mut x = use_motion(0.0f32);
+///     let mut glow = use_motion(Color::from_rgba(0, 0, 0, 255));
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 animate!(x => 100.0, spring(stiffness: 100.0, damping: 10.0));
+///                 animate!(
+///                     glow => Color::from_rgba(255, 0, 0, 255),
+///                     tween(300 ms, Cubic::ease_in_out).loop(alternate)
+///                 );
+///             },
+///             "Go"
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! animate {
+    ($handle:expr => $target:expr, $kind:ident ( $($config:tt)* ) $(.loop( $($loop_mode:tt)+ ))? $(,)?) => {
+        $handle.animate_to(
+            $target,
+            $crate::animate_config!($kind ( $($config)* ) $(.loop( $($loop_mode)+ ))?),
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! animate_config {
+    ($kind:ident ( $($config:tt)* ) .loop( $($loop_mode:tt)+ )) => {
+        $crate::animate_config!($kind ( $($config)* ))
+            .with_loop($crate::animate_loop!($($loop_mode)+))
+    };
+    (spring ( $($field:ident : $value:expr),* $(,)? )) => {{
+        #[allow(unused_mut)]
+        let mut spring = $crate::animations::spring::Spring::default();
+        $(
+            $crate::animate_spring_assign!(spring, $field, $value);
+        )*
+        $crate::animations::core::AnimationConfig::spring(spring)
+    }};
+    (tween ( $milliseconds:literal ms $(, $easing:expr)? $(,)? )) => {
+        $crate::animate_config!(tween (
+            $crate::Duration::from_millis($milliseconds) $(, $easing)?
+        ))
+    };
+    (tween ( $duration:expr $(,)? )) => {
+        $crate::animations::core::AnimationConfig::tween($duration)
+    };
+    (tween ( $duration:expr, $easing:expr $(,)? )) => {
+        $crate::animations::core::AnimationConfig::new(
+            $crate::animations::core::AnimationMode::Tween(
+                $crate::animations::tween::Tween::new($duration).with_easing($easing),
+            ),
+        )
+    };
+    ($kind:ident ( $($config:tt)* )) => {
+        compile_error!(concat!(
+            "unknown animation kind `",
+            stringify!($kind),
+            "`. Supported kinds are `spring` and `tween`."
+        ))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! animate_spring_assign {
+    ($spring:ident, stiffness, $value:expr) => {
+        $spring.stiffness = $value;
+    };
+    ($spring:ident, damping, $value:expr) => {
+        $spring.damping = $value;
+    };
+    ($spring:ident, mass, $value:expr) => {
+        $spring.mass = $value;
+    };
+    ($spring:ident, velocity, $value:expr) => {
+        $spring.velocity = $value;
+    };
+    ($spring:ident, $field:ident, $value:expr) => {
+        compile_error!(concat!(
+            "unknown spring field `",
+            stringify!($field),
+            "`. Supported fields are `stiffness`, `damping`, `mass`, and `velocity`."
+        ));
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! animate_loop {
+    (infinite) => {
+        $crate::animations::core::LoopMode::Infinite
+    };
+    (alternate) => {
+        $crate::animations::core::LoopMode::Alternate
+    };
+    (times ( $count:expr )) => {
+        $crate::animations::core::LoopMode::Times($count)
+    };
+    (alternate_times ( $count:expr )) => {
+        $crate::animations::core::LoopMode::AlternateTimes($count)
+    };
+    ($($loop_mode:tt)+) => {
+        compile_error!(concat!(
+            "unknown loop `",
+            stringify!($($loop_mode)+),
+            "`. Supported loops are `infinite`, `alternate`, `times(n)`, and `alternate_times(n)`."
+        ))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Duration;
+    use crate::Motion;
+    use crate::animations::core::{AnimationConfig, AnimationMode, LoopMode};
+    use crate::animations::spring::Spring;
+    use crate::animations::tween::Tween;
+    use easer::functions::{Cubic, Easing};
+
+    #[test]
+    fn spring_form_sets_named_fields() {
+        let config = crate::animate_config!(spring(stiffness: 200.0, damping: 12.0));
+        let expected = AnimationConfig::spring(Spring {
+            stiffness: 200.0,
+            damping: 12.0,
+            ..Spring::default()
+        });
+
+        assert!(config == expected);
+        assert!(crate::animate_config!(spring()) == AnimationConfig::spring(Spring::default()));
+    }
+
+    #[test]
+    fn tween_form_sets_duration_easing_and_loop() {
+        let config = crate::animate_config!(tween(300 ms, Cubic::ease_in_out).loop(infinite));
+        let expected = AnimationConfig::new(AnimationMode::Tween(
+            Tween::new(Duration::from_millis(300)).with_easing(Cubic::ease_in_out),
+        ))
+        .with_loop(LoopMode::Infinite);
+
+        assert!(config == expected);
+        assert!(
+            crate::animate_config!(tween(Duration::from_secs(1)).loop(times(3)))
+                == AnimationConfig::tween(Duration::from_secs(1)).with_loop(LoopMode::Times(3))
+        );
+    }
+
+    #[test]
+    fn animate_starts_the_motion() {
+        let mut motion = Motion::new(0.0f32);
+        crate::animate!(motion => 100.0, tween(100 ms));
+
+        assert!(motion.is_running());
+        while motion.update(1.0 / 60.0) {}
+        assert_eq!(motion.get_value(), 100.0);
+    }
+}
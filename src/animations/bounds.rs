@@ -0,0 +1,215 @@
+//! Keeping animated values inside a range
+//!
+//! A spring overshoots its target, which is unwanted for values such as a volume that
+//! must stay between 0 and 1. [`Motion::set_bounds`](crate::motion::Motion::set_bounds)
+//! keeps a [`Bounded`] value inside a range after every update, either stopping it at
+//! the edge or bouncing it back.
+
+use crate::animations::core::Animatable;
+use crate::animations::vec2::Vec2;
+
+/// What a bounded motion does when it reaches the edge of its range
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BoundaryBehavior {
+    /// Stops at the edge like a hard wall, losing the velocity towards it
+    #[default]
+    Clamp,
+    /// Bounces off the edge, reversing the velocity towards it
+    Reflect,
+}
+
+/// Values whose components can be kept within a range
+///
+/// Each component is bounded on its own, so a [`Vec2`] ball reflecting off the right
+/// wall keeps its vertical speed.
+pub trait Bounded: Animatable {
+    /// Moves `value` back inside `min..=max`, adjusting `velocity` as `behavior` says
+    fn apply_bounds(
+        value: &mut Self,
+        velocity: &mut Self,
+        min: &Self,
+        max: &Self,
+        behavior: BoundaryBehavior,
+    );
+}
+
+/// Bounds one component.
+///
+/// Swapped bounds are put back in order and a NaN bound leaves that side open, so a
+/// bad range never panics mid-animation.
+fn bound_component(
+    value: &mut f32,
+    velocity: &mut f32,
+    min: f32,
+    max: f32,
+    behavior: BoundaryBehavior,
+) {
+    let (min, max) = if max < min { (max, min) } else { (min, max) };
+    let (wall, outward) = if *value < min {
+        (min, -1.0)
+    } else if *value > max {
+        (max, 1.0)
+    } else {
+        return;
+    };
+
+    match behavior {
+        BoundaryBehavior::Clamp => {
+            *value = wall;
+            if *velocity * outward > 0.0 {
+                *velocity = 0.0;
+            }
+        }
+        BoundaryBehavior::Reflect => {
+            // Fold the overshoot back inside, never past the opposite wall
+            *value = (2.0 * wall - *value).max(min).min(max);
+            *velocity = -outward * velocity.abs();
+        }
+    }
+}
+
+impl Bounded for f32 {
+    fn apply_bounds(
+        value: &mut Self,
+        velocity: &mut Self,
+        min: &Self,
+        max: &Self,
+        behavior: BoundaryBehavior,
+    ) {
+        bound_component(value, velocity, *min, *max, behavior);
+    }
+}
+
+impl Bounded for Vec2 {
+    fn apply_bounds(
+        value: &mut Self,
+        velocity: &mut Self,
+        min: &Self,
+        max: &Self,
+        behavior: BoundaryBehavior,
+    ) {
+        bound_component(&mut value.x, &mut velocity.x, min.x, max.x, behavior);
+        bound_component(&mut value.y, &mut velocity.y, min.y, max.y, behavior);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoundaryBehavior, Bounded};
+    use crate::{
+        Motion,
+        animations::{spring::Spring, vec2::Vec2},
+        prelude::AnimationConfig,
+    };
+
+    fn bouncy() -> AnimationConfig {
+        AnimationConfig::spring(Spring {
+            stiffness: 300.0,
+            damping: 4.0,
+            mass: 1.0,
+            velocity: 0.0,
+        })
+    }
+
+    #[test]
+    fn clamp_stops_at_the_wall_with_zero_velocity() {
+        let mut motion = Motion::new(0.0f32);
+        motion.set_bounds(0.0, 1.0, BoundaryBehavior::Clamp);
+        motion.animate_to(1.0, bouncy());
+
+        let mut hit_wall = false;
+        let mut frames = 0;
+        while motion.update(1.0 / 60.0) {
+            let value = motion.get_value();
+            assert!((0.0..=1.0).contains(&value), "escaped: {value}");
+            if value == 1.0 {
+                hit_wall = true;
+                assert!(motion.velocity <= 0.0, "still pushing into the wall");
+            }
+            frames += 1;
+            assert!(frames < 1000, "spring should settle");
+        }
+        assert!(hit_wall);
+        assert_eq!(motion.get_value(), 1.0);
+    }
+
+    #[test]
+    fn reflect_reverses_the_velocity_and_stays_inside() {
+        let (mut value, mut velocity) = (Vec2::new(105.0, 50.0), Vec2::new(40.0, -3.0));
+        Vec2::apply_bounds(
+            &mut value,
+            &mut velocity,
+            &Vec2::ZERO,
+            &Vec2::new(100.0, 100.0),
+            BoundaryBehavior::Reflect,
+        );
+        assert_eq!(value, Vec2::new(95.0, 50.0));
+        assert_eq!(velocity, Vec2::new(-40.0, -3.0));
+
+        let mut ball = Motion::new(Vec2::new(10.0, 10.0));
+        ball.set_bounds(
+            Vec2::ZERO,
+            Vec2::new(100.0, 100.0),
+            BoundaryBehavior::Reflect,
+        );
+        ball.animate_to(Vec2::new(95.0, 90.0), bouncy());
+
+        let mut frames = 0;
+        while ball.update(1.0 / 60.0) {
+            let Vec2 { x, y } = ball.get_value();
+            assert!((0.0..=100.0).contains(&x) && (0.0..=100.0).contains(&y));
+            frames += 1;
+            assert!(frames < 2000, "ball should come to rest");
+        }
+        assert_eq!(ball.get_value(), Vec2::new(95.0, 90.0));
+    }
+
+    #[test]
+    fn targets_outside_the_bounds_settle_on_the_edge() {
+        let mut motion = Motion::new(0.5f32);
+        motion.set_bounds(0.0, 1.0, BoundaryBehavior::Clamp);
+        motion.animate_to(3.0, AnimationConfig::spring(Spring::default()));
+
+        let mut frames = 0;
+        while motion.update(1.0 / 60.0) {
+            frames += 1;
+            assert!(frames < 1000, "spring should settle on the wall");
+        }
+        assert_eq!(motion.get_value(), 1.0);
+    }
+
+    #[test]
+    fn swapped_or_nan_bounds_do_not_panic() {
+        let mut motion = Motion::new(0.5f32);
+        motion.set_bounds(1.0, 0.0, BoundaryBehavior::Reflect);
+        motion.animate_to(1.0, bouncy());
+        let mut frames = 0;
+        while motion.update(1.0 / 60.0) {
+            let value = motion.get_value();
+            assert!((0.0..=1.0).contains(&value), "escaped: {value}");
+            frames += 1;
+            assert!(frames < 1000, "spring should settle");
+        }
+
+        let (mut value, mut velocity) = (2.0f32, 5.0f32);
+        f32::apply_bounds(
+            &mut value,
+            &mut velocity,
+            &f32::NAN,
+            &1.0,
+            BoundaryBehavior::Reflect,
+        );
+        assert_eq!(value, 0.0);
+        assert_eq!(velocity, -5.0);
+
+        let (mut value, mut velocity) = (-3.0f32, -1.0f32);
+        f32::apply_bounds(
+            &mut value,
+            &mut velocity,
+            &f32::NAN,
+            &1.0,
+            BoundaryBehavior::Clamp,
+        );
+        assert_eq!((value, velocity), (-3.0, -1.0));
+    }
+}
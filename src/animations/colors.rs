@@ -6,6 +6,9 @@
 use crate::animations::core::Animatable;
 use wide::f32x4;
 
+#[cfg(feature = "tailwind")]
+pub mod tailwind;
+
 /// Represents an RGBA color with normalized components
 ///
 /// Each component (r,g,b,a) is stored as a float between 0.0 and 1.0
@@ -29,7 +32,7 @@ impl Color {
     /// use dioxus_motion::prelude::Color;
     /// let color = Color::new(1.0, 0.5, 0.0, 1.0); // Orange color
     /// ```
-    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self {
             r: r.clamp(0.0, 1.0),
             g: g.clamp(0.0, 1.0),
@@ -45,7 +48,7 @@ impl Color {
     /// use dioxus_motion::prelude::Color;
     /// let color = Color::from_rgba(255, 128, 0, 255); // Orange color
     /// ```
-    pub fn from_rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+    pub const fn from_rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
         Color::new(
             r as f32 / 255.0,
             g as f32 / 255.0,
@@ -54,6 +57,61 @@ impl Color {
         )
     }
 
+    /// Creates an opaque color from HSL: `hue` in degrees, `saturation` and `lightness` 0.0-1.0
+    ///
+    /// Hues outside `0.0..360.0` wrap around the wheel.
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::Color;
+    /// assert_eq!(Color::from_hsl(120.0, 1.0, 0.5).to_rgba(), (0, 255, 0, 255));
+    /// assert_eq!(Color::from_hsl(-120.0, 1.0, 0.5).to_rgba(), (0, 0, 255, 255));
+    /// ```
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let (saturation, lightness) = (saturation.clamp(0.0, 1.0), lightness.clamp(0.0, 1.0));
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let second = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, second, 0.0),
+            1 => (second, chroma, 0.0),
+            2 => (0.0, chroma, second),
+            3 => (0.0, second, chroma),
+            4 => (second, 0.0, chroma),
+            _ => (chroma, 0.0, second),
+        };
+        let m = lightness - chroma / 2.0;
+        Color::new(r + m, g + m, b + m, 1.0)
+    }
+
+    /// Converts to HSL as `(hue in degrees, saturation, lightness)`, ignoring alpha
+    ///
+    /// Greys have no hue and report `0.0`.
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let chroma = max - min;
+        let lightness = (max + min) / 2.0;
+        if chroma <= 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = chroma / (1.0 - (2.0 * lightness - 1.0).abs());
+        let sector = if max == self.r {
+            ((self.g - self.b) / chroma).rem_euclid(6.0)
+        } else if max == self.g {
+            (self.b - self.r) / chroma + 2.0
+        } else {
+            (self.r - self.g) / chroma + 4.0
+        };
+        (sector * 60.0, saturation.min(1.0), lightness)
+    }
+
+    /// Creates an opaque color from a `0xRRGGBB` value
+    const fn from_hex(rgb: u32) -> Self {
+        Color::from_rgba((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8, 255)
+    }
+
     /// Converts color to 8-bit RGBA values
     ///
     /// # Returns
@@ -66,6 +124,208 @@ impl Color {
             (self.a * 255.0 + 0.5) as u8,
         )
     }
+
+    /// Returns a copy of this color with a different alpha, keeping RGB unchanged
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::Color;
+    /// let faded = Color::from_rgba(255, 128, 0, 255).with_alpha(0.0);
+    /// assert_eq!(faded.to_rgba(), (255, 128, 0, 0));
+    /// ```
+    pub fn with_alpha(self, a: f32) -> Self {
+        Self {
+            a: a.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Returns the alpha component as an opacity from 0.0 to 1.0
+    pub fn opacity(&self) -> f32 {
+        self.a
+    }
+
+    /// Composites `over` on top of this color using a blend mode
+    ///
+    /// Follows the W3C compositing model: the blend mode mixes the channels where both
+    /// layers are present, then the result is alpha-composited source-over this color.
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::{BlendMode, Color};
+    /// let base = Color::new(0.0, 0.0, 1.0, 1.0);
+    /// let tint = Color::new(1.0, 0.0, 0.0, 0.5);
+    /// assert_eq!(base.blend(&tint, BlendMode::Normal), Color::new(0.5, 0.0, 0.5, 1.0));
+    /// ```
+    pub fn blend(&self, over: &Color, mode: BlendMode) -> Color {
+        let alpha = over.a + self.a * (1.0 - over.a);
+        if alpha <= 0.0 {
+            return Color::new(0.0, 0.0, 0.0, 0.0);
+        }
+
+        let channel = |backdrop: f32, source: f32| {
+            let mixed = (1.0 - self.a) * source + self.a * mode.apply(backdrop, source);
+            (over.a * mixed + self.a * (1.0 - over.a) * backdrop) / alpha
+        };
+
+        Color::new(
+            channel(self.r, over.r),
+            channel(self.g, over.g),
+            channel(self.b, over.b),
+            alpha,
+        )
+    }
+
+    /// Interpolates in linear light instead of directly on the sRGB values
+    ///
+    /// The RGB channels are decoded to linear light, mixed, and encoded back to sRGB,
+    /// which is how light actually adds up: fades keep their brightness instead of
+    /// dipping dark through the middle. Alpha is mixed linearly as usual.
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::Color;
+    /// let grey = Color::new(0.5, 0.5, 0.5, 1.0);
+    /// let white = Color::new(1.0, 1.0, 1.0, 1.0);
+    /// assert!(grey.interpolate_linear_rgb(&white, 0.5).r > 0.75);
+    /// ```
+    pub fn interpolate_linear_rgb(&self, target: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |from: f32, to: f32| {
+            let from = srgb_to_linear(from);
+            encode_srgb(from + (srgb_to_linear(to) - from) * t)
+        };
+
+        Color::new(
+            mix(self.r, target.r),
+            mix(self.g, target.g),
+            mix(self.b, target.b),
+            self.a + (target.a - self.a) * t,
+        )
+    }
+
+    /// Interpolates towards `target` in the given color space
+    pub fn interpolate_in(&self, target: &Self, t: f32, space: ColorSpace) -> Self {
+        match space {
+            ColorSpace::Srgb => self.interpolate(target, t),
+            ColorSpace::LinearRgb => self.interpolate_linear_rgb(target, t),
+        }
+    }
+
+    /// WCAG relative luminance, from 0.0 for black to 1.0 for white
+    ///
+    /// Alpha is ignored; blend a translucent color onto its backdrop first.
+    pub fn relative_luminance(&self) -> f32 {
+        0.2126 * srgb_to_linear(self.r)
+            + 0.7152 * srgb_to_linear(self.g)
+            + 0.0722 * srgb_to_linear(self.b)
+    }
+
+    /// WCAG contrast ratio against `other`, from 1.0 (identical) to 21.0 (black on white)
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::Color;
+    /// assert!((Color::BLACK.contrast_ratio(&Color::WHITE) - 21.0).abs() < 1e-4);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Whether white text contrasts better with this color than black text
+    ///
+    /// Handy for picking a legible text color over an animated background.
+    pub fn is_dark(&self) -> bool {
+        self.contrast_ratio(&Color::WHITE) > self.contrast_ratio(&Color::BLACK)
+    }
+
+    fn same_rgb(&self, other: &Self) -> bool {
+        self.r == other.r && self.g == other.g && self.b == other.b
+    }
+}
+
+/// How [`Color::blend`] mixes a layer with the color beneath it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// The top layer replaces the one beneath, weighted by its alpha
+    #[default]
+    Normal,
+    /// Multiplies channels, always darkening; black stays black
+    Multiply,
+    /// Inverse multiply of the inverted channels, always lightening; white stays white
+    Screen,
+}
+
+impl BlendMode {
+    /// Blends a backdrop channel with a source channel
+    fn apply(self, backdrop: f32, source: f32) -> f32 {
+        match self {
+            Self::Normal => source,
+            Self::Multiply => backdrop * source,
+            Self::Screen => backdrop + source - backdrop * source,
+        }
+    }
+}
+
+/// Color space two colors are mixed in by [`Color::interpolate_in`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSpace {
+    /// Mix the gamma-encoded sRGB values directly; cheapest, and what `Animatable` uses
+    #[default]
+    Srgb,
+    /// Mix in linear light, for gamma-correct fades and gradients
+    LinearRgb,
+}
+
+/// Decodes an sRGB channel to linear light
+fn srgb_to_linear(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes a linear-light channel back to sRGB
+fn encode_srgb(channel: f32) -> f32 {
+    if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// The CSS basic named colors
+///
+/// For the Tailwind palette, see the `tailwind` module behind the `tailwind` feature.
+///
+/// # Examples
+/// ```
+/// use dioxus_motion::prelude::Color;
+/// assert_eq!(Color::ORANGE.to_rgba(), (255, 165, 0, 255));
+/// ```
+impl Color {
+    pub const TRANSPARENT: Color = Color::new(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Color = Color::from_hex(0x000000);
+    pub const SILVER: Color = Color::from_hex(0xc0c0c0);
+    pub const GRAY: Color = Color::from_hex(0x808080);
+    pub const WHITE: Color = Color::from_hex(0xffffff);
+    pub const MAROON: Color = Color::from_hex(0x800000);
+    pub const RED: Color = Color::from_hex(0xff0000);
+    pub const PURPLE: Color = Color::from_hex(0x800080);
+    pub const FUCHSIA: Color = Color::from_hex(0xff00ff);
+    pub const GREEN: Color = Color::from_hex(0x008000);
+    pub const LIME: Color = Color::from_hex(0x00ff00);
+    pub const OLIVE: Color = Color::from_hex(0x808000);
+    pub const YELLOW: Color = Color::from_hex(0xffff00);
+    pub const NAVY: Color = Color::from_hex(0x000080);
+    pub const BLUE: Color = Color::from_hex(0x0000ff);
+    pub const TEAL: Color = Color::from_hex(0x008080);
+    pub const AQUA: Color = Color::from_hex(0x00ffff);
+    pub const ORANGE: Color = Color::from_hex(0xffa500);
+    pub const PINK: Color = Color::from_hex(0xffc0cb);
+    pub const REBECCA_PURPLE: Color = Color::from_hex(0x663399);
 }
 
 impl Default for Color {
@@ -117,6 +377,12 @@ impl std::ops::Mul<f32> for Color {
 /// Much simpler with the new trait design - uses standard operators
 impl Animatable for Color {
     fn interpolate(&self, target: &Self, t: f32) -> Self {
+        // Alpha-only fades never touch RGB, so hue cannot drift
+        if self.same_rgb(target) {
+            let t = t.clamp(0.0, 1.0);
+            return self.with_alpha(self.a + (target.a - self.a) * t);
+        }
+
         let a = [self.r, self.g, self.b, self.a];
         let b = [target.r, target.g, target.b, target.a];
         let va = f32x4::new(a);
@@ -134,10 +400,144 @@ impl Animatable for Color {
     // Uses default epsilon of 0.01 from the trait - no need for COLOR_EPSILON
 }
 
+/// Errors returned by [`ColorGradient::new`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ColorGradientError {
+    #[error("A gradient needs at least one color stop")]
+    NoStops,
+    #[error("Gradient stop offsets must be finite")]
+    InvalidOffset,
+    #[error("Gradient stop offsets must be in ascending order")]
+    UnsortedOffsets,
+}
+
+/// Colors placed at offsets along a line, sampled by a single parameter
+///
+/// Animate the parameter rather than the gradient: drive an `f32` motion from 0 to 1
+/// and call [`ColorGradient::sample`] with its value to sweep through every stop.
+/// Two stops at the same offset make a hard edge.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::{Color, ColorGradient};
+///
+/// let sunset = ColorGradient::new(&[
+///     (0.0, Color::from_rgba(255, 200, 80, 255)),
+///     (0.5, Color::from_rgba(240, 90, 60, 255)),
+///     (1.0, Color::from_rgba(60, 30, 90, 255)),
+/// ])
+/// .unwrap();
+/// assert_eq!(sunset.sample(0.5), Color::from_rgba(240, 90, 60, 255));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorGradient {
+    stops: Vec<(f32, Color)>,
+    space: ColorSpace,
+}
+
+impl ColorGradient {
+    /// Creates a gradient from `(offset, color)` stops in ascending offset order
+    pub fn new(stops: &[(f32, Color)]) -> Result<Self, ColorGradientError> {
+        if stops.is_empty() {
+            return Err(ColorGradientError::NoStops);
+        }
+        if stops.iter().any(|(offset, _)| !offset.is_finite()) {
+            return Err(ColorGradientError::InvalidOffset);
+        }
+        if stops.windows(2).any(|pair| pair[1].0 < pair[0].0) {
+            return Err(ColorGradientError::UnsortedOffsets);
+        }
+
+        Ok(Self {
+            stops: stops.to_vec(),
+            space: ColorSpace::default(),
+        })
+    }
+
+    /// Mixes neighbouring stops in `space` instead of plain sRGB
+    pub fn with_space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// The `(offset, color)` stops, in ascending offset order
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// The color at `t`, clamped to the first and last stops
+    pub fn sample(&self, t: f32) -> Color {
+        let after = self.stops.partition_point(|(offset, _)| *offset <= t);
+        let Some(&(from_offset, from)) = after.checked_sub(1).and_then(|i| self.stops.get(i))
+        else {
+            return self.stops[0].1;
+        };
+        let Some(&(to_offset, to)) = self.stops.get(after) else {
+            return from;
+        };
+
+        let local = (t - from_offset) / (to_offset - from_offset);
+        from.interpolate_in(&to, local, self.space)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn named_colors_match_their_css_values() {
+        assert_eq!(Color::RED, Color::from_rgba(255, 0, 0, 255));
+        assert_eq!(Color::GREEN.to_rgba(), (0, 128, 0, 255));
+        assert_eq!(Color::ORANGE.to_rgba(), (255, 165, 0, 255));
+        assert_eq!(Color::REBECCA_PURPLE.to_rgba(), (102, 51, 153, 255));
+        assert_eq!(Color::TRANSPARENT.to_rgba(), (0, 0, 0, 0));
+        assert_eq!(Color::BLACK, Color::default());
+    }
+
+    #[cfg(feature = "tailwind")]
+    #[test]
+    fn tailwind_palette_matches_known_shades() {
+        assert_eq!(tailwind::BLUE_500.to_rgba(), (59, 130, 246, 255));
+        assert_eq!(tailwind::SLATE_900.to_rgba(), (15, 23, 42, 255));
+        assert_eq!(tailwind::EMERALD_400.to_rgba(), (52, 211, 153, 255));
+        assert_eq!(tailwind::ROSE_950.to_rgba(), (76, 5, 25, 255));
+    }
+
+    #[test]
+    fn gradient_hits_stops_exactly_and_blends_between() {
+        let red = Color::from_rgba(255, 0, 0, 255);
+        let green = Color::from_rgba(0, 255, 0, 255);
+        let blue = Color::from_rgba(0, 0, 255, 255);
+        let gradient = ColorGradient::new(&[(0.0, red), (0.25, green), (1.0, blue)])
+            .expect("stops are sorted");
+
+        assert_eq!(gradient.sample(0.0), red);
+        assert_eq!(gradient.sample(0.25), green);
+        assert_eq!(gradient.sample(1.0), blue);
+        assert_eq!(gradient.sample(-1.0), red);
+        assert_eq!(gradient.sample(2.0), blue);
+
+        let first_half = gradient.sample(0.125);
+        assert!((first_half.r - 0.5).abs() < 1e-6 && (first_half.g - 0.5).abs() < 1e-6);
+        let second_half = gradient.sample(0.625);
+        assert!((second_half.g - 0.5).abs() < 1e-6 && (second_half.b - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn gradient_rejects_bad_stops() {
+        let black = Color::default();
+        assert_eq!(ColorGradient::new(&[]), Err(ColorGradientError::NoStops));
+        assert_eq!(
+            ColorGradient::new(&[(0.5, black), (0.2, black)]),
+            Err(ColorGradientError::UnsortedOffsets)
+        );
+        assert_eq!(
+            ColorGradient::new(&[(f32::NAN, black)]),
+            Err(ColorGradientError::InvalidOffset)
+        );
+    }
+
     #[test]
     fn test_color_new() {
         let color = Color::new(1.0, 0.5, 0.0, 1.0);
@@ -156,6 +556,105 @@ mod tests {
         assert!((color.a - 1.0).abs() < f32::EPSILON);
     }
 
+    fn assert_color_eq(actual: Color, expected: Color) {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-6;
+        assert!(
+            close(actual.r, expected.r)
+                && close(actual.g, expected.g)
+                && close(actual.b, expected.b)
+                && close(actual.a, expected.a),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_color_blend_normal() {
+        let base = Color::new(0.0, 0.0, 1.0, 1.0);
+
+        assert_color_eq(
+            base.blend(&Color::new(1.0, 0.0, 0.0, 0.5), BlendMode::Normal),
+            Color::new(0.5, 0.0, 0.5, 1.0),
+        );
+        assert_color_eq(
+            base.blend(&Color::new(1.0, 0.0, 0.0, 0.0), BlendMode::Normal),
+            base,
+        );
+        assert_color_eq(
+            Color::new(0.2, 0.4, 0.6, 0.0)
+                .blend(&Color::new(1.0, 0.5, 0.0, 0.5), BlendMode::Normal),
+            Color::new(1.0, 0.5, 0.0, 0.5),
+        );
+    }
+
+    #[test]
+    fn test_color_blend_multiply() {
+        let black = Color::new(0.0, 0.0, 0.0, 1.0);
+        let orange = Color::new(1.0, 0.5, 0.0, 1.0);
+
+        assert_color_eq(black.blend(&orange, BlendMode::Multiply), black);
+        assert_color_eq(orange.blend(&black, BlendMode::Multiply), black);
+        assert_color_eq(
+            orange.blend(&Color::new(0.5, 0.5, 0.5, 1.0), BlendMode::Multiply),
+            Color::new(0.5, 0.25, 0.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn test_color_blend_screen() {
+        let white = Color::new(1.0, 1.0, 1.0, 1.0);
+        let orange = Color::new(1.0, 0.5, 0.0, 1.0);
+
+        assert_color_eq(white.blend(&orange, BlendMode::Screen), white);
+        assert_color_eq(orange.blend(&white, BlendMode::Screen), white);
+        assert_color_eq(
+            Color::new(0.5, 0.5, 0.5, 1.0)
+                .blend(&Color::new(0.5, 0.0, 1.0, 1.0), BlendMode::Screen),
+            Color::new(0.75, 0.5, 1.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn hsl_round_trips_through_rgb() {
+        assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::RED);
+        assert_eq!(Color::from_hsl(360.0, 1.0, 0.5), Color::RED);
+        assert_eq!(Color::from_hsl(300.0, 1.0, 0.5), Color::FUCHSIA);
+        assert_eq!(Color::from_hsl(200.0, 0.0, 1.0), Color::WHITE);
+        for hue in (0..360).step_by(15) {
+            let (h, s, l) = Color::from_hsl(hue as f32, 0.8, 0.4).to_hsl();
+            assert!((h - hue as f32).abs() < 1e-3, "{hue} -> {h}");
+            assert!((s - 0.8).abs() < 1e-4 && (l - 0.4).abs() < 1e-4);
+        }
+        assert_eq!(Color::GRAY.to_hsl().0, 0.0);
+    }
+
+    #[test]
+    fn contrast_matches_wcag_reference_values() {
+        assert_eq!(Color::BLACK.relative_luminance(), 0.0);
+        assert!((Color::WHITE.relative_luminance() - 1.0).abs() < 1e-6);
+        assert!((Color::WHITE.contrast_ratio(&Color::BLACK) - 21.0).abs() < 1e-4);
+        assert_eq!(Color::RED.contrast_ratio(&Color::RED), 1.0);
+        // #767676 is the lightest grey passing WCAG AA (4.5:1) on white.
+        let grey = Color::from_rgba(0x76, 0x76, 0x76, 255);
+        assert!((grey.contrast_ratio(&Color::WHITE) - 4.54).abs() < 0.01);
+        assert!((Color::BLUE.relative_luminance() - 0.0722).abs() < 1e-6);
+    }
+
+    #[test]
+    fn is_dark_flips_at_the_equal_contrast_luminance() {
+        // White and black text contrast equally at a luminance of sqrt(1.05 * 0.05) - 0.05.
+        let threshold = (1.05f32 * 0.05).sqrt() - 0.05;
+        for level in 0..=255u8 {
+            let grey = Color::from_rgba(level, level, level, 255);
+            assert_eq!(
+                grey.is_dark(),
+                grey.relative_luminance() < threshold,
+                "{level}"
+            );
+        }
+        assert!(Color::NAVY.is_dark());
+        assert!(!Color::YELLOW.is_dark());
+    }
+
     #[test]
     fn test_color_lerp() {
         let start = Color::new(0.0, 0.0, 0.0, 1.0);
@@ -168,6 +667,21 @@ mod tests {
         assert!((mid.a - 1.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_color_linear_rgb_fade_is_brighter_midway() {
+        let grey = Color::new(0.5, 0.5, 0.5, 1.0);
+        let white = Color::new(1.0, 1.0, 1.0, 1.0);
+
+        let srgb = grey.interpolate_in(&white, 0.5, ColorSpace::Srgb);
+        let linear = grey.interpolate_in(&white, 0.5, ColorSpace::LinearRgb);
+
+        assert!((srgb.r - 0.75).abs() < 1e-6);
+        assert!((linear.r - 0.802).abs() < 1e-3, "{linear:?}");
+        assert!(linear.r > srgb.r);
+        assert_color_eq(grey.interpolate_linear_rgb(&white, 0.0), grey);
+        assert_color_eq(grey.interpolate_linear_rgb(&white, 1.0), white);
+    }
+
     #[test]
     fn test_color_to_rgba() {
         let color = Color::new(1.0, 0.5, 0.0, 1.0);
@@ -177,4 +691,34 @@ mod tests {
         assert_eq!(b, 0);
         assert_eq!(a, 255);
     }
+
+    #[test]
+    fn test_color_with_alpha_and_opacity() {
+        let color = Color::from_rgba(10, 20, 30, 255).with_alpha(0.25);
+        assert_eq!(color.opacity(), 0.25);
+        assert_eq!(color.to_rgba(), (10, 20, 30, 64));
+
+        assert_eq!(color.with_alpha(2.0).opacity(), 1.0);
+    }
+
+    #[test]
+    fn test_color_alpha_fade_keeps_rgb() {
+        use crate::Motion;
+        use crate::animations::core::AnimationConfig;
+        use instant::Duration;
+
+        let start = Color::from_rgba(37, 99, 235, 255);
+        let mut motion = Motion::new(start);
+        motion.animate_to(
+            start.with_alpha(0.0),
+            AnimationConfig::tween(Duration::from_millis(300)),
+        );
+
+        while motion.update(1.0 / 60.0) {
+            let (r, g, b, _) = motion.current.to_rgba();
+            assert_eq!((r, g, b), (37, 99, 235));
+        }
+
+        assert_eq!(motion.current.to_rgba(), (37, 99, 235, 0));
+    }
 }
@@ -0,0 +1,141 @@
+//! Tuple-based composite animation values
+//!
+//! Rust's orphan rules prevent implementing the operator traits [`Animatable`] needs
+//! directly on tuples, so [`Composite`] wraps a tuple of up to four animatable values
+//! and animates each element independently.
+
+use crate::animations::core::Animatable;
+
+/// A tuple of animatable values animated together as one motion value
+///
+/// Each element is interpolated on its own; [`Animatable::magnitude`] combines the
+/// element magnitudes so the motion only completes once every element has settled.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::animations::core::Animatable;
+/// use dioxus_motion::prelude::{Color, Composite};
+///
+/// let from = Composite((0.0f32, Color::from_rgba(0, 0, 0, 255)));
+/// let to = Composite((100.0f32, Color::from_rgba(255, 255, 255, 255)));
+/// let Composite((x, color)) = from.interpolate(&to, 0.5);
+/// assert_eq!(x, 50.0);
+/// assert!((color.r - 0.5).abs() < 1e-6);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Composite<T>(pub T);
+
+macro_rules! impl_composite {
+    ($($name:ident : $index:tt),+) => {
+        impl<$($name: Animatable),+> std::ops::Add for Composite<($($name,)+)> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Composite(($(self.0.$index + other.0.$index,)+))
+            }
+        }
+
+        impl<$($name: Animatable),+> std::ops::Sub for Composite<($($name,)+)> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Composite(($(self.0.$index - other.0.$index,)+))
+            }
+        }
+
+        impl<$($name: Animatable),+> std::ops::Mul<f32> for Composite<($($name,)+)> {
+            type Output = Self;
+
+            fn mul(self, factor: f32) -> Self {
+                Composite(($(self.0.$index * factor,)+))
+            }
+        }
+
+        impl<$($name: Animatable),+> Animatable for Composite<($($name,)+)> {
+            fn interpolate(&self, target: &Self, t: f32) -> Self {
+                Composite(($(self.0.$index.interpolate(&target.0.$index, t),)+))
+            }
+
+            fn magnitude(&self) -> f32 {
+                (0.0 $(+ self.0.$index.magnitude().powi(2))+).sqrt()
+            }
+
+            fn epsilon() -> f32 {
+                f32::INFINITY $(.min($name::epsilon()))+
+            }
+        }
+    };
+}
+
+impl_composite!(A: 0);
+impl_composite!(A: 0, B: 1);
+impl_composite!(A: 0, B: 1, C: 2);
+impl_composite!(A: 0, B: 1, C: 2, D: 3);
+
+#[cfg(test)]
+mod tests {
+    use super::Composite;
+    use crate::{
+        Motion,
+        animations::{colors::Color, core::Animatable, spring::Spring, transform::Transform},
+        prelude::AnimationConfig,
+    };
+
+    #[test]
+    fn tuple_motion_converges_each_element() {
+        let black = Color::from_rgba(0, 0, 0, 255);
+        let white = Color::from_rgba(255, 255, 255, 255);
+        let mut motion = Motion::new(Composite((0.0f32, black)));
+
+        motion.animate_to(Composite((100.0, white)), AnimationConfig::tween_ms(300));
+        while motion.update(1.0 / 60.0) {}
+        assert_eq!(motion.get_value(), Composite((100.0, white)));
+
+        motion.animate_to(Composite((100.0, black)), AnimationConfig::tween_ms(300));
+        motion.update(0.15);
+        let Composite((x, color)) = motion.get_value();
+        assert_eq!(x, 100.0);
+        assert!(color.r > 0.0 && color.r < 1.0);
+
+        while motion.update(1.0 / 60.0) {}
+        assert_eq!(motion.get_value(), Composite((100.0, black)));
+    }
+
+    #[test]
+    fn spring_settles_once_every_element_is_at_rest() {
+        let mut motion = Motion::new(Composite((0.0f32, 0.0f32)));
+        motion.animate_to(
+            Composite((100.0, -50.0)),
+            AnimationConfig::spring(Spring::default()),
+        );
+
+        let mut frames = 0;
+        while motion.update(1.0 / 60.0) {
+            frames += 1;
+            assert!(frames < 1000, "spring should settle");
+        }
+        assert_eq!(motion.get_value(), Composite((100.0, -50.0)));
+    }
+
+    #[test]
+    fn magnitude_combines_every_element() {
+        assert_eq!(Composite((3.0f32, -4.0f32)).magnitude(), 5.0);
+        assert_eq!(Composite((2.0f32,)).magnitude(), 2.0);
+        assert_eq!(
+            <Composite<(f32, Color)> as Animatable>::epsilon(),
+            f32::epsilon().min(Color::epsilon())
+        );
+    }
+
+    #[test]
+    fn four_element_composite_interpolates_field_wise() {
+        let from = Composite((0.0f32, 10.0f32, Transform::identity(), 1.0f32));
+        let to = Composite((10.0f32, 0.0f32, Transform::new(20.0, 0.0, 1.0, 0.0), 1.0f32));
+
+        let Composite((a, b, transform, d)) = from.interpolate(&to, 0.25);
+        assert_eq!(a, 2.5);
+        assert_eq!(b, 7.5);
+        assert_eq!(transform.x, 5.0);
+        assert_eq!(d, 1.0);
+    }
+}
@@ -3,9 +3,14 @@
 //! This module contains the fundamental traits and types for implementing animations in Dioxus Motion.
 //! It provides support for both tweening and spring-based animations with configurable parameters.
 
+use std::any::Any;
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex};
 
-use crate::animations::{spring::Spring, tween::Tween};
+use crate::animations::{
+    spring::{Spring, TransformSprings},
+    tween::Tween,
+};
 use instant::Duration;
 
 /// A simplified trait for types that can be animated
@@ -33,6 +38,23 @@ pub trait Animatable:
     fn epsilon() -> f32 {
         0.01 // Single default epsilon for simplicity
     }
+
+    /// Returns per-component completion thresholds, laid out like the value itself
+    ///
+    /// Types whose fields use different units (pixels and radians, say) can return
+    /// `Some` so springs settle only once every component is within its own threshold,
+    /// as judged by [`Animatable::within_components`]. The default `None` compares
+    /// [`Animatable::magnitude`] against [`Animatable::epsilon`] instead.
+    fn epsilon_components() -> Option<Self> {
+        None
+    }
+
+    /// Whether every component of `self` is within the matching component of `epsilon`
+    ///
+    /// Only called with the thresholds from [`Animatable::epsilon_components`].
+    fn within_components(&self, epsilon: &Self) -> bool {
+        self.magnitude() < epsilon.magnitude()
+    }
 }
 
 #[cfg(test)]
@@ -49,6 +71,33 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn tween_secs_creates_tween_config_with_easing() {
+        use easer::functions::{Cubic, Easing};
+
+        let config = AnimationConfig::tween_secs(0.5, Cubic::ease_in_out);
+
+        assert_eq!(
+            config.mode,
+            AnimationMode::Tween(
+                Tween::new(Duration::from_millis(500)).with_easing(Cubic::ease_in_out)
+            )
+        );
+        assert_eq!(
+            Tween::seconds(-1.0, Cubic::ease_in_out).duration,
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn rounding_modes_round_in_their_direction() {
+        assert_eq!(RoundingMode::Floor.apply(2.7), 2);
+        assert_eq!(RoundingMode::Round.apply(2.5), 3);
+        assert_eq!(RoundingMode::Ceil.apply(2.1), 3);
+        assert_eq!(RoundingMode::Floor.apply(-2.1), -3);
+        assert_eq!(RoundingMode::Round.apply(f32::NAN), 0);
+    }
+
     #[test]
     fn spring_creates_spring_config() {
         let spring = Spring::default();
@@ -56,15 +105,103 @@ mod tests {
 
         assert_eq!(config.mode, AnimationMode::Spring(spring));
     }
+
+    #[test]
+    fn loop_jitter_scale_stays_in_range() {
+        let jitter = LoopJitter {
+            fraction: 0.2,
+            seed: Some(7),
+        };
+        let mut state = jitter.initial_state();
+
+        for _ in 0..1000 {
+            let scale = jitter.next_scale(&mut state);
+            assert!((0.8..=1.2).contains(&scale));
+        }
+    }
+
+    #[test]
+    fn with_speed_stores_multiplier() {
+        assert_eq!(AnimationConfig::tween_ms(100).speed_multiplier(), 1.0);
+        assert_eq!(
+            AnimationConfig::tween_ms(100)
+                .with_speed(2.0)
+                .speed_multiplier(),
+            2.0
+        );
+    }
+
+    #[test]
+    fn with_speed_clamps_invalid_multipliers() {
+        for multiplier in [0.0, -2.0, f32::NAN, f32::NEG_INFINITY] {
+            assert_eq!(
+                AnimationConfig::tween_ms(100)
+                    .with_speed(multiplier)
+                    .speed_multiplier(),
+                AnimationConfig::MIN_SPEED
+            );
+        }
+        assert_eq!(
+            AnimationConfig::tween_ms(100)
+                .with_speed(f32::INFINITY)
+                .speed_multiplier(),
+            AnimationConfig::MAX_SPEED
+        );
+        assert_eq!(
+            AnimationConfig::tween_ms(100)
+                .with_speed(AnimationConfig::MIN_SPEED)
+                .speed_multiplier(),
+            AnimationConfig::MIN_SPEED
+        );
+    }
+
+    fn hash_of(config: &AnimationConfig) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        config.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn configs_with_same_timing_are_equal_regardless_of_callbacks() {
+        let plain = AnimationConfig::spring(Spring::default()).with_loop(LoopMode::Times(2));
+        let with_callback = AnimationConfig::spring(Spring::default())
+            .with_loop(LoopMode::Times(2))
+            .with_on_complete(|| {});
+
+        assert!(plain == with_callback);
+        assert_eq!(hash_of(&plain), hash_of(&with_callback));
+    }
+
+    #[test]
+    fn configs_with_different_delays_are_unequal() {
+        use easer::functions::{Cubic, Easing};
+
+        let base = AnimationConfig::tween_ms(200);
+        let delayed = AnimationConfig::tween_ms(200).with_delay(Duration::from_millis(50));
+
+        assert!(base != delayed);
+        assert!(base != AnimationConfig::tween_ms(200).with_epsilon(0.5));
+        assert!(base != AnimationConfig::tween_secs(0.2, Cubic::ease_in_out));
+    }
+}
+
+/// Hashes a float consistently with `==`, so `0.0` and `-0.0` hash alike
+pub(crate) fn hash_f32<H: Hasher>(value: f32, state: &mut H) {
+    let bits = if value == 0.0 { 0 } else { value.to_bits() };
+    bits.hash(state);
 }
 
 /// Defines the type of animation to be used
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
 pub enum AnimationMode {
     /// Tween animation with duration and easing
     Tween(Tween),
     /// Physics-based spring animation
     Spring(Spring),
+    /// Physics-based spring animation with an independent spring per `Transform` field
+    ///
+    /// Other animatable types are driven by the `x` spring as a whole.
+    ComponentSpring(TransformSprings),
 }
 
 impl Default for AnimationMode {
@@ -74,22 +211,126 @@ impl Default for AnimationMode {
 }
 
 /// Defines how the animation should loop
-#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Hash)]
 pub enum LoopMode {
     /// Play animation once
     #[default]
     None,
     /// Loop animation indefinitely
     Infinite,
-    /// Loop animation a specific number of times
+    /// Play the animation `n` times in total, restarting from the start each time
+    ///
+    /// `Times(0)` and `Times(1)` both play once, the same as [`LoopMode::None`].
     Times(u8),
     /// Loop animation back and forth indefinitely
     Alternate,
-    /// Loop animation back and forth a specific number of times
+    /// Play `n` round trips to the target and back, ending where the animation started
+    ///
+    /// `AlternateTimes(0)` plays the forward trip once, the same as [`LoopMode::None`].
     AlternateTimes(u8),
+    /// Loop animation back and forth indefinitely, using a separate mode per direction
+    ///
+    /// `forward` drives the trip toward the target and `backward` the return trip,
+    /// replacing the config's own mode while this loop mode is active.
+    PingPong {
+        forward: AnimationMode,
+        backward: AnimationMode,
+    },
+}
+
+/// How a smoothly animated value is rounded to a whole number for display
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round down, so the display never runs ahead of the value
+    Floor,
+    /// Round to the nearest integer, ties away from zero
+    #[default]
+    Round,
+    /// Round up
+    Ceil,
+}
+
+impl RoundingMode {
+    /// Rounds `value`, saturating at the `i64` range; `NaN` becomes `0`
+    pub fn apply(self, value: f32) -> i64 {
+        let rounded = match self {
+            Self::Floor => value.floor(),
+            Self::Round => value.round(),
+            Self::Ceil => value.ceil(),
+        };
+        rounded as i64
+    }
+}
+
+/// Shortest a jittered repetition may get, relative to its base duration
+const MIN_LOOP_SCALE: f32 = 0.05;
+
+/// Randomized timing variance applied to each loop repetition
+///
+/// Every repetition after the first runs for its base duration scaled by a factor
+/// drawn uniformly from `1.0 - fraction ..= 1.0 + fraction`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoopJitter {
+    /// Maximum relative deviation of a repetition's duration (0.0-1.0)
+    pub fraction: f32,
+    /// Seed for reproducible jitter; `None` picks a fresh seed per animation
+    pub seed: Option<u64>,
+}
+
+impl Hash for LoopJitter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32(self.fraction, state);
+        self.seed.hash(state);
+    }
+}
+
+impl LoopJitter {
+    /// Initial PRNG state for one animation run
+    pub(crate) fn initial_state(&self) -> u64 {
+        self.seed.unwrap_or_else(|| {
+            use std::hash::{BuildHasher, Hasher};
+            std::collections::hash_map::RandomState::new()
+                .build_hasher()
+                .finish()
+        })
+    }
+
+    /// Advances `state` and returns the next duration scale
+    pub(crate) fn next_scale(&self, state: &mut u64) -> f32 {
+        // SplitMix64: tiny, fast and good enough for timing variance.
+        *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        let unit = (z >> 40) as f32 / (1u64 << 24) as f32;
+        let fraction = self.fraction.clamp(0.0, 1.0);
+        (1.0 + fraction * (unit * 2.0 - 1.0)).max(MIN_LOOP_SCALE)
+    }
+}
+
+/// Follow-up animation started by [`AnimationConfig::chain`]
+///
+/// The target is type-erased because configs are shared across value types; it only
+/// runs on a motion whose value type matches.
+pub struct ChainedAnimation {
+    target: Box<dyn Any + Send + Sync>,
+    config: AnimationConfig,
+}
+
+impl ChainedAnimation {
+    /// The chained target and config, if the target is a `T`
+    pub(crate) fn get<T: Animatable>(&self) -> Option<(T, AnimationConfig)> {
+        self.target
+            .downcast_ref::<T>()
+            .map(|target| (target.clone(), self.config.clone()))
+    }
 }
 
 pub type OnComplete = Arc<Mutex<dyn FnMut() + Send + 'static>>;
+/// Callback run with the loop index each time a looping animation starts over
+pub type OnLoop = Arc<Mutex<dyn FnMut(u8) + Send + 'static>>;
 /// Configuration for an animation
 #[derive(Clone, Default)]
 pub struct AnimationConfig {
@@ -99,22 +340,119 @@ pub struct AnimationConfig {
     pub loop_mode: Option<LoopMode>,
     /// Delay before animation starts
     pub delay: Duration,
+    /// How far into the animation it starts, skipping the beginning
+    pub time_offset: Option<Duration>,
     /// Callback when animation completes
     pub on_complete: Option<Arc<Mutex<dyn FnMut() + Send>>>,
+    /// Callback when the animation is stopped before it completes
+    pub on_cancel: Option<Arc<Mutex<dyn FnMut() + Send>>>,
+    /// Callback when a looping animation starts another iteration
+    pub on_loop: Option<OnLoop>,
     /// Custom epsilon threshold for animation completion detection
     /// If None, uses the type's default epsilon from Animatable::epsilon()
     pub epsilon: Option<f32>,
+    /// Playback speed multiplier applied to the animation's time step
+    /// If None, the animation plays at normal speed (1.0)
+    pub speed: Option<f32>,
+    /// Randomized duration variance applied to each loop repetition
+    pub loop_jitter: Option<LoopJitter>,
+    /// Shortest time a tween or spring may take to finish
+    pub min_duration: Option<Duration>,
+    /// Fastest a spring may move, in value units per second
+    /// If None, spring velocity is unbounded
+    pub max_velocity: Option<f32>,
+    /// Times a spring may change direction before it is critically damped
+    /// If None, the spring oscillates until its own damping settles it
+    pub max_bounces: Option<u8>,
+    /// Animation started on the same motion once this one completes
+    pub chain: Option<Arc<ChainedAnimation>>,
+    /// While this animation runs, `animate_to` calls with a lower priority are ignored
+    pub priority: u8,
+    /// Constant speed of a tween, in value units per second
+    /// If set, the tween's duration is derived from the distance it covers when it starts
+    pub tween_speed: Option<f32>,
+}
+
+/// Configs compare by their timing: mode, looping, delay, time offset, epsilon, speed,
+/// jitter, minimum duration, velocity cap, bounce limit and tween speed, and by
+/// priority. Callbacks are ignored, so `on_complete`, `on_cancel`, `on_loop` and
+/// [`AnimationConfig::chain`] follow-ups never make two configs unequal; configs that
+/// differ only in what they trigger afterwards are equal. Easings compare by function
+/// identity, and a `NaN` field, like `f32::NAN`, is never equal to anything.
+impl PartialEq for AnimationConfig {
+    fn eq(&self, other: &Self) -> bool {
+        self.mode == other.mode
+            && self.loop_mode == other.loop_mode
+            && self.delay == other.delay
+            && self.time_offset == other.time_offset
+            && self.epsilon == other.epsilon
+            && self.speed == other.speed
+            && self.loop_jitter == other.loop_jitter
+            && self.min_duration == other.min_duration
+            && self.max_velocity == other.max_velocity
+            && self.max_bounces == other.max_bounces
+            && self.priority == other.priority
+            && self.tween_speed == other.tween_speed
+    }
+}
+
+impl Eq for AnimationConfig {}
+
+impl Hash for AnimationConfig {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.mode.hash(state);
+        self.loop_mode.hash(state);
+        self.delay.hash(state);
+        self.time_offset.hash(state);
+        self.epsilon.is_some().hash(state);
+        if let Some(epsilon) = self.epsilon {
+            hash_f32(epsilon, state);
+        }
+        self.speed.is_some().hash(state);
+        if let Some(speed) = self.speed {
+            hash_f32(speed, state);
+        }
+        self.loop_jitter.hash(state);
+        self.min_duration.hash(state);
+        self.max_velocity.is_some().hash(state);
+        if let Some(max_velocity) = self.max_velocity {
+            hash_f32(max_velocity, state);
+        }
+        self.max_bounces.hash(state);
+        self.priority.hash(state);
+        self.tween_speed.is_some().hash(state);
+        if let Some(tween_speed) = self.tween_speed {
+            hash_f32(tween_speed, state);
+        }
+    }
 }
 
 impl AnimationConfig {
+    /// Slowest playback speed [`AnimationConfig::with_speed`] accepts
+    pub const MIN_SPEED: f32 = 0.01;
+
+    /// Fastest playback speed [`AnimationConfig::with_speed`] accepts
+    pub const MAX_SPEED: f32 = 100.0;
+
     /// Creates a new animation configuration with specified mode
     pub fn new(mode: AnimationMode) -> Self {
         Self {
             mode,
             loop_mode: None,
             delay: Duration::default(),
+            time_offset: None,
             on_complete: None,
+            on_cancel: None,
+            on_loop: None,
             epsilon: None,
+            speed: None,
+            loop_jitter: None,
+            min_duration: None,
+            max_velocity: None,
+            max_bounces: None,
+            chain: None,
+            priority: 0,
+            tween_speed: None,
         }
     }
 
@@ -128,23 +466,171 @@ impl AnimationConfig {
         Self::tween(Duration::from_millis(milliseconds))
     }
 
+    /// Creates a tween animation configuration lasting `seconds` with the given easing.
+    pub fn tween_secs(seconds: f32, easing: fn(f32, f32, f32, f32) -> f32) -> Self {
+        Self::new(AnimationMode::Tween(Tween::seconds(seconds, easing)))
+    }
+
+    /// Creates a tween that moves at `units_per_sec` instead of lasting a fixed time
+    ///
+    /// The duration is `(target - initial).magnitude() / units_per_sec`, worked out when
+    /// the animation starts, so long moves take proportionally longer than short ones. A
+    /// speed that is zero, negative or not finite gives a zero-length tween.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// use easer::functions::{Cubic, Easing};
+    /// // Every drag-release slides back at 800 pixels per second.
+    /// let snap_back = AnimationConfig::tween_speed(800.0, Cubic::ease_out);
+    /// ```
+    pub fn tween_speed(units_per_sec: f32, easing: fn(f32, f32, f32, f32) -> f32) -> Self {
+        Self {
+            tween_speed: Some(units_per_sec),
+            ..Self::new(AnimationMode::Tween(
+                Tween::new(Duration::ZERO).with_easing(easing),
+            ))
+        }
+    }
+
     /// Creates a spring animation configuration with the specified spring.
     pub fn spring(spring: Spring) -> Self {
         Self::new(AnimationMode::Spring(spring))
     }
 
+    /// Creates a spring animation configuration tuned for the target platform.
+    ///
+    /// Uses [`Spring::platform_default`]: softer on the web, stiffer on native.
+    pub fn for_platform() -> Self {
+        Self::spring(Spring::platform_default())
+    }
+
     /// Sets the loop mode for the animation
     pub fn with_loop(mut self, loop_mode: LoopMode) -> Self {
         self.loop_mode = Some(loop_mode);
         self
     }
 
+    /// Varies each loop repetition's duration by up to `± fraction`
+    ///
+    /// Pass a `seed` for reproducible timing (e.g. in tests); `None` uses a fresh
+    /// random seed whenever the animation starts. Has no effect without a loop mode.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let pulse = AnimationConfig::tween_ms(800)
+    ///     .with_loop(LoopMode::Infinite)
+    ///     .with_loop_jitter(0.15, None);
+    /// ```
+    pub fn with_loop_jitter(mut self, fraction: f32, seed: Option<u64>) -> Self {
+        self.loop_jitter = Some(LoopJitter { fraction, seed });
+        self
+    }
+
+    /// Guarantees the animation takes at least `duration` to finish
+    ///
+    /// Keeps tiny changes visibly animated. Shorter tweens are stretched to `duration`;
+    /// springs keep integrating normally but their progress toward the target is capped
+    /// at `elapsed / duration`, so they cannot settle before the floor.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let toggle = AnimationConfig::spring(Spring::default())
+    ///     .with_min_duration(Duration::from_millis(300));
+    /// ```
+    pub fn with_min_duration(mut self, duration: Duration) -> Self {
+        self.min_duration = Some(duration);
+        self
+    }
+
+    /// Caps how fast a spring may move, in value units per second
+    ///
+    /// Applied on every integration step, so a fling seeded with extreme velocity keeps
+    /// its direction but never travels more than `max_velocity * dt` in a frame. For
+    /// multi-dimensional values the cap bounds the velocity's magnitude; per-field
+    /// [`AnimationMode::ComponentSpring`] springs cap each field. Tweens ignore it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let fling = AnimationConfig::spring(Spring::default()).with_max_velocity(3000.0);
+    /// ```
+    pub fn with_max_velocity(mut self, max_velocity: f32) -> Self {
+        self.max_velocity = Some(max_velocity);
+        self
+    }
+
+    /// Lets a spring change direction `bounces` times, then settles it without more overshoot
+    ///
+    /// Each reversal of the spring's velocity counts as one bounce. Once the limit is
+    /// reached, the rest of the motion is critically damped, so a bouncy spring gives a
+    /// fixed number of playful wobbles instead of ringing on. Tweens ignore it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let boing = AnimationConfig::spring(Spring::from_response_bounce(0.5, 0.8))
+    ///     .with_max_bounces(2);
+    /// ```
+    pub fn with_max_bounces(mut self, bounces: u8) -> Self {
+        self.max_bounces = Some(bounces);
+        self
+    }
+
+    /// Animates on to `next_target` with `next_config` once this animation completes
+    ///
+    /// A lighter alternative to [`crate::sequence::AnimationSequence`] for two-phase
+    /// motion such as overshoot-then-settle. This config's `on_complete` still runs
+    /// when the first phase ends, and spring velocity carries into the next phase.
+    /// Chains nest: `next_config` may have its own chain. The chain is ignored if
+    /// `next_target` is not the motion's value type.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let overshoot = AnimationConfig::tween_ms(150)
+    ///     .chain(100.0f32, AnimationConfig::spring(Spring::default()));
+    /// ```
+    pub fn chain<T: Animatable + Send + Sync>(
+        mut self,
+        next_target: T,
+        next_config: AnimationConfig,
+    ) -> Self {
+        self.chain = Some(Arc::new(ChainedAnimation {
+            target: Box::new(next_target),
+            config: next_config,
+        }));
+        self
+    }
+
     /// Sets a delay before the animation starts
     pub fn with_delay(mut self, delay: Duration) -> Self {
         self.delay = delay;
         self
     }
 
+    /// Starts the animation `offset` into its run instead of from the beginning
+    ///
+    /// Unlike [`AnimationConfig::with_delay`], which waits and then plays from the
+    /// start, the first frame is already `offset` along, so staggered items appear to
+    /// catch up. Tweens jump to the value at that elapsed time. Single springs jump to
+    /// the position and velocity they would have reached, solved in closed form from
+    /// the velocity they start with, so a spring taking over a running tween keeps its
+    /// momentum; component springs only count the offset as elapsed time. Loop repeats
+    /// and keyframe animations start from the beginning as usual.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let catch_up = AnimationConfig::tween_ms(1000).with_time_offset(Duration::from_millis(300));
+    /// ```
+    pub fn with_time_offset(mut self, offset: Duration) -> Self {
+        self.time_offset = Some(offset);
+        self
+    }
+
     /// Sets a callback to be called when animation completes
     pub fn with_on_complete<F>(mut self, f: F) -> Self
     where
@@ -154,6 +640,76 @@ impl AnimationConfig {
         self
     }
 
+    /// Sets a callback to be called when the animation is stopped before it completes
+    ///
+    /// Runs when a running, paused or delayed animation is ended by `stop`, `reset`,
+    /// `cancel_delay` or [`crate::stop_all`]. It does not run when the animation
+    /// completes, or when another animation takes over the motion.
+    pub fn with_on_cancel<F>(mut self, f: F) -> Self
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.on_cancel = Some(Arc::new(Mutex::new(f)));
+        self
+    }
+
+    /// Sets a callback run each time a looping animation starts another iteration
+    ///
+    /// The callback receives the index of the iteration that is starting, counting the
+    /// first play as `0`, so `LoopMode::Times(3)` calls it with `1` and `2` before
+    /// `on_complete` runs. Alternating loops count each reversal as an iteration, and
+    /// infinite loops keep calling it, wrapping the index after 255.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// use std::sync::{
+    ///     Arc,
+    ///     atomic::{AtomicU32, Ordering},
+    /// };
+    ///
+    /// let beats = Arc::new(AtomicU32::new(0));
+    /// let counter = Arc::clone(&beats);
+    /// let pulse = AnimationConfig::tween_ms(400)
+    ///     .with_loop(LoopMode::Infinite)
+    ///     .with_on_loop(move |_| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     });
+    /// ```
+    pub fn with_on_loop<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(u8) + Send + 'static,
+    {
+        self.on_loop = Some(Arc::new(Mutex::new(f)));
+        self
+    }
+
+    /// Sets the priority that lower-priority animations must wait out
+    ///
+    /// While an animation with priority `p` is running or paused, [`Motion::animate_to`]
+    /// with a config of lower priority is ignored, so a programmatic animation can keep
+    /// hover effects from interrupting it. Sequences take the priority of their first
+    /// step, paths that of their config, and keyframes and `follow` have priority `0`.
+    /// Equal priorities replace each other as usual, and every config starts at `0`, so
+    /// by default the last call wins.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// use dioxus_motion::motion::Motion;
+    ///
+    /// let mut motion = Motion::new(0.0f32);
+    /// motion.animate_to(100.0, AnimationConfig::tween_ms(500).with_priority(1));
+    /// motion.animate_to(20.0, AnimationConfig::tween_ms(200));
+    /// assert_eq!(motion.target, 100.0);
+    /// ```
+    ///
+    /// [`Motion::animate_to`]: crate::motion::Motion::animate_to
+    pub fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
     /// Sets a custom epsilon threshold for animation completion detection
     ///
     /// # Arguments
@@ -170,21 +726,72 @@ impl AnimationConfig {
         self
     }
 
+    /// Sets a playback speed multiplier for the animation
+    ///
+    /// Tweens and keyframes finish in `duration / multiplier`, and springs integrate
+    /// with a proportionally scaled time step. The delay is not affected.
+    ///
+    /// Multipliers are clamped to [`AnimationConfig::MIN_SPEED`]..=[`AnimationConfig::MAX_SPEED`],
+    /// so zero, negative and NaN values play at the minimum speed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let config = AnimationConfig::tween_ms(300).with_speed(1.5);
+    /// assert_eq!(config.speed_multiplier(), 1.5);
+    ///
+    /// let paused = AnimationConfig::tween_ms(300).with_speed(0.0);
+    /// assert_eq!(paused.speed_multiplier(), AnimationConfig::MIN_SPEED);
+    /// ```
+    pub fn with_speed(mut self, multiplier: f32) -> Self {
+        let multiplier = if multiplier.is_nan() {
+            Self::MIN_SPEED
+        } else {
+            multiplier.clamp(Self::MIN_SPEED, Self::MAX_SPEED)
+        };
+        self.speed = Some(multiplier);
+        self
+    }
+
+    /// Returns the playback speed multiplier, defaulting to 1.0
+    pub fn speed_multiplier(&self) -> f32 {
+        self.speed
+            .filter(|speed| speed.is_finite() && *speed > 0.0)
+            .unwrap_or(1.0)
+    }
+
+    /// Fixes a speed-based tween's duration for covering `distance`; other configs are unchanged
+    pub(crate) fn resolve_tween_speed(&mut self, distance: f32) {
+        if let (Some(speed), AnimationMode::Tween(tween)) = (self.tween_speed, &mut self.mode) {
+            tween.duration = Duration::try_from_secs_f32(distance / speed).unwrap_or_default();
+        }
+    }
+
+    /// Duration of one run of `tween`, stretched to the minimum duration if set
+    pub(crate) fn tween_duration(&self, tween: &Tween) -> Duration {
+        self.min_duration
+            .map_or(tween.duration, |floor| tween.duration.max(floor))
+    }
+
     /// Gets the total duration of the animation
     pub fn get_duration(&self) -> Duration {
         match &self.mode {
-            AnimationMode::Spring(_) => {
+            AnimationMode::Spring(_) | AnimationMode::ComponentSpring(_) => {
                 // Springs don't have a fixed duration, estimate based on typical settling time
                 Duration::from_secs_f32(1.0) // You might want to adjust this based on spring parameters
             }
             AnimationMode::Tween(tween) => {
-                let base_duration = tween.duration;
+                let base_duration = self.tween_duration(tween);
                 match self.loop_mode {
                     Some(LoopMode::Infinite) => Duration::from_secs(f32::INFINITY as u64),
-                    Some(LoopMode::Times(count)) => base_duration * count.into(),
-                    Some(LoopMode::Alternate) => Duration::from_secs(f32::INFINITY as u64),
-                    Some(LoopMode::AlternateTimes(count)) => base_duration * (count * 2).into(),
-                    Some(LoopMode::None) | None => base_duration,
+                    Some(LoopMode::Times(count)) => base_duration * u32::from(count.max(1)),
+                    Some(LoopMode::Alternate | LoopMode::PingPong { .. }) => {
+                        Duration::from_secs(f32::INFINITY as u64)
+                    }
+                    Some(LoopMode::AlternateTimes(count)) if count > 0 => {
+                        base_duration * (u32::from(count) * 2)
+                    }
+                    Some(LoopMode::None | LoopMode::AlternateTimes(_)) | None => base_duration,
                 }
             }
         }
@@ -198,4 +805,22 @@ impl AnimationConfig {
             callback();
         }
     }
+
+    /// Execute the cancel callback if it exists
+    pub fn execute_cancel(&self) {
+        if let Some(on_cancel) = &self.on_cancel
+            && let Ok(mut callback) = on_cancel.lock()
+        {
+            callback();
+        }
+    }
+
+    /// Execute the loop callback if it exists
+    pub fn execute_loop(&self, index: u8) {
+        if let Some(on_loop) = &self.on_loop
+            && let Ok(mut callback) = on_loop.lock()
+        {
+            callback(index);
+        }
+    }
 }
@@ -408,6 +408,20 @@ impl IntoCssValue for String {
     }
 }
 
+/// Formats a CSS custom property declaration such as `--progress: 0.5;`.
+///
+/// The leading `--` is optional in `name`.
+///
+/// # Examples
+/// ```
+/// use dioxus_motion::animations::css::css_var_declaration;
+/// assert_eq!(css_var_declaration("progress", "0.5"), "--progress: 0.5;");
+/// ```
+pub fn css_var_declaration(name: &str, value: &str) -> String {
+    let name = name.trim().trim_start_matches("--");
+    format!("--{name}: {value};")
+}
+
 /// Parses a CSS value string into the appropriate [`CssValue`] variant.
 pub(crate) fn parse_css_string(value: &str) -> CssValue {
     let value = value.trim();
@@ -472,7 +486,7 @@ fn lerp(start: f32, end: f32, t: f32) -> f32 {
     start + (end - start) * t.clamp(0.0, 1.0)
 }
 
-fn format_number(value: f32) -> String {
+pub(crate) fn format_number(value: f32) -> String {
     let value = if value == -0.0 { 0.0 } else { value };
     let rounded = (value * 1_000_000.0).round() / 1_000_000.0;
     rounded.to_string()
@@ -702,6 +716,8 @@ fn parse_number_prefix(value: &str) -> Option<(usize, f32)> {
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used, clippy::panic, clippy::approx_constant)]
+
     use super::*;
 
     // ── helpers ──────────────────────────────────────────────────────────────
@@ -717,6 +733,21 @@ mod tests {
             && approx_eq(a.alpha, b.alpha)
     }
 
+    // ── css_var_declaration ──────────────────────────────────────────────────
+
+    #[test]
+    fn css_var_declaration_adds_prefix() {
+        assert_eq!(
+            css_var_declaration("progress", &format_number(0.25)),
+            "--progress: 0.25;"
+        );
+    }
+
+    #[test]
+    fn css_var_declaration_keeps_existing_prefix() {
+        assert_eq!(css_var_declaration("--angle", "45deg"), "--angle: 45deg;");
+    }
+
     // ── lerp ─────────────────────────────────────────────────────────────────
 
     #[test]
@@ -0,0 +1,169 @@
+//! Lengths of time as animated values
+//!
+//! [`AnimatableDuration`] lets a countdown or progress timer tick smoothly between
+//! two durations instead of jumping a whole second at a time.
+
+use crate::Duration;
+use crate::animations::core::Animatable;
+
+/// A length of time that can be animated, measured in seconds
+///
+/// Springs that overshoot zero may carry the raw value below it while they settle, so
+/// read it with [`AnimatableDuration::as_secs_f32`] or
+/// [`AnimatableDuration::as_duration`], which never go negative.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::animations::core::Animatable;
+/// use dioxus_motion::prelude::{AnimatableDuration, Duration};
+///
+/// let full = AnimatableDuration::from_secs(60.0);
+/// let halfway = full.interpolate(&AnimatableDuration::ZERO, 0.5);
+/// assert_eq!(halfway.as_duration(), Duration::from_secs(30));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct AnimatableDuration {
+    secs: f32,
+}
+
+impl AnimatableDuration {
+    /// No time at all
+    pub const ZERO: AnimatableDuration = AnimatableDuration { secs: 0.0 };
+
+    /// Creates a duration of `secs` seconds; negative or `NaN` values become zero
+    pub fn from_secs(secs: f32) -> Self {
+        Self {
+            secs: secs.max(0.0),
+        }
+    }
+
+    /// The duration in seconds, never below zero
+    pub fn as_secs_f32(&self) -> f32 {
+        self.secs.max(0.0)
+    }
+
+    /// The duration as a [`Duration`], never below zero
+    pub fn as_duration(&self) -> Duration {
+        Duration::from_secs_f32(self.as_secs_f32())
+    }
+}
+
+impl From<Duration> for AnimatableDuration {
+    fn from(duration: Duration) -> Self {
+        Self::from_secs(duration.as_secs_f32())
+    }
+}
+
+impl From<AnimatableDuration> for Duration {
+    fn from(duration: AnimatableDuration) -> Self {
+        duration.as_duration()
+    }
+}
+
+/// Sums, differences and scaled values stay signed, since springs use them for deltas
+/// and velocities; only the readers clamp at zero.
+impl std::ops::Add for AnimatableDuration {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            secs: self.secs + other.secs,
+        }
+    }
+}
+
+impl std::ops::Sub for AnimatableDuration {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            secs: self.secs - other.secs,
+        }
+    }
+}
+
+impl std::ops::Mul<f32> for AnimatableDuration {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        Self {
+            secs: self.secs * factor,
+        }
+    }
+}
+
+impl Animatable for AnimatableDuration {
+    /// Overshooting easings are floored at zero, so a tween never shows negative time.
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self {
+            secs: (self.secs + (target.secs - self.secs) * t).max(0.0),
+        }
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.secs.abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AnimatableDuration;
+    use crate::{
+        Duration, Motion,
+        animations::{core::AnimationConfig, spring::Spring},
+    };
+
+    #[test]
+    fn countdown_decreases_monotonically_to_zero() {
+        let mut motion = Motion::new(AnimatableDuration::from_secs(60.0));
+        motion.animate_to(AnimatableDuration::ZERO, AnimationConfig::tween_ms(1000));
+
+        let mut previous = motion.get_value().as_secs_f32();
+        while motion.update(1.0 / 60.0) {
+            let remaining = motion.get_value().as_secs_f32();
+            assert!(remaining <= previous, "{remaining} after {previous}");
+            previous = remaining;
+        }
+        assert_eq!(motion.get_value().as_duration(), Duration::ZERO);
+    }
+
+    #[test]
+    fn overshooting_spring_never_reads_below_zero() {
+        let mut motion = Motion::new(AnimatableDuration::from_secs(60.0));
+        motion.animate_to(
+            AnimatableDuration::ZERO,
+            AnimationConfig::spring(Spring {
+                damping: 4.0,
+                ..Spring::default()
+            }),
+        );
+
+        let mut overshot = false;
+        let mut frames = 0;
+        while motion.update(1.0 / 60.0) {
+            let value = motion.get_value();
+            overshot |= value.secs < 0.0;
+            assert!(value.as_secs_f32() >= 0.0);
+            frames += 1;
+            assert!(frames < 2000, "spring should settle");
+        }
+        assert!(overshot, "the spring should swing past zero");
+        assert_eq!(motion.get_value(), AnimatableDuration::ZERO);
+    }
+
+    #[test]
+    fn constructors_floor_negative_time() {
+        assert_eq!(
+            AnimatableDuration::from_secs(-5.0),
+            AnimatableDuration::ZERO
+        );
+        assert_eq!(
+            AnimatableDuration::from(Duration::from_millis(1500)).as_secs_f32(),
+            1.5
+        );
+        assert_eq!(
+            Duration::from(AnimatableDuration::from_secs(2.0)),
+            Duration::from_secs(2)
+        );
+    }
+}
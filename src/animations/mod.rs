@@ -1,11 +1,19 @@
 pub mod benchmarks;
+pub mod bounds;
 pub mod closure_pool;
 pub mod colors;
+pub mod composite;
 pub mod core;
 pub mod css;
+pub mod duration;
 pub mod epsilon;
+pub mod modular;
+pub mod path;
 pub mod platform;
+pub mod precise;
 pub mod spring;
 pub mod style;
+pub mod text;
 pub mod transform;
 pub mod tween;
+pub mod vec2;
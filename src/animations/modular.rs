@@ -0,0 +1,202 @@
+//! Values on a ring, such as angles and hues
+//!
+//! [`Modular`] animates across the wrap boundary along the shortest path, so an angle
+//! going from 350° to 10° turns 20° forward instead of 340° back.
+
+use crate::animations::core::Animatable;
+
+/// A value that wraps at `modulus`, animated along the shortest path around the ring
+///
+/// The raw value is kept unwrapped while animating so velocities and intermediate
+/// values stay continuous; read it with [`Modular::value`], which wraps it into
+/// `0.0..modulus`. A modulus that is zero, negative or not finite disables wrapping.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::animations::core::Animatable;
+/// use dioxus_motion::prelude::Modular;
+///
+/// let from = Modular::degrees(350.0);
+/// let halfway = from.interpolate(&Modular::degrees(10.0), 0.5);
+/// assert_eq!(halfway.value(), 0.0);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Modular {
+    raw: f32,
+    modulus: f32,
+}
+
+impl Modular {
+    /// Creates a value wrapping at `modulus`
+    pub fn new(value: f32, modulus: f32) -> Self {
+        let modulus = if modulus.is_finite() && modulus > 0.0 {
+            modulus
+        } else {
+            0.0
+        };
+        Self {
+            raw: wrap(value, modulus),
+            modulus,
+        }
+    }
+
+    /// An angle in degrees, wrapping at 360
+    pub fn degrees(value: f32) -> Self {
+        Self::new(value, 360.0)
+    }
+
+    /// A hue or other unit-interval value, wrapping at 1.0
+    pub fn unit(value: f32) -> Self {
+        Self::new(value, 1.0)
+    }
+
+    /// The value wrapped into `0.0..modulus`
+    pub fn value(&self) -> f32 {
+        wrap(self.raw, self.modulus)
+    }
+
+    /// The wrap-around point, or `0.0` if the value does not wrap
+    pub fn modulus(&self) -> f32 {
+        self.modulus
+    }
+
+    /// Picks the modulus of whichever operand has one; deltas and velocities carry none.
+    fn shared_modulus(&self, other: &Self) -> f32 {
+        if self.modulus > 0.0 {
+            self.modulus
+        } else {
+            other.modulus
+        }
+    }
+}
+
+fn wrap(value: f32, modulus: f32) -> f32 {
+    if modulus > 0.0 {
+        value.rem_euclid(modulus)
+    } else {
+        value
+    }
+}
+
+/// Signed difference `to - from` along the shorter way around the ring
+fn shortest_delta(from: f32, to: f32, modulus: f32) -> f32 {
+    if modulus <= 0.0 {
+        return to - from;
+    }
+
+    let delta = (to - from).rem_euclid(modulus);
+    if delta > modulus / 2.0 {
+        delta - modulus
+    } else {
+        delta
+    }
+}
+
+impl std::ops::Add for Modular {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            raw: self.raw + other.raw,
+            modulus: self.shared_modulus(&other),
+        }
+    }
+}
+
+/// Subtraction yields the shortest signed delta, which is what springs and completion
+/// checks measure. The delta itself does not wrap, so velocities built from it keep
+/// their sign and size.
+impl std::ops::Sub for Modular {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            raw: shortest_delta(other.raw, self.raw, self.shared_modulus(&other)),
+            modulus: 0.0,
+        }
+    }
+}
+
+impl std::ops::Mul<f32> for Modular {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        Self {
+            raw: self.raw * factor,
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Animatable for Modular {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        let modulus = self.shared_modulus(target);
+        Self {
+            raw: self.raw + shortest_delta(self.raw, target.raw, modulus) * t,
+            modulus,
+        }
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.raw.abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Modular;
+    use crate::{
+        Motion,
+        animations::{core::Animatable, spring::Spring},
+        prelude::AnimationConfig,
+    };
+
+    fn value_at_quarter(from: f32, to: f32) -> f32 {
+        let mut motion = Motion::new(Modular::degrees(from));
+        motion.animate_to(Modular::degrees(to), AnimationConfig::tween_ms(1000));
+        motion.update(0.25);
+        motion.get_value().value()
+    }
+
+    #[test]
+    fn crosses_the_wrap_forward_by_the_short_way() {
+        assert!((value_at_quarter(350.0, 10.0) - 355.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn crosses_the_wrap_backward_by_the_short_way() {
+        assert!((value_at_quarter(10.0, 350.0) - 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn spring_settles_on_the_wrapped_target() {
+        let mut motion = Motion::new(Modular::degrees(340.0));
+        motion.animate_to(
+            Modular::degrees(20.0),
+            AnimationConfig::spring(Spring::default()),
+        );
+
+        let mut frames = 0;
+        while motion.update(1.0 / 60.0) {
+            let value = motion.get_value().value();
+            assert!(
+                !(60.0..300.0).contains(&value),
+                "took the long way: {value}"
+            );
+            frames += 1;
+            assert!(frames < 1000, "spring should settle");
+        }
+        assert_eq!(motion.get_value().value(), 20.0);
+    }
+
+    #[test]
+    fn difference_is_the_shortest_signed_delta() {
+        assert_eq!(
+            (Modular::degrees(10.0) - Modular::degrees(350.0)).magnitude(),
+            20.0
+        );
+        assert!(((Modular::unit(0.9) - Modular::unit(0.1)).magnitude() - 0.2).abs() < 1e-6);
+        assert_eq!(Modular::degrees(-90.0).value(), 270.0);
+        assert_eq!(Modular::new(725.0, f32::NAN).value(), 725.0);
+    }
+}
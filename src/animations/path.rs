@@ -0,0 +1,96 @@
+//! SVG path drawing helpers
+//!
+//! A "draw" animation reveals a stroke by animating `stroke-dashoffset` from the
+//! path's total length down to zero while `stroke-dasharray` stays at that length.
+
+use crate::animations::css::format_number;
+
+/// Dash geometry for drawing an SVG path of a known length
+///
+/// The animated value is the dash offset: `total_length` hides the stroke entirely
+/// and `0.0` shows all of it. See `use_path_draw` for the hook that animates it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathDraw {
+    total_length: f32,
+}
+
+impl PathDraw {
+    /// Creates a path draw for a path of `total_length` user units
+    ///
+    /// The length usually comes from `SVGPathElement.getTotalLength()`; a slightly
+    /// larger value is fine and only delays the visible start of the stroke.
+    pub fn new(total_length: f32) -> Self {
+        Self {
+            total_length: total_length.max(0.0),
+        }
+    }
+
+    /// The total length of the path
+    pub fn total_length(&self) -> f32 {
+        self.total_length
+    }
+
+    /// Dash offset for a drawing progress from 0.0 (hidden) to 1.0 (fully drawn)
+    pub fn offset_at(&self, progress: f32) -> f32 {
+        self.total_length * (1.0 - progress.clamp(0.0, 1.0))
+    }
+
+    /// Inline CSS for the path at the given dash offset
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::PathDraw;
+    /// let draw = PathDraw::new(120.0);
+    /// assert_eq!(
+    ///     draw.style(draw.offset_at(0.25)),
+    ///     "stroke-dasharray: 120; stroke-dashoffset: 90;"
+    /// );
+    /// ```
+    pub fn style(&self, dash_offset: f32) -> String {
+        format!(
+            "stroke-dasharray: {}; stroke-dashoffset: {};",
+            format_number(self.total_length),
+            format_number(dash_offset)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Motion;
+    use crate::prelude::AnimationConfig;
+
+    #[test]
+    fn test_path_draw_offset_bounds() {
+        let draw = PathDraw::new(300.0);
+
+        assert_eq!(draw.offset_at(0.0), 300.0);
+        assert_eq!(draw.offset_at(1.0), 0.0);
+        assert_eq!(draw.offset_at(2.0), 0.0);
+        assert_eq!(PathDraw::new(-5.0).total_length(), 0.0);
+    }
+
+    #[test]
+    fn test_path_draw_offset_animates_from_length_to_zero() {
+        let draw = PathDraw::new(300.0);
+        let mut motion = Motion::new(draw.offset_at(0.0));
+        motion.animate_to(draw.offset_at(1.0), AnimationConfig::tween_ms(500));
+
+        assert_eq!(
+            draw.style(motion.get_value()),
+            "stroke-dasharray: 300; stroke-dashoffset: 300;"
+        );
+
+        let mut previous = motion.get_value();
+        while motion.update(1.0 / 60.0) {
+            assert!(motion.get_value() <= previous);
+            previous = motion.get_value();
+        }
+
+        assert_eq!(
+            draw.style(motion.get_value()),
+            "stroke-dasharray: 300; stroke-dashoffset: 0;"
+        );
+    }
+}
@@ -124,6 +124,93 @@ impl TimeProvider for MotionTime {
 /// Type alias for the default time provider
 pub type Time = MotionTime;
 
+/// Longest step a single frame may advance animations, in seconds
+pub(crate) const MAX_FRAME_DELTA: f32 = 0.1;
+
+/// Frames arriving closer together than this, in seconds, are merged into the next one
+const MIN_FRAME_DELTA: f32 = 0.001;
+
+/// Turns frame timestamps into animation time steps
+///
+/// Throttled tabs deliver frames late or in bursts. Late frames are capped at
+/// [`MAX_FRAME_DELTA`] so motions don't jump, and frames bunched within a millisecond
+/// of the last step are skipped so their time carries into the next one. While the
+/// page is hidden no steps are produced, and the first frame after it becomes visible
+/// again only sets a new baseline instead of replaying the time spent hidden.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(not(feature = "dioxus"), allow(dead_code))]
+pub(crate) struct FrameClock {
+    last_frame: Option<Instant>,
+    hidden: bool,
+    smoothed_dt: Option<f32>,
+}
+
+#[cfg_attr(not(feature = "dioxus"), allow(dead_code))]
+impl FrameClock {
+    /// Records whether the page is hidden; hiding forgets the last frame.
+    pub(crate) fn set_hidden(&mut self, hidden: bool) {
+        if hidden {
+            self.reset();
+        }
+        self.hidden = hidden;
+    }
+
+    pub(crate) fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Forgets the last frame, so the next tick starts a new baseline.
+    pub(crate) fn reset(&mut self) {
+        self.last_frame = None;
+        self.smoothed_dt = None;
+    }
+
+    /// Time step for a frame at `now`, or `None` if this frame should not advance motions.
+    pub(crate) fn tick(&mut self, now: Instant) -> Option<f32> {
+        if self.hidden {
+            return None;
+        }
+        let Some(last_frame) = self.last_frame else {
+            self.last_frame = Some(now);
+            return None;
+        };
+
+        let dt = now.duration_since(last_frame).as_secs_f32();
+        if dt < MIN_FRAME_DELTA {
+            return None;
+        }
+        self.last_frame = Some(now);
+        Some(dt.min(MAX_FRAME_DELTA))
+    }
+
+    /// Blends `dt` into the running average of recent steps, weighting the average by `factor`.
+    ///
+    /// A `factor` of zero passes `dt` through unchanged. The first step after a reset
+    /// starts the average.
+    pub(crate) fn smooth(&mut self, dt: f32, factor: f32) -> f32 {
+        let smoothed = self
+            .smoothed_dt
+            .map_or(dt, |average| factor * average + (1.0 - factor) * dt);
+        self.smoothed_dt = Some(smoothed);
+        smoothed
+    }
+}
+
+/// Whether the page is in a background tab or otherwise hidden; always false off the web.
+#[cfg_attr(not(feature = "dioxus"), allow(dead_code))]
+pub(crate) fn document_hidden() -> bool {
+    #[cfg(feature = "web")]
+    {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .is_some_and(|document| document.hidden())
+    }
+    #[cfg(not(feature = "web"))]
+    {
+        false
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,6 +229,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn frame_clock_caps_late_frames_and_merges_bunched_ones() {
+        let start = Instant::now();
+        let mut clock = FrameClock::default();
+
+        assert_eq!(
+            clock.tick(start),
+            None,
+            "first frame only sets the baseline"
+        );
+        assert_eq!(
+            clock.tick(start + Duration::from_secs(2)),
+            Some(MAX_FRAME_DELTA)
+        );
+
+        let bunched = start + Duration::from_secs(2) + Duration::from_micros(200);
+        assert_eq!(clock.tick(bunched), None);
+        let next = clock.tick(start + Duration::from_secs(2) + Duration::from_millis(16));
+        assert!(next.is_some_and(|dt| (dt - 0.016).abs() < 1e-4));
+    }
+
+    #[test]
+    fn frame_clock_pauses_while_hidden_and_resumes_from_a_new_baseline() {
+        use crate::{Motion, animations::core::AnimationConfig};
+
+        let frame = Duration::from_millis(16);
+        let mut now = Instant::now();
+        let mut clock = FrameClock::default();
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(1000));
+        let mut run_frames = |clock: &mut FrameClock, now: &mut Instant, frames: u32| {
+            let mut steps = 0;
+            for _ in 0..frames {
+                *now += frame;
+                if let Some(dt) = clock.tick(*now) {
+                    motion.update(dt);
+                    steps += 1;
+                }
+            }
+            (steps, motion.get_value())
+        };
+
+        let (_, before_hiding) = run_frames(&mut clock, &mut now, 10);
+        clock.set_hidden(true);
+        let (steps, while_hidden) = run_frames(&mut clock, &mut now, 120);
+        assert_eq!(steps, 0, "no updates while hidden");
+        assert_eq!(while_hidden, before_hiding);
+
+        clock.set_hidden(false);
+        let (steps, resumed) = run_frames(&mut clock, &mut now, 2);
+        assert_eq!(steps, 1, "first visible frame re-establishes the baseline");
+        assert!(
+            resumed - before_hiding < 2.0,
+            "resumed without a jump: {resumed}"
+        );
+    }
+
+    #[test]
+    fn smoothing_damps_alternating_frames_but_follows_rate_changes() {
+        fn variance(values: &[f32]) -> f32 {
+            let mean = values.iter().sum::<f32>() / values.len() as f32;
+            values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / values.len() as f32
+        }
+
+        let mut clock = FrameClock::default();
+        let raw: Vec<f32> = (0..60)
+            .map(|i| if i % 2 == 0 { 0.008 } else { 0.025 })
+            .collect();
+        let smoothed: Vec<f32> = raw.iter().map(|&dt| clock.smooth(dt, 0.7)).collect();
+        assert!(variance(&smoothed[10..]) < variance(&raw[10..]) / 4.0);
+
+        // A real drop from 60fps to 30fps comes through within a few frames.
+        let mut clock = FrameClock::default();
+        (0..30).for_each(|_| {
+            clock.smooth(1.0 / 60.0, 0.7);
+        });
+        let caught_up = (0..8).fold(0.0, |_, _| clock.smooth(1.0 / 30.0, 0.7));
+        assert!((caught_up - 1.0 / 30.0).abs() < 0.1 / 30.0, "{caught_up}");
+
+        let mut clock = FrameClock::default();
+        assert_eq!(clock.smooth(0.016, 0.0), 0.016);
+        assert_eq!(clock.smooth(0.032, 0.0), 0.032);
+        clock.reset();
+        assert_eq!(clock.smooth(0.05, 0.5), 0.05, "reset restarts the average");
+    }
+
     #[cfg(not(feature = "web"))]
     #[tokio::test]
     async fn test_desktop_sleep_threshold_optimization() {
@@ -0,0 +1,120 @@
+//! Double-precision animated values
+//!
+//! [`Animatable`] requires `Mul<f32>`, and Rust's orphan rules forbid implementing that
+//! for the foreign `f64`, so [`Precise`] wraps an `f64` instead. The value, its velocity
+//! and every spring step stay in `f64`; only the time step and spring constants are
+//! `f32`, which scale the value without limiting its precision.
+
+use crate::animations::core::Animatable;
+
+/// An `f64` animated without losing precision to `f32`
+///
+/// Use it for values an `f32` cannot hold exactly, such as coordinates in a large map
+/// or financial figures: at `1e12` an `f32` can only step in increments of 65536. The
+/// completion epsilon is the usual `0.01`; lower it with
+/// [`AnimationConfig::with_epsilon`](crate::animations::core::AnimationConfig::with_epsilon)
+/// for finer settling.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::animations::core::Animatable;
+/// use dioxus_motion::prelude::Precise;
+///
+/// let from = Precise(1.0e12);
+/// let halfway = from.interpolate(&Precise(1.0e12 + 1.0), 0.5);
+/// assert_eq!(halfway, Precise(1.0e12 + 0.5));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Precise(pub f64);
+
+impl From<f64> for Precise {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Precise> for f64 {
+    fn from(value: Precise) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Add for Precise {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl std::ops::Sub for Precise {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl std::ops::Mul<f32> for Precise {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        Self(self.0 * f64::from(factor))
+    }
+}
+
+impl Animatable for Precise {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self(self.0 + (target.0 - self.0) * f64::from(t))
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.0.abs() as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Precise;
+    use crate::{
+        Motion,
+        animations::spring::Spring,
+        prelude::{AnimationConfig, Duration},
+    };
+
+    const BASE: f64 = 1.0e12;
+
+    #[test]
+    fn tween_steps_finer_than_f32_can_represent() {
+        // Every f32 between these endpoints rounds to the same value.
+        assert_eq!(BASE as f32, (BASE + 100.0) as f32);
+
+        let mut motion = Motion::new(Precise(BASE));
+        motion.animate_to(Precise(BASE + 100.0), AnimationConfig::tween_ms(1000));
+        motion.update(0.25);
+        assert!((motion.get_value().0 - (BASE + 25.0)).abs() < 1e-3);
+
+        while motion.update(1.0 / 60.0) {}
+        assert_eq!(motion.get_value(), Precise(BASE + 100.0));
+    }
+
+    #[test]
+    fn spring_converges_on_a_high_precision_target() {
+        // An f32 near 1e9 can only step in increments of 64.
+        let base = 1.0e9;
+        let target = Precise(base + 0.123_456);
+        let mut motion = Motion::new(Precise(base - 40.0));
+        motion.animate_to(
+            target,
+            AnimationConfig::spring(Spring::default()).with_epsilon(1e-3),
+        );
+
+        let mut elapsed = Duration::ZERO;
+        while motion.update(1.0 / 60.0) {
+            elapsed += Duration::from_secs_f32(1.0 / 60.0);
+            assert!(elapsed < Duration::from_secs(20), "spring should settle");
+            assert!(motion.get_value().0 > base - 41.0);
+        }
+        assert_eq!(motion.get_value(), target);
+    }
+}
@@ -6,6 +6,8 @@
 #[cfg(feature = "dioxus")]
 use dioxus::prelude::Store;
 
+use crate::animations::core::{Animatable, AnimationConfig, hash_f32};
+
 /// Configuration for spring-based animations
 ///
 /// Uses a mass-spring-damper system to create natural motion.
@@ -40,6 +42,15 @@ pub struct Spring {
     pub velocity: f32,
 }
 
+impl std::hash::Hash for Spring {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        hash_f32(self.stiffness, state);
+        hash_f32(self.damping, state);
+        hash_f32(self.mass, state);
+        hash_f32(self.velocity, state);
+    }
+}
+
 /// Default spring configuration for general-purpose animations
 impl Default for Spring {
     fn default() -> Self {
@@ -52,6 +63,220 @@ impl Default for Spring {
     }
 }
 
+impl Spring {
+    /// Creates a spring from a perceptual `response` and `bounce`, like SwiftUI springs
+    ///
+    /// `response` is the period of the undamped oscillation in seconds, roughly how long
+    /// the motion takes. `bounce` of 0.0 is critically damped (no overshoot), positive
+    /// values up to 1.0 overshoot increasingly, and negative values are over-damped.
+    /// Mass is fixed at 1.0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Spring;
+    /// let snappy = Spring::from_response_bounce(0.35, 0.2);
+    /// assert!(snappy.damping_ratio() < 1.0);
+    /// ```
+    pub fn from_response_bounce(response: f32, bounce: f32) -> Self {
+        use std::f32::consts::PI;
+
+        let response = response.max(0.01);
+        let bounce = bounce.clamp(-0.99, 1.0);
+        let stiffness = (2.0 * PI / response).powi(2);
+        let damping = if bounce >= 0.0 {
+            4.0 * PI * (1.0 - bounce) / response
+        } else {
+            4.0 * PI / (response * (1.0 + bounce))
+        };
+
+        Self {
+            stiffness,
+            damping,
+            mass: 1.0,
+            velocity: 0.0,
+        }
+    }
+
+    /// Animation config for this spring that critically damps it after `bounces`
+    /// changes of direction
+    ///
+    /// Shorthand for `AnimationConfig::spring(self).with_max_bounces(bounces)`; see
+    /// [`AnimationConfig::with_max_bounces`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let boing = Spring::from_response_bounce(0.5, 0.8).with_max_bounces(2);
+    /// ```
+    pub fn with_max_bounces(self, bounces: u8) -> AnimationConfig {
+        AnimationConfig::spring(self).with_max_bounces(bounces)
+    }
+
+    /// The same spring with just enough damping to settle without overshooting.
+    pub(crate) fn critically_damped(self) -> Self {
+        Self {
+            damping: 2.0 * (self.stiffness * self.mass).sqrt(),
+            ..self
+        }
+    }
+
+    /// Spring tuned for browsers: softer, so the fixed-step web integrator stays smooth
+    /// at uneven frame rates (stiffness 200, damping 20)
+    pub fn web_default() -> Self {
+        Self {
+            stiffness: 200.0,
+            damping: 20.0,
+            ..Self::default()
+        }
+    }
+
+    /// Spring tuned for desktop and mobile: stiffer and snappier, matching the steadier
+    /// native frame pacing (stiffness 300, damping 30)
+    pub fn native_default() -> Self {
+        Self {
+            stiffness: 300.0,
+            damping: 30.0,
+            ..Self::default()
+        }
+    }
+
+    /// [`Spring::web_default`] when built with the `web` feature, otherwise
+    /// [`Spring::native_default`], so apps need no `#[cfg]` of their own
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::{AnimationConfig, Spring};
+    /// let config = AnimationConfig::spring(Spring::platform_default());
+    /// ```
+    pub fn platform_default() -> Self {
+        if cfg!(feature = "web") {
+            Self::web_default()
+        } else {
+            Self::native_default()
+        }
+    }
+
+    /// Ratio of the damping to critical damping
+    ///
+    /// 1.0 is critically damped, below 1.0 overshoots and above 1.0 creeps in slowly.
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Closed-form velocity of the unclamped spring after `t` seconds.
+    ///
+    /// Returns the factors `(c, d)` such that the velocity is
+    /// `c * initial_displacement + d * initial_velocity`, the derivatives of
+    /// [`Spring::response`]'s factors. A spring that never moves gives `(0.0, 0.0)`.
+    pub(crate) fn velocity_response(&self, t: f32) -> (f32, f32) {
+        if self.stiffness <= 0.0 || self.mass <= 0.0 {
+            return (0.0, 0.0);
+        }
+        let (position, velocity) = self.response(t);
+        // With x'' = -(k/m)x - (c/m)x', the derivative of the solution released from
+        // rest is -(k/m) times the solution started from unit velocity, and the
+        // latter's derivative follows from the equation itself.
+        (
+            -self.stiffness / self.mass * velocity,
+            position - self.damping / self.mass * velocity,
+        )
+    }
+
+    /// Closed-form response of the unclamped spring after `t` seconds.
+    ///
+    /// Returns the factors `(a, b)` such that the displacement from the target is
+    /// `a * initial_displacement + b * initial_velocity`. A spring without positive
+    /// stiffness and mass never moves, giving `(1.0, 0.0)`.
+    pub(crate) fn response(&self, t: f32) -> (f32, f32) {
+        if self.stiffness <= 0.0 || self.mass <= 0.0 {
+            return (1.0, 0.0);
+        }
+
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping_ratio();
+
+        if zeta < 1.0 - 1e-4 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let decay = (-zeta * omega * t).exp();
+            let (sin, cos) = (omega_d * t).sin_cos();
+            (
+                decay * (cos + zeta * omega / omega_d * sin),
+                decay * sin / omega_d,
+            )
+        } else if zeta > 1.0 + 1e-4 {
+            let root = omega * (zeta * zeta - 1.0).sqrt();
+            let (fast, slow) = (-zeta * omega - root, -zeta * omega + root);
+            let (fast_decay, slow_decay) = ((fast * t).exp(), (slow * t).exp());
+            (
+                (slow * fast_decay - fast * slow_decay) / (slow - fast),
+                (slow_decay - fast_decay) / (slow - fast),
+            )
+        } else {
+            let decay = (-omega * t).exp();
+            (decay * (1.0 + omega * t), decay * t)
+        }
+    }
+}
+
+/// Independent spring parameters for each field of a [`Transform`](crate::prelude::Transform)
+///
+/// Used with `AnimationMode::ComponentSpring` so one axis can settle quickly
+/// while another stays soft, e.g. a snappy horizontal release with a gentle drop.
+/// The 3D rotations follow `rotation` and perspective follows `scale`.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::{Spring, TransformSprings};
+/// let springs = TransformSprings {
+///     x: Spring { stiffness: 400.0, damping: 40.0, ..Spring::default() },
+///     ..TransformSprings::uniform(Spring::default())
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct TransformSprings {
+    /// Spring driving the X translation
+    pub x: Spring,
+    /// Spring driving the Y translation
+    pub y: Spring,
+    /// Spring driving scale, perspective and the transform origin
+    pub scale: Spring,
+    /// Spring driving all rotation axes
+    pub rotation: Spring,
+}
+
+impl TransformSprings {
+    /// Uses the same spring for every field
+    pub fn uniform(spring: Spring) -> Self {
+        Self {
+            x: spring,
+            y: spring,
+            scale: spring,
+            rotation: spring,
+        }
+    }
+}
+
+impl Default for TransformSprings {
+    fn default() -> Self {
+        Self::uniform(Spring::default())
+    }
+}
+
+/// Scales `value` down to `max` magnitude, keeping its direction
+///
+/// A cap that is not a positive, finite number leaves `value` unchanged.
+pub(crate) fn clamp_magnitude<U: Animatable>(value: U, max: Option<f32>) -> U {
+    let Some(max) = max.filter(|max| max.is_finite() && *max > 0.0) else {
+        return value;
+    };
+    let magnitude = value.magnitude();
+    if magnitude > max {
+        value * (max / magnitude)
+    } else {
+        value
+    }
+}
+
 /// Represents the current state of a spring animation
 ///
 /// Used to track whether the spring is still moving or has settled
@@ -67,6 +292,43 @@ pub enum SpringState {
 mod tests {
     use super::*;
 
+    #[test]
+    fn clamp_magnitude_keeps_direction() {
+        use crate::prelude::Vec2;
+
+        assert_eq!(
+            clamp_magnitude(Vec2::new(30.0, -40.0), Some(5.0)),
+            Vec2::new(3.0, -4.0)
+        );
+        assert_eq!(
+            clamp_magnitude(Vec2::new(3.0, 4.0), Some(10.0)),
+            Vec2::new(3.0, 4.0)
+        );
+        assert_eq!(clamp_magnitude(-50.0f32, Some(f32::NAN)), -50.0);
+        assert_eq!(clamp_magnitude(-50.0f32, None), -50.0);
+    }
+
+    #[test]
+    fn platform_presets_settle_with_native_stiffer() {
+        use crate::{Motion, prelude::AnimationConfig};
+
+        let web = Spring::web_default();
+        let native = Spring::native_default();
+        assert!(native.stiffness > web.stiffness);
+        assert!(native.damping > web.damping);
+
+        for spring in [web, native, Spring::platform_default()] {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, AnimationConfig::spring(spring));
+            let mut frames = 0;
+            while motion.update(1.0 / 60.0) {
+                frames += 1;
+                assert!(frames < 600, "{spring:?} should settle");
+            }
+            assert_eq!(motion.get_value(), 100.0);
+        }
+    }
+
     #[test]
     fn test_spring_default() {
         let spring = Spring::default();
@@ -90,4 +352,45 @@ mod tests {
         assert_eq!(spring.mass, 2.0);
         assert_eq!(spring.velocity, 5.0);
     }
+
+    #[test]
+    fn test_spring_zero_bounce_is_critically_damped() {
+        let spring = Spring::from_response_bounce(0.5, 0.0);
+
+        assert!((spring.damping_ratio() - 1.0).abs() < 1e-4);
+        assert_eq!(spring.mass, 1.0);
+    }
+
+    #[test]
+    fn test_spring_positive_bounce_is_under_damped() {
+        assert!(Spring::from_response_bounce(0.5, 0.3).damping_ratio() < 1.0);
+        assert!(Spring::from_response_bounce(0.5, -0.3).damping_ratio() > 1.0);
+    }
+
+    #[test]
+    fn test_spring_response_controls_settling_time() {
+        use crate::Motion;
+        use crate::prelude::AnimationConfig;
+
+        let settle_frames = |response: f32| {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(
+                100.0,
+                AnimationConfig::spring(Spring::from_response_bounce(response, 0.0)),
+            );
+            let mut frames = 0;
+            while motion.update(1.0 / 60.0) {
+                frames += 1;
+                assert!(frames < 10_000, "spring never settled");
+            }
+            frames
+        };
+
+        let fast = settle_frames(0.2);
+        let medium = settle_frames(0.5);
+        let slow = settle_frames(1.0);
+
+        assert!(fast < medium);
+        assert!(medium < slow);
+    }
 }
@@ -88,6 +88,12 @@ pub struct MotionStyle {
     pub skew_y: f32,
     /// Transform perspective in pixels. A value of 0 omits perspective.
     pub perspective: f32,
+    /// Gaussian blur radius in pixels for the `filter` property. A value of 0 omits it.
+    pub blur: f32,
+    /// Brightness multiplier for the `filter` property. A value of 1 omits it.
+    pub brightness: f32,
+    /// Blur radius in pixels for the `backdrop-filter` property. A value of 0 omits it.
+    pub backdrop_blur: f32,
     /// Additional animated CSS properties keyed by kebab-case CSS property name.
     pub properties: BTreeMap<String, CssValue>,
 }
@@ -112,6 +118,9 @@ impl MotionStyle {
             skew_x: 0.0,
             skew_y: 0.0,
             perspective: 0.0,
+            blur: 0.0,
+            brightness: 1.0,
+            backdrop_blur: 0.0,
             properties: BTreeMap::new(),
         }
     }
@@ -206,6 +215,54 @@ impl MotionStyle {
         self
     }
 
+    /// Sets the `filter` blur radius in pixels.
+    ///
+    /// Blur and brightness compose into a single `filter` declaration, always in the
+    /// order `blur() brightness()` so both ends of an animation line up. Identity
+    /// values are left out, and the declaration is omitted entirely when both are.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "dioxus")] {
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut style = use_motion(MotionStyle::default().blur(6.0));
+    ///
+    ///     rsx! {
+    ///         img {
+    ///             src: "/photo.jpg",
+    ///             style: "{style.get_value()}",
+    ///             onmouseenter: move |_| {
+    ///                 style.animate_to(MotionStyle::default(), AnimationConfig::tween_ms(250));
+    ///             },
+    ///             onmouseleave: move |_| {
+    ///                 style.animate_to(MotionStyle::default().blur(6.0), AnimationConfig::tween_ms(250));
+    ///             },
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn blur(mut self, blur: f32) -> Self {
+        self.blur = blur;
+        self
+    }
+
+    /// Sets the `filter` brightness multiplier.
+    pub fn brightness(mut self, brightness: f32) -> Self {
+        self.brightness = brightness;
+        self
+    }
+
+    /// Sets the `backdrop-filter` blur radius in pixels, for frosted-glass effects.
+    pub fn backdrop_blur(mut self, backdrop_blur: f32) -> Self {
+        self.backdrop_blur = backdrop_blur;
+        self
+    }
+
     /// Sets an animated CSS property by value type.
     pub fn property(mut self, property: impl Into<String>, value: CssValue) -> Self {
         let property = normalize_style_property(&property.into());
@@ -257,6 +314,9 @@ impl std::ops::Add for MotionStyle {
             skew_x: self.skew_x + other.skew_x,
             skew_y: self.skew_y + other.skew_y,
             perspective: self.perspective + other.perspective,
+            blur: self.blur + other.blur,
+            brightness: self.brightness + other.brightness,
+            backdrop_blur: self.backdrop_blur + other.backdrop_blur,
             properties: merge_style_properties(&self.properties, &other.properties, |a, b| {
                 a.add(&b).unwrap_or(b)
             }),
@@ -285,6 +345,9 @@ impl std::ops::Sub for MotionStyle {
             skew_x: self.skew_x - other.skew_x,
             skew_y: self.skew_y - other.skew_y,
             perspective: self.perspective - other.perspective,
+            blur: self.blur - other.blur,
+            brightness: self.brightness - other.brightness,
+            backdrop_blur: self.backdrop_blur - other.backdrop_blur,
             properties: merge_style_properties(&self.properties, &other.properties, |a, b| {
                 a.sub(&b).unwrap_or(b)
             }),
@@ -313,6 +376,9 @@ impl std::ops::Mul<f32> for MotionStyle {
             skew_x: self.skew_x * factor,
             skew_y: self.skew_y * factor,
             perspective: self.perspective * factor,
+            blur: self.blur * factor,
+            brightness: self.brightness * factor,
+            backdrop_blur: self.backdrop_blur * factor,
             properties: self
                 .properties
                 .iter()
@@ -322,8 +388,125 @@ impl std::ops::Mul<f32> for MotionStyle {
     }
 }
 
+impl Animatable for MotionStyle {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mut style = self.clone() + (target.clone() - self.clone()) * t;
+
+        for (property, target_value) in &target.properties {
+            if let Some(current_value) = self.properties.get(property) {
+                style
+                    .properties
+                    .insert(property.clone(), current_value.interpolate(target_value, t));
+            } else {
+                style
+                    .properties
+                    .insert(property.clone(), target_value.clone());
+            }
+        }
+
+        for (property, current_value) in &self.properties {
+            if !target.properties.contains_key(property) {
+                style
+                    .properties
+                    .insert(property.clone(), current_value.clone());
+            }
+        }
+
+        style
+    }
+
+    fn magnitude(&self) -> f32 {
+        let property_magnitude: f32 = self
+            .properties
+            .values()
+            .map(|value| value.number() * value.number())
+            .sum();
+
+        (self.opacity * self.opacity
+            + self.x * self.x
+            + self.y * self.y
+            + self.z * self.z
+            + self.scale * self.scale
+            + self.scale_x * self.scale_x
+            + self.scale_y * self.scale_y
+            + self.scale_z * self.scale_z
+            + self.rotate * self.rotate
+            + self.rotate_x * self.rotate_x
+            + self.rotate_y * self.rotate_y
+            + self.rotate_z * self.rotate_z
+            + self.skew * self.skew
+            + self.skew_x * self.skew_x
+            + self.skew_y * self.skew_y
+            + self.perspective * self.perspective
+            + self.blur * self.blur
+            + self.brightness * self.brightness
+            + self.backdrop_blur * self.backdrop_blur
+            + property_magnitude)
+            .sqrt()
+    }
+}
+
+impl fmt::Display for MotionStyle {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let perspective = if self.perspective > 0.0 {
+            format!(" perspective({}px)", self.perspective)
+        } else {
+            String::new()
+        };
+
+        write!(
+            formatter,
+            "opacity: {}; transform:{} translateX({}px) translateY({}px) translateZ({}px) scale({}) scaleX({}) scaleY({}) scaleZ({}) rotate({}deg) rotateX({}deg) rotateY({}deg) rotateZ({}deg) skew({}deg) skewX({}deg) skewY({}deg)",
+            self.opacity,
+            perspective,
+            self.x,
+            self.y,
+            self.z,
+            self.scale,
+            self.scale_x,
+            self.scale_y,
+            self.scale_z,
+            self.rotate,
+            self.rotate_x,
+            self.rotate_y,
+            self.rotate_z,
+            self.skew,
+            self.skew_x,
+            self.skew_y
+        )?;
+
+        let mut filters = Vec::new();
+        if self.blur != 0.0 {
+            filters.push(format!("blur({}px)", self.blur));
+        }
+        if self.brightness != 1.0 {
+            filters.push(format!("brightness({})", self.brightness));
+        }
+        if !filters.is_empty() {
+            write!(formatter, "; filter: {}", filters.join(" "))?;
+        }
+
+        if self.backdrop_blur != 0.0 {
+            write!(
+                formatter,
+                "; backdrop-filter: blur({}px)",
+                self.backdrop_blur
+            )?;
+        }
+
+        for (property, value) in &self.properties {
+            write!(formatter, "; {property}: {}", value.to_css())?;
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::panic)]
+
     use super::*;
     use crate::animations::css::{CssColor, CssValue};
 
@@ -358,6 +541,41 @@ mod tests {
         assert_color(style.properties.get("border-color"), 255.0, 0.0, 0.0, 0.5);
     }
 
+    #[test]
+    fn filters_compose_into_one_declaration() {
+        assert!(!MotionStyle::default().to_css().contains("filter"));
+
+        let style = MotionStyle::default().blur(4.0).brightness(1.25);
+        assert!(
+            style
+                .to_css()
+                .ends_with("; filter: blur(4px) brightness(1.25)")
+        );
+
+        let style = MotionStyle::default().brightness(0.5).backdrop_blur(12.0);
+        assert!(
+            style
+                .to_css()
+                .ends_with("; filter: brightness(0.5); backdrop-filter: blur(12px)")
+        );
+    }
+
+    #[test]
+    fn filters_interpolate() {
+        let start = MotionStyle::default().blur(8.0).backdrop_blur(0.0);
+        let target = MotionStyle::default().brightness(2.0).backdrop_blur(20.0);
+
+        let mid = start.interpolate(&target, 0.5);
+
+        assert!(approx_eq(mid.blur, 4.0));
+        assert!(approx_eq(mid.brightness, 1.5));
+        assert!(approx_eq(mid.backdrop_blur, 10.0));
+        assert!(
+            mid.to_css()
+                .ends_with("; filter: blur(4px) brightness(1.5); backdrop-filter: blur(10px)")
+        );
+    }
+
     #[test]
     fn property_normalizes_keys() {
         let style = MotionStyle::default().property("backgroundColor", CssValue::Px(12.0));
@@ -495,96 +713,3 @@ mod tests {
         );
     }
 }
-
-impl Animatable for MotionStyle {
-    fn interpolate(&self, target: &Self, t: f32) -> Self {
-        let t = t.clamp(0.0, 1.0);
-        let mut style = self.clone() + (target.clone() - self.clone()) * t;
-
-        for (property, target_value) in &target.properties {
-            if let Some(current_value) = self.properties.get(property) {
-                style
-                    .properties
-                    .insert(property.clone(), current_value.interpolate(target_value, t));
-            } else {
-                style
-                    .properties
-                    .insert(property.clone(), target_value.clone());
-            }
-        }
-
-        for (property, current_value) in &self.properties {
-            if !target.properties.contains_key(property) {
-                style
-                    .properties
-                    .insert(property.clone(), current_value.clone());
-            }
-        }
-
-        style
-    }
-
-    fn magnitude(&self) -> f32 {
-        let property_magnitude: f32 = self
-            .properties
-            .values()
-            .map(|value| value.number() * value.number())
-            .sum();
-
-        (self.opacity * self.opacity
-            + self.x * self.x
-            + self.y * self.y
-            + self.z * self.z
-            + self.scale * self.scale
-            + self.scale_x * self.scale_x
-            + self.scale_y * self.scale_y
-            + self.scale_z * self.scale_z
-            + self.rotate * self.rotate
-            + self.rotate_x * self.rotate_x
-            + self.rotate_y * self.rotate_y
-            + self.rotate_z * self.rotate_z
-            + self.skew * self.skew
-            + self.skew_x * self.skew_x
-            + self.skew_y * self.skew_y
-            + self.perspective * self.perspective
-            + property_magnitude)
-            .sqrt()
-    }
-}
-
-impl fmt::Display for MotionStyle {
-    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let perspective = if self.perspective > 0.0 {
-            format!(" perspective({}px)", self.perspective)
-        } else {
-            String::new()
-        };
-
-        write!(
-            formatter,
-            "opacity: {}; transform:{} translateX({}px) translateY({}px) translateZ({}px) scale({}) scaleX({}) scaleY({}) scaleZ({}) rotate({}deg) rotateX({}deg) rotateY({}deg) rotateZ({}deg) skew({}deg) skewX({}deg) skewY({}deg)",
-            self.opacity,
-            perspective,
-            self.x,
-            self.y,
-            self.z,
-            self.scale,
-            self.scale_x,
-            self.scale_y,
-            self.scale_z,
-            self.rotate,
-            self.rotate_x,
-            self.rotate_y,
-            self.rotate_z,
-            self.skew,
-            self.skew_x,
-            self.skew_y
-        )?;
-
-        for (property, value) in &self.properties {
-            write!(formatter, "; {property}: {}", value.to_css())?;
-        }
-
-        Ok(())
-    }
-}
@@ -0,0 +1,160 @@
+//! Text module for typewriter-style animations
+//!
+//! Provides a text value whose revealed portion is animated as a fraction.
+//! The fraction is the only animated component; the text itself is carried along.
+
+use std::sync::Arc;
+
+use crate::animations::core::Animatable;
+
+/// Text revealed progressively by an animated fraction
+///
+/// `fraction` runs from 0.0 (nothing visible) to 1.0 (the whole text visible),
+/// so tweens, springs and sequences drive a typewriter effect with their usual easing.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TypedText {
+    text: Arc<str>,
+    fraction: f32,
+}
+
+impl TypedText {
+    /// Creates a typed text with nothing revealed yet
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::TypedText;
+    /// let text = TypedText::new("Hello");
+    /// assert_eq!(text.visible(), "");
+    /// assert_eq!(text.revealed().visible(), "Hello");
+    /// ```
+    pub fn new(text: impl Into<Arc<str>>) -> Self {
+        Self {
+            text: text.into(),
+            fraction: 0.0,
+        }
+    }
+
+    /// Returns a copy with the given reveal fraction (clamped to 0.0-1.0)
+    pub fn with_fraction(self, fraction: f32) -> Self {
+        Self {
+            fraction: fraction.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Returns a copy with the whole text revealed, the usual animation target
+    pub fn revealed(self) -> Self {
+        self.with_fraction(1.0)
+    }
+
+    /// The full text, regardless of how much is revealed
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The reveal fraction (0.0-1.0)
+    pub fn fraction(&self) -> f32 {
+        self.fraction
+    }
+
+    /// The currently revealed prefix, cut on character boundaries
+    pub fn visible(&self) -> &str {
+        let total = self.text.chars().count();
+        let count = (self.fraction.clamp(0.0, 1.0) * total as f32).floor() as usize;
+
+        match self.text.char_indices().nth(count) {
+            Some((end, _)) => &self.text[..end],
+            None => &self.text,
+        }
+    }
+
+    /// Picks the text to carry through arithmetic, preferring a non-empty one
+    fn carried_text(&self, other: &Self) -> Arc<str> {
+        if self.text.is_empty() {
+            Arc::clone(&other.text)
+        } else {
+            Arc::clone(&self.text)
+        }
+    }
+}
+
+impl std::ops::Add for TypedText {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            text: self.carried_text(&other),
+            fraction: self.fraction + other.fraction,
+        }
+    }
+}
+
+impl std::ops::Sub for TypedText {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            text: self.carried_text(&other),
+            fraction: self.fraction - other.fraction,
+        }
+    }
+}
+
+impl std::ops::Mul<f32> for TypedText {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        Self {
+            fraction: self.fraction * factor,
+            ..self
+        }
+    }
+}
+
+/// Only the fraction is interpolated; the target's text wins when it has one
+impl Animatable for TypedText {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self {
+            text: target.carried_text(self),
+            fraction: self.fraction + (target.fraction - self.fraction) * t,
+        }
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.fraction.abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Motion;
+    use crate::prelude::AnimationConfig;
+
+    #[test]
+    fn test_typed_text_visible_cuts_on_chars() {
+        let text = TypedText::new("héllo");
+
+        assert_eq!(text.clone().with_fraction(0.0).visible(), "");
+        assert_eq!(text.clone().with_fraction(0.4).visible(), "hé");
+        assert_eq!(text.clone().with_fraction(0.99).visible(), "héll");
+        assert_eq!(text.revealed().visible(), "héllo");
+    }
+
+    #[test]
+    fn test_typed_text_grows_monotonically() {
+        let text = TypedText::new("Hello, world");
+        let mut motion = Motion::new(text.clone());
+        motion.animate_to(text.revealed(), AnimationConfig::tween_ms(500));
+
+        let mut previous = String::new();
+        while motion.update(1.0 / 60.0) {
+            let visible = motion.current.visible();
+            assert!(visible.starts_with(previous.as_str()));
+            assert!(visible.len() >= previous.len());
+            previous = visible.to_string();
+        }
+
+        assert_eq!(motion.current.visible(), "Hello, world");
+    }
+}
@@ -1,16 +1,28 @@
-//! Transform module for 2D transformations
+//! Transform module for 2D and 3D transformations
 //!
 //! Provides a Transform type that can be animated, supporting:
 //! - Translation (x, y)
 //! - Scale
-//! - Rotation
+//! - Rotation, plus optional X/Y-axis rotation for 3D flips
+//! - Perspective
+//! - Transform origin, so scaling and rotation can pivot around any point
 //!
 //! Uses radians for rotation and supports smooth interpolation.
 
-use crate::animations::core::Animatable;
+use crate::animations::{core::Animatable, css::format_number};
 use wide::f32x4;
 
-/// Represents a 2D transformation with translation, scale, and rotation
+/// The default transform origin, which the arithmetic treats as zero
+///
+/// Origins are stored as they read, but sums and differences work on the offset from
+/// the centre, so velocities and deltas of a centred transform stay at rest.
+const CENTRE: f32 = 0.5;
+
+/// Represents a transformation with translation, scale, and rotation
+///
+/// The 3D parts, set with [`Transform::rotate_x`], [`Transform::rotate_y`] and
+/// [`Transform::with_perspective`], default to zero, so a plain 2D transform is
+/// unaffected by them.
 ///
 /// # Examples
 /// ```rust
@@ -28,28 +40,315 @@ pub struct Transform {
     pub scale: f32,
     /// Rotation in radians
     pub rotation: f32,
+    /// X-axis rotation in radians
+    pub(crate) rotation_x: f32,
+    /// Y-axis rotation in radians
+    pub(crate) rotation_y: f32,
+    /// Perspective distance in pixels. A value of 0 omits perspective.
+    pub(crate) perspective: f32,
+    /// Horizontal transform origin in fractions of the element's width (0.5 is the centre)
+    pub(crate) origin_x: f32,
+    /// Vertical transform origin in fractions of the element's height (0.5 is the centre)
+    pub(crate) origin_y: f32,
 }
 
 impl Transform {
     /// Creates a new transform with specified parameters
-    pub fn new(x: f32, y: f32, scale: f32, rotation: f32) -> Self {
+    pub const fn new(x: f32, y: f32, scale: f32, rotation: f32) -> Self {
         Self {
             x,
             y,
             scale,
             rotation,
+            rotation_x: 0.0,
+            rotation_y: 0.0,
+            perspective: 0.0,
+            origin_x: CENTRE,
+            origin_y: CENTRE,
         }
     }
 
     /// Creates an identity transform (no transformation)
-    pub fn identity() -> Self {
+    pub const fn identity() -> Self {
+        Self::new(0.0, 0.0, 1.0, 0.0)
+    }
+
+    /// Sets the X translation, in pixels
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Transform;
+    /// use std::f32::consts::FRAC_PI_4;
+    /// let transform = Transform::identity().x(100.0).scale(1.2).rotate_deg(45.0);
+    /// assert_eq!(transform, Transform::new(100.0, 0.0, 1.2, FRAC_PI_4));
+    /// ```
+    pub const fn x(self, x: f32) -> Self {
+        Self { x, ..self }
+    }
+
+    /// Sets the Y translation, in pixels
+    pub const fn y(self, y: f32) -> Self {
+        Self { y, ..self }
+    }
+
+    /// Sets the uniform scale factor
+    pub const fn scale(self, scale: f32) -> Self {
+        Self { scale, ..self }
+    }
+
+    /// Sets the rotation, in radians
+    pub const fn rotate(self, radians: f32) -> Self {
+        Self {
+            rotation: radians,
+            ..self
+        }
+    }
+
+    /// Sets the rotation, in degrees
+    pub const fn rotate_deg(self, degrees: f32) -> Self {
+        self.rotate(degrees.to_radians())
+    }
+
+    /// Sets the rotation around the X axis, in radians, for 3D flips
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Transform;
+    /// use std::f32::consts::PI;
+    /// let flipped = Transform::identity().rotate_y(PI).with_perspective(1000.0);
+    /// assert_eq!(flipped.rotation_y(), PI);
+    /// assert_eq!(flipped.perspective(), 1000.0);
+    /// ```
+    pub const fn rotate_x(self, radians: f32) -> Self {
+        Self {
+            rotation_x: radians,
+            ..self
+        }
+    }
+
+    /// Sets the rotation around the Y axis, in radians, for 3D flips
+    pub const fn rotate_y(self, radians: f32) -> Self {
+        Self {
+            rotation_y: radians,
+            ..self
+        }
+    }
+
+    /// Sets the perspective distance, in pixels; 0 omits perspective
+    pub const fn with_perspective(self, perspective: f32) -> Self {
+        Self {
+            perspective,
+            ..self
+        }
+    }
+
+    /// The rotation around the X axis, in radians
+    pub const fn rotation_x(&self) -> f32 {
+        self.rotation_x
+    }
+
+    /// The rotation around the Y axis, in radians
+    pub const fn rotation_y(&self) -> f32 {
+        self.rotation_y
+    }
+
+    /// The perspective distance, in pixels; 0 when unset
+    pub const fn perspective(&self) -> f32 {
+        self.perspective
+    }
+
+    /// Sets the point scaling and rotation pivot around, normalized to the element's box
+    ///
+    /// `(0.0, 0.0)` is the top-left corner, `(1.0, 1.0)` the bottom-right and
+    /// `(0.5, 0.5)`, the default, the centre, like CSS `transform-origin` percentages.
+    /// The origin interpolates linearly, so the anchor itself can animate.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Transform;
+    /// let grow_from_corner = Transform::new(0.0, 0.0, 0.2, 0.0).with_origin(0.0, 1.0);
+    /// assert_eq!(grow_from_corner.origin(), (0.0, 1.0));
+    /// ```
+    pub const fn with_origin(self, x: f32, y: f32) -> Self {
         Self {
-            x: 0.0,
-            y: 0.0,
-            scale: 1.0,
-            rotation: 0.0,
+            origin_x: x,
+            origin_y: y,
+            ..self
+        }
+    }
+
+    /// The transform origin, normalized to the element's box; `(0.5, 0.5)` is the centre
+    pub const fn origin(&self) -> (f32, f32) {
+        (self.origin_x, self.origin_y)
+    }
+
+    /// The origin as an offset from the centre, the form the arithmetic works in
+    pub(crate) fn origin_offset(&self) -> (f32, f32) {
+        (self.origin_x - CENTRE, self.origin_y - CENTRE)
+    }
+
+    /// Sets the origin from an offset from the centre, see [`Transform::origin_offset`]
+    pub(crate) fn with_origin_offset(self, x: f32, y: f32) -> Self {
+        self.with_origin(x + CENTRE, y + CENTRE)
+    }
+
+    /// Formats the transform as a CSS `transform` value
+    ///
+    /// Rotations are converted from radians to degrees. Perspective and the
+    /// X/Y-axis rotations are only emitted when they are non-zero. The origin is not
+    /// part of the value; see [`Transform::origin_css`] and [`Transform::to_style`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Transform;
+    /// let transform = Transform::new(10.0, 20.0, 1.5, std::f32::consts::PI);
+    /// assert_eq!(transform.to_css(), "translate(10px, 20px) rotate(180deg) scale(1.5)");
+    /// ```
+    pub fn to_css(&self) -> String {
+        let mut css = String::new();
+
+        if self.perspective > 0.0 {
+            css.push_str(&format!(
+                "perspective({}px) ",
+                format_number(self.perspective)
+            ));
+        }
+
+        css.push_str(&format!(
+            "translate({}px, {}px)",
+            format_number(self.x),
+            format_number(self.y)
+        ));
+
+        if self.rotation_x != 0.0 {
+            css.push_str(&format!(
+                " rotateX({}deg)",
+                format_number(self.rotation_x.to_degrees())
+            ));
+        }
+
+        if self.rotation_y != 0.0 {
+            css.push_str(&format!(
+                " rotateY({}deg)",
+                format_number(self.rotation_y.to_degrees())
+            ));
+        }
+
+        css.push_str(&format!(
+            " rotate({}deg) scale({})",
+            format_number(self.rotation.to_degrees()),
+            format_number(self.scale)
+        ));
+
+        css
+    }
+
+    /// Formats the origin as a CSS `transform-origin` value, such as `"0% 100%"`
+    pub fn origin_css(&self) -> String {
+        format!(
+            "{}% {}%",
+            format_number(self.origin_x * 100.0),
+            format_number(self.origin_y * 100.0)
+        )
+    }
+
+    /// Formats the transform as `style` declarations
+    ///
+    /// Always includes `transform`, and adds `transform-origin` when the origin is not
+    /// the centre, which is already the CSS default.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Transform;
+    /// let transform = Transform::new(0.0, 0.0, 0.5, 0.0).with_origin(0.0, 1.0);
+    /// assert_eq!(
+    ///     transform.to_style(),
+    ///     "transform: translate(0px, 0px) rotate(0deg) scale(0.5); transform-origin: 0% 100%;"
+    /// );
+    /// ```
+    pub fn to_style(&self) -> String {
+        let mut style = format!("transform: {};", self.to_css());
+        if self.origin() != (CENTRE, CENTRE) {
+            style.push_str(&format!(" transform-origin: {};", self.origin_css()));
+        }
+        style
+    }
+
+    /// Returns the 2D affine matrix `[a, b, c, d, e, f]` for this transform
+    ///
+    /// Matches the argument order of CSS `matrix(a, b, c, d, e, f)`: translation
+    /// followed by rotation and uniform scale. The 3D fields are ignored, and so is the
+    /// origin, since placing it in pixels needs the element's size.
+    pub fn to_matrix(&self) -> [f32; 6] {
+        let (sin, cos) = self.rotation.sin_cos();
+        [
+            self.scale * cos,
+            self.scale * sin,
+            -self.scale * sin,
+            self.scale * cos,
+            self.x,
+            self.y,
+        ]
+    }
+
+    /// Composes `other` inside this transform, as if `other` were applied in a child
+    /// element transformed by `self`
+    ///
+    /// The result's matrix equals `self.to_matrix()` multiplied by `other.to_matrix()`:
+    /// `other`'s translation is rotated and scaled by `self`, rotations add and scales
+    /// multiply. The 3D fields and the origin are carried over from `self`, so the
+    /// composed transform still pivots where the outer one does.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Transform;
+    /// let parent = Transform::new(100.0, 0.0, 2.0, 0.0);
+    /// let child = Transform::new(10.0, 5.0, 1.0, 0.0);
+    /// let world = parent.compose(&child);
+    /// assert_eq!((world.x, world.y, world.scale), (120.0, 10.0, 2.0));
+    /// ```
+    pub fn compose(&self, other: &Transform) -> Transform {
+        let (sin, cos) = self.rotation.sin_cos();
+        Transform {
+            x: self.x + self.scale * (cos * other.x - sin * other.y),
+            y: self.y + self.scale * (sin * other.x + cos * other.y),
+            scale: self.scale * other.scale,
+            rotation: self.rotation + other.rotation,
+            ..*self
+        }
+    }
+
+    /// Returns the transform that undoes this one under [`Transform::compose`]
+    ///
+    /// Returns `None` when the scale is zero (or not finite), since such a transform
+    /// collapses everything to a point. The 3D fields and the origin are carried over
+    /// from `self`.
+    pub fn inverse(&self) -> Option<Transform> {
+        if self.scale == 0.0 || !self.scale.is_finite() {
+            return None;
         }
+
+        let scale = 1.0 / self.scale;
+        let (sin, cos) = (-self.rotation).sin_cos();
+        Some(Transform {
+            x: -scale * (cos * self.x - sin * self.y),
+            y: -scale * (sin * self.x + cos * self.y),
+            scale,
+            rotation: -self.rotation,
+            ..*self
+        })
+    }
+}
+
+/// Interpolates an angle in radians along the shortest path
+pub(crate) fn interpolate_rotation(from: f32, to: f32, t: f32) -> f32 {
+    let mut rotation_diff = to - from;
+    if rotation_diff > std::f32::consts::PI {
+        rotation_diff -= 2.0 * std::f32::consts::PI;
+    } else if rotation_diff < -std::f32::consts::PI {
+        rotation_diff += 2.0 * std::f32::consts::PI;
     }
+    from + rotation_diff * t
 }
 
 impl Default for Transform {
@@ -62,12 +361,17 @@ impl std::ops::Add for Transform {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        Transform::new(
-            self.x + other.x,
-            self.y + other.y,
-            self.scale + other.scale,
-            self.rotation + other.rotation,
-        )
+        Transform {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            scale: self.scale + other.scale,
+            rotation: self.rotation + other.rotation,
+            rotation_x: self.rotation_x + other.rotation_x,
+            rotation_y: self.rotation_y + other.rotation_y,
+            perspective: self.perspective + other.perspective,
+            origin_x: self.origin_x + other.origin_x - CENTRE,
+            origin_y: self.origin_y + other.origin_y - CENTRE,
+        }
     }
 }
 
@@ -75,12 +379,17 @@ impl std::ops::Sub for Transform {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        Transform::new(
-            self.x - other.x,
-            self.y - other.y,
-            self.scale - other.scale,
-            self.rotation - other.rotation,
-        )
+        Transform {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            scale: self.scale - other.scale,
+            rotation: self.rotation - other.rotation,
+            rotation_x: self.rotation_x - other.rotation_x,
+            rotation_y: self.rotation_y - other.rotation_y,
+            perspective: self.perspective - other.perspective,
+            origin_x: self.origin_x - other.origin_x + CENTRE,
+            origin_y: self.origin_y - other.origin_y + CENTRE,
+        }
     }
 }
 
@@ -88,12 +397,17 @@ impl std::ops::Mul<f32> for Transform {
     type Output = Self;
 
     fn mul(self, factor: f32) -> Self {
-        Transform::new(
-            self.x * factor,
-            self.y * factor,
-            self.scale * factor,
-            self.rotation * factor,
-        )
+        Transform {
+            x: self.x * factor,
+            y: self.y * factor,
+            scale: self.scale * factor,
+            rotation: self.rotation * factor,
+            rotation_x: self.rotation_x * factor,
+            rotation_y: self.rotation_y * factor,
+            perspective: self.perspective * factor,
+            origin_x: (self.origin_x - CENTRE) * factor + CENTRE,
+            origin_y: (self.origin_y - CENTRE) * factor + CENTRE,
+        }
     }
 }
 
@@ -115,43 +429,101 @@ impl Animatable for f32 {
 /// Much simpler with the new trait design - uses standard operators
 impl Animatable for Transform {
     fn interpolate(&self, target: &Self, t: f32) -> Self {
-        // SIMD for x, y, scale; handle rotation separately for shortest path
-        let a = [self.x, self.y, self.scale, 0.0];
-        let b = [target.x, target.y, target.scale, 0.0];
+        // SIMD for x, y, scale, perspective; handle rotations separately for shortest path
+        let a = [self.x, self.y, self.scale, self.perspective];
+        let b = [target.x, target.y, target.scale, target.perspective];
         let va = f32x4::new(a);
         let vb = f32x4::new(b);
         let vt = f32x4::splat(t.clamp(0.0, 1.0));
         let result = va + (vb - va) * vt;
         let out = result.to_array();
 
-        // Rotation: shortest path
-        let mut rotation_diff = target.rotation - self.rotation;
-        if rotation_diff > std::f32::consts::PI {
-            rotation_diff -= 2.0 * std::f32::consts::PI;
-        } else if rotation_diff < -std::f32::consts::PI {
-            rotation_diff += 2.0 * std::f32::consts::PI;
+        Transform {
+            x: out[0],
+            y: out[1],
+            scale: out[2],
+            rotation: interpolate_rotation(self.rotation, target.rotation, t),
+            rotation_x: interpolate_rotation(self.rotation_x, target.rotation_x, t),
+            rotation_y: interpolate_rotation(self.rotation_y, target.rotation_y, t),
+            perspective: out[3],
+            origin_x: self
+                .origin_x
+                .interpolate(&target.origin_x, t.clamp(0.0, 1.0)),
+            origin_y: self
+                .origin_y
+                .interpolate(&target.origin_y, t.clamp(0.0, 1.0)),
         }
-        let rotation = self.rotation + rotation_diff * t;
-
-        Transform::new(out[0], out[1], out[2], rotation)
     }
 
     fn magnitude(&self) -> f32 {
+        let (origin_x, origin_y) = self.origin_offset();
         (self.x * self.x
             + self.y * self.y
             + self.scale * self.scale
-            + self.rotation * self.rotation)
+            + self.rotation * self.rotation
+            + self.rotation_x * self.rotation_x
+            + self.rotation_y * self.rotation_y
+            + self.perspective * self.perspective
+            + origin_x * origin_x
+            + origin_y * origin_y)
             .sqrt()
     }
 
-    // Uses default epsilon of 0.01 from the trait - no need for TRANSFORM_EPSILON
+    /// Pixels settle at the default 0.01, while scale and the rotations (in radians)
+    /// need a finer threshold to stop without a visible snap.
+    fn epsilon_components() -> Option<Self> {
+        Some(Transform {
+            x: 0.01,
+            y: 0.01,
+            scale: 0.001,
+            rotation: 0.001,
+            rotation_x: 0.001,
+            rotation_y: 0.001,
+            perspective: 0.01,
+            origin_x: CENTRE + 0.01,
+            origin_y: CENTRE + 0.01,
+        })
+    }
+
+    fn within_components(&self, epsilon: &Self) -> bool {
+        let (origin_x, origin_y) = self.origin_offset();
+        let (epsilon_x, epsilon_y) = epsilon.origin_offset();
+        self.x.abs() < epsilon.x
+            && self.y.abs() < epsilon.y
+            && self.scale.abs() < epsilon.scale
+            && self.rotation.abs() < epsilon.rotation
+            && self.rotation_x.abs() < epsilon.rotation_x
+            && self.rotation_y.abs() < epsilon.rotation_y
+            && self.perspective.abs() < epsilon.perspective
+            && origin_x.abs() < epsilon_x
+            && origin_y.abs() < epsilon_y
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
+
     use super::*;
     use std::f32::consts::PI;
 
+    #[test]
+    fn builders_match_new() {
+        assert_eq!(
+            Transform::identity()
+                .x(100.0)
+                .y(50.0)
+                .scale(1.5)
+                .rotate(PI / 4.0),
+            Transform::new(100.0, 50.0, 1.5, PI / 4.0)
+        );
+        assert_eq!(Transform::identity().rotate_deg(180.0).rotation, PI);
+        assert_eq!(
+            Transform::identity().x(10.0).x(20.0),
+            Transform::new(20.0, 0.0, 1.0, 0.0)
+        );
+    }
+
     #[test]
     fn test_transform_new() {
         let transform = Transform::new(100.0, 50.0, 1.5, PI / 4.0);
@@ -181,4 +553,133 @@ mod tests {
         assert_eq!(mid.scale, 1.5);
         assert!((mid.rotation - PI / 2.0).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_transform_3d_flip_midpoint_css() {
+        let start = Transform::identity().with_perspective(1000.0);
+        let end = start.rotate_y(PI);
+        let mid = start.interpolate(&end, 0.5);
+
+        assert!((mid.rotation_y - PI / 2.0).abs() < f32::EPSILON);
+        assert_eq!(
+            mid.to_css(),
+            "perspective(1000px) translate(0px, 0px) rotateY(90deg) rotate(0deg) scale(1)"
+        );
+    }
+
+    #[test]
+    fn test_transform_to_css() {
+        let transform = Transform::new(12.5, -4.0, 2.0, PI / 2.0);
+        assert_eq!(
+            transform.to_css(),
+            "translate(12.5px, -4px) rotate(90deg) scale(2)"
+        );
+    }
+
+    #[test]
+    fn test_transform_origin_css() {
+        let transform = Transform::new(0.0, 0.0, 0.5, 0.0).with_origin(0.0, 1.0);
+        assert_eq!(transform.origin_x, 0.0);
+        assert_eq!(transform.origin_y, 1.0);
+        assert_eq!(
+            transform.to_css(),
+            "translate(0px, 0px) rotate(0deg) scale(0.5)"
+        );
+        assert_eq!(transform.origin_css(), "0% 100%");
+        assert_eq!(
+            transform.to_style(),
+            "transform: translate(0px, 0px) rotate(0deg) scale(0.5); transform-origin: 0% 100%;"
+        );
+
+        let centred = Transform::new(0.0, 0.0, 0.5, 0.0);
+        assert_eq!(centred.origin(), (0.5, 0.5));
+        assert_eq!(centred.with_origin(0.5, 0.5), centred);
+        assert_eq!(centred.origin_css(), "50% 50%");
+        assert!(!centred.to_style().contains("transform-origin"));
+
+        let mid = centred.interpolate(&transform, 0.5);
+        assert_eq!(mid.origin(), (0.25, 0.75));
+
+        // Deltas between centred transforms leave the origin at rest
+        let delta = transform - transform;
+        assert_eq!(delta.origin_offset(), (0.0, 0.0));
+        assert_eq!(
+            (centred + (transform - centred) * 0.5).origin(),
+            (0.25, 0.75)
+        );
+    }
+
+    #[test]
+    fn test_transform_origin_springs_from_rest() {
+        use crate::{Motion, animations::spring::Spring, prelude::AnimationConfig};
+
+        let corner = Transform::identity().with_origin(1.0, 0.0);
+        let mut motion = Motion::new(Transform::identity());
+        motion.animate_to(corner, AnimationConfig::spring(Spring::default()));
+        motion.update(1.0 / 60.0);
+        let (x, y) = motion.get_value().origin();
+        assert!(
+            x > 0.5 && x < 1.0,
+            "origin should move towards the corner: {x}"
+        );
+        assert!(y < 0.5);
+
+        while motion.update(1.0 / 60.0) {}
+        assert_eq!(motion.get_value().origin(), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_transform_to_matrix_round_trips() {
+        let transform = Transform::new(30.0, -15.0, 1.0, PI / 3.0);
+        let [a, b, c, d, e, f] = transform.to_matrix();
+
+        assert_eq!((e, f), (30.0, -15.0));
+        assert!((b.atan2(a) - transform.rotation).abs() < 1e-6);
+        assert!(((a * a + b * b).sqrt() - 1.0).abs() < 1e-6);
+        assert!((c + b).abs() < 1e-6);
+        assert!((d - a).abs() < 1e-6);
+
+        let identity = Transform::identity().to_matrix();
+        assert_eq!(identity, [1.0, 0.0, -0.0, 1.0, 0.0, 0.0]);
+    }
+
+    fn assert_matrix_eq(actual: [f32; 6], expected: [f32; 6]) {
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-4, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_transform_compose_matches_matrix_product() {
+        let parent = Transform::new(40.0, -10.0, 1.5, PI / 6.0);
+        let child = Transform::new(12.0, 8.0, 0.5, PI / 3.0);
+        let [a1, b1, c1, d1, e1, f1] = parent.to_matrix();
+        let [a2, b2, c2, d2, e2, f2] = child.to_matrix();
+
+        let expected = [
+            a1 * a2 + c1 * b2,
+            b1 * a2 + d1 * b2,
+            a1 * c2 + c1 * d2,
+            b1 * c2 + d1 * d2,
+            a1 * e2 + c1 * f2 + e1,
+            b1 * e2 + d1 * f2 + f1,
+        ];
+
+        assert_matrix_eq(parent.compose(&child).to_matrix(), expected);
+    }
+
+    #[test]
+    fn test_transform_compose_inverse_is_identity() {
+        let transform = Transform::new(25.0, -40.0, 2.5, 0.7);
+        let inverse = transform.inverse().unwrap();
+
+        let identity = Transform::identity().to_matrix();
+        assert_matrix_eq(transform.compose(&inverse).to_matrix(), identity);
+        assert_matrix_eq(inverse.compose(&transform).to_matrix(), identity);
+    }
+
+    #[test]
+    fn test_transform_inverse_zero_scale() {
+        assert!(Transform::new(10.0, 10.0, 0.0, 1.0).inverse().is_none());
+    }
 }
@@ -8,6 +8,95 @@ use dioxus::prelude::Store;
 use easer::functions::{Easing, Linear};
 pub use instant::Duration;
 
+/// Easing function signature shared by tweens and keyframes: `(t, b, c, d) -> value`
+type EasingFn = fn(f32, f32, f32, f32) -> f32;
+
+/// Largest step count supported by [`Tween::steps`]
+pub const MAX_STEPS: u32 = 64;
+
+/// Step counts rejected by [`Tween::steps`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum StepsError {
+    #[error("A stepped easing needs at least one step")]
+    NoSteps,
+    #[error("{0} steps is more than the {MAX_STEPS} a stepped easing supports")]
+    TooManySteps(u32),
+}
+
+/// Where the jumps of a [`Tween::steps`] easing happen, matching CSS `steps()`
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepJump {
+    /// Jumps at the start of each step (`jump-start`)
+    Start,
+    /// Jumps at the end of each step (`jump-end`), the CSS default
+    #[default]
+    End,
+    /// Jumps at both ends, adding a step (`jump-both`)
+    Both,
+    /// Holds both ends, removing a step (`jump-none`)
+    None,
+}
+
+impl StepJump {
+    const fn from_index(index: u8) -> Self {
+        match index {
+            0 => Self::Start,
+            1 => Self::End,
+            2 => Self::Both,
+            _ => Self::None,
+        }
+    }
+
+    /// Stepped progress for `steps` steps at linear progress `t` (0.0 to 1.0)
+    ///
+    /// Follows the CSS step easing algorithm. `jump-none` needs at least two steps,
+    /// so fewer are treated as two.
+    pub fn progress(self, steps: u32, t: f32) -> f32 {
+        let steps = match self {
+            Self::None => steps.max(2),
+            _ => steps.max(1),
+        };
+        let t = t.clamp(0.0, 1.0);
+        let mut step = (t * steps as f32).floor();
+        if matches!(self, Self::Start | Self::Both) {
+            step += 1.0;
+        }
+
+        let jumps = match self {
+            Self::Start | Self::End => steps,
+            Self::Both => steps + 1,
+            Self::None => steps - 1,
+        } as f32;
+
+        step.min(jumps) / jumps
+    }
+}
+
+fn stepped_easing<const STEPS: u32, const JUMP: u8>(t: f32, b: f32, c: f32, d: f32) -> f32 {
+    b + c * StepJump::from_index(JUMP).progress(STEPS, t / d)
+}
+
+/// Builds one monomorphized easing per step count and jump mode, since easings are
+/// plain function pointers and cannot capture the step count.
+macro_rules! step_easing_table {
+    ($($steps:literal)*) => {
+        [
+            [$(stepped_easing::<$steps, { StepJump::Start as u8 }> as EasingFn),*],
+            [$(stepped_easing::<$steps, { StepJump::End as u8 }> as EasingFn),*],
+            [$(stepped_easing::<$steps, { StepJump::Both as u8 }> as EasingFn),*],
+            [$(stepped_easing::<$steps, { StepJump::None as u8 }> as EasingFn),*],
+        ]
+    };
+}
+
+static STEP_EASINGS: [[EasingFn; MAX_STEPS as usize]; 4] = step_easing_table!(
+    1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+    33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48
+    49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64
+);
+
 /// Configuration for tween-based animations
 ///
 /// # Examples
@@ -33,6 +122,13 @@ impl PartialEq for Tween {
     }
 }
 
+impl std::hash::Hash for Tween {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.duration.hash(state);
+        (self.easing as usize).hash(state);
+    }
+}
+
 /// Default tween configuration with 300ms duration and linear easing
 impl Default for Tween {
     fn default() -> Self {
@@ -52,6 +148,25 @@ impl Tween {
         }
     }
 
+    /// Creates a tween lasting `seconds` with the given easing
+    ///
+    /// Negative, NaN or overflowing durations give a zero-length tween.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::Duration;
+    /// use dioxus_motion::prelude::Tween;
+    /// use easer::functions::{Cubic, Easing};
+    /// let tween = Tween::seconds(0.5, Cubic::ease_in_out);
+    /// assert_eq!(tween.duration, Duration::from_millis(500));
+    /// ```
+    pub fn seconds(seconds: f32, easing: fn(f32, f32, f32, f32) -> f32) -> Self {
+        Self {
+            duration: Duration::try_from_secs_f32(seconds).unwrap_or_default(),
+            easing,
+        }
+    }
+
     /// Sets the easing function for the animation
     ///
     /// # Arguments
@@ -60,6 +175,59 @@ impl Tween {
         self.easing = easing;
         self
     }
+
+    /// Returns a stepped easing like CSS `steps(steps, jump)`
+    ///
+    /// The value holds on discrete plateaus instead of moving smoothly, which suits
+    /// sprite-sheet and frame-by-frame animation. Works anywhere an easing function is
+    /// accepted, including keyframes. Easings are plain function pointers, one per step
+    /// count, so `steps` must be within `1..=MAX_STEPS`; other counts return a
+    /// [`StepsError`] rather than a curve with the wrong number of plateaus.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::Duration;
+    /// use dioxus_motion::prelude::{StepJump, StepsError, Tween};
+    /// let tween = Tween::new(Duration::from_millis(800)).with_easing(Tween::steps(8, StepJump::End)?);
+    /// assert_eq!((tween.easing)(0.3, 0.0, 1.0, 1.0), 0.25);
+    /// assert_eq!(Tween::steps(120, StepJump::End), Err(StepsError::TooManySteps(120)));
+    /// # Ok::<(), StepsError>(())
+    /// ```
+    pub fn steps(steps: u32, jump: StepJump) -> Result<EasingFn, StepsError> {
+        match steps {
+            0 => Err(StepsError::NoSteps),
+            1..=MAX_STEPS => Ok(STEP_EASINGS[jump as usize][steps as usize - 1]),
+            _ => Err(StepsError::TooManySteps(steps)),
+        }
+    }
+
+    /// Samples this tween's easing curve; see [`Tween::sample_easing`]
+    pub fn sample(&self, points: usize) -> Vec<(f32, f32)> {
+        Self::sample_easing(self.easing, points)
+    }
+
+    /// Samples an easing curve into `points` evenly spaced `(t, eased)` pairs over `[0, 1]`
+    ///
+    /// Useful for drawing an easing preview. Eased values are not clamped, so easings
+    /// that overshoot (Back, Elastic) report their true range outside `0.0..=1.0`.
+    /// A single point samples `t = 0`; zero points give an empty curve.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Tween;
+    /// use easer::functions::{Back, Easing};
+    /// let curve = Tween::sample_easing(Back::ease_out, 32);
+    /// assert!(curve.iter().any(|&(_, eased)| eased > 1.0));
+    /// ```
+    pub fn sample_easing(easing: fn(f32, f32, f32, f32) -> f32, points: usize) -> Vec<(f32, f32)> {
+        let last = points.saturating_sub(1).max(1) as f32;
+        (0..points)
+            .map(|index| {
+                let t = index as f32 / last;
+                (t, easing(t, 0.0, 1.0, 1.0))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -67,6 +235,38 @@ mod tests {
     use super::*;
     use easer::functions::{Cubic, Easing};
 
+    #[test]
+    fn sample_linear_is_the_diagonal() {
+        let curve = Tween::sample_easing(Linear::ease_in_out, 5);
+        assert_eq!(
+            curve,
+            vec![
+                (0.0, 0.0),
+                (0.25, 0.25),
+                (0.5, 0.5),
+                (0.75, 0.75),
+                (1.0, 1.0)
+            ]
+        );
+        assert_eq!(Tween::default().sample(5), curve);
+        assert!(Tween::sample_easing(Linear::ease_in_out, 0).is_empty());
+        assert_eq!(
+            Tween::sample_easing(Linear::ease_in_out, 1),
+            vec![(0.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn sample_bounce_ends_at_one() {
+        use easer::functions::Bounce;
+
+        let curve = Tween::sample_easing(Bounce::ease_out, 20);
+        assert_eq!(curve.len(), 20);
+        assert_eq!(curve.first(), Some(&(0.0, 0.0)));
+        assert_eq!(curve.last().map(|&(t, _)| t), Some(1.0));
+        assert!((curve[19].1 - 1.0).abs() < 1e-6);
+    }
+
     #[test]
     fn test_tween_new() {
         let tween = Tween {
@@ -98,6 +298,41 @@ mod tests {
         assert!((result - 1.0).abs() < f32::EPSILON);
     }
 
+    fn sample(jump: StepJump) -> Vec<f32> {
+        let easing = Tween::steps(4, jump).expect("4 steps are supported");
+        [0.0, 0.1, 0.3, 0.6, 0.9, 1.0]
+            .iter()
+            .map(|t| easing(*t, 0.0, 1.0, 1.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_tween_steps_plateaus() {
+        assert_eq!(sample(StepJump::End), [0.0, 0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(sample(StepJump::Start), [0.25, 0.25, 0.5, 0.75, 1.0, 1.0]);
+        assert_eq!(sample(StepJump::Both), [0.2, 0.2, 0.4, 0.6, 0.8, 1.0]);
+        assert_eq!(
+            sample(StepJump::None),
+            [0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_tween_steps_rejects_unsupported_counts() {
+        assert_eq!(Tween::steps(0, StepJump::End), Err(StepsError::NoSteps));
+        assert_eq!(
+            Tween::steps(MAX_STEPS + 1, StepJump::End),
+            Err(StepsError::TooManySteps(MAX_STEPS + 1))
+        );
+
+        let most = Tween::steps(MAX_STEPS, StepJump::End).expect("MAX_STEPS is supported");
+        assert_eq!(most(0.5, 0.0, 1.0, 1.0), 0.5);
+        assert_eq!(
+            most(1.0 / MAX_STEPS as f32, 0.0, 1.0, 1.0),
+            1.0 / MAX_STEPS as f32
+        );
+    }
+
     #[test]
     fn test_tween_partial_eq_uses_function_identity() {
         let base = Tween::new(Duration::from_secs(1));
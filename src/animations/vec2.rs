@@ -0,0 +1,68 @@
+//! Two-dimensional vector values
+//!
+//! Used for pointer offsets and velocities, such as the offset tracked by
+//! [`crate::drag::use_drag`].
+
+use crate::animations::core::Animatable;
+
+/// A 2D vector with `f32` components
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Vec2 {
+    /// The zero vector
+    pub const ZERO: Vec2 = Vec2 { x: 0.0, y: 0.0 };
+
+    /// Creates a vector from its components
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Euclidean length of the vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Vec2;
+    /// assert_eq!(Vec2::new(3.0, 4.0).length(), 5.0);
+    /// ```
+    pub fn length(&self) -> f32 {
+        self.x.hypot(self.y)
+    }
+}
+
+impl std::ops::Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl std::ops::Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl std::ops::Mul<f32> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Animatable for Vec2 {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        *self + (*target - *self) * t
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.length()
+    }
+}
@@ -0,0 +1,164 @@
+//! Crossfading between two elements that take turns being shown.
+//!
+//! [`use_crossfade`] drives one progress motion between the two slots, so the outgoing
+//! element fades out exactly as fast as the incoming one fades in. Toggling again
+//! mid-fade turns the same motion around instead of starting a second one.
+
+use dioxus::prelude::*;
+
+use crate::{
+    animations::core::AnimationConfig,
+    manager::{AnimationManager, MotionHandle},
+    use_motion,
+};
+
+/// Handle returned by [`use_crossfade`].
+#[derive(Clone, Copy)]
+pub struct Crossfade<T> {
+    items: [T; 2],
+    progress: MotionHandle<f32>,
+    shown: CopyValue<usize>,
+}
+
+impl<T> Crossfade<T> {
+    /// Opacity of `slot` (0 or 1); the two opacities always sum to `1.0`
+    ///
+    /// Reading subscribes the component, so it re-renders on every frame of the fade.
+    pub fn opacity(&self, slot: usize) -> f32 {
+        let shown = self.progress.get_value().clamp(0.0, 1.0);
+        if slot == 0 { 1.0 - shown } else { shown }
+    }
+
+    /// Opacities of both slots, in slot order
+    pub fn opacities(&self) -> [f32; 2] {
+        [self.opacity(0), self.opacity(1)]
+    }
+
+    /// Each item paired with its current opacity, in slot order
+    pub fn slots(&self) -> impl Iterator<Item = (&T, f32)> {
+        self.items.iter().zip(self.opacities())
+    }
+
+    /// The motion going from `0.0` (first slot shown) to `1.0` (second slot shown)
+    pub fn progress(&self) -> MotionHandle<f32> {
+        self.progress
+    }
+
+    /// Fades towards the item at `index`, turning around any fade in progress.
+    fn show(&mut self, index: usize, config: AnimationConfig) {
+        let index = index.min(1);
+        if *self.shown.peek() != index {
+            self.shown.set(index);
+            self.progress.animate_to(index as f32, config);
+        }
+    }
+}
+
+/// Crossfades between two items, showing the one at `index` (0 or 1).
+///
+/// When `index` changes between renders, the shown item fades out while the other
+/// fades in, both following `config`. Indices above 1 show the second item. Springs
+/// may overshoot, so opacities are clamped to `0.0..=1.0`.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(feature = "dioxus")] {
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn app() -> Element {
+///     let mut playing = use_signal(|| false);
+///     let icons = use_crossfade(
+///         ["M8 5v14l11-7z", "M6 5h4v14H6zM14 5h4v14h-4z"],
+///         usize::from(playing()),
+///         AnimationConfig::tween_ms(200),
+///     );
+///
+///     rsx! {
+///         button { onclick: move |_| playing.toggle(),
+///             svg { view_box: "0 0 24 24", width: "24", height: "24",
+///                 for (path, opacity) in icons.slots() {
+///                     path { d: "{path}", fill: "currentColor", opacity: "{opacity}" }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+pub fn use_crossfade<T>(items: [T; 2], index: usize, config: AnimationConfig) -> Crossfade<T> {
+    let progress = use_motion(index.min(1) as f32);
+    let shown = use_hook(|| CopyValue::new(index.min(1)));
+
+    let mut crossfade = Crossfade {
+        items,
+        progress,
+        shown,
+    };
+    crossfade.show(index, config);
+    crossfade
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{cell::RefCell, rc::Rc};
+
+    use dioxus::prelude::{Element, ScopeId, VNode, VirtualDom};
+
+    use super::{Crossfade, use_crossfade};
+    use crate::{manager::AnimationManager, prelude::AnimationConfig};
+
+    type HostedCrossfade = Rc<RefCell<Option<Crossfade<&'static str>>>>;
+
+    #[allow(non_snake_case)]
+    fn CrossfadeHost(fade: HostedCrossfade) -> Element {
+        *fade.borrow_mut() = Some(use_crossfade(
+            ["play", "pause"],
+            0,
+            AnimationConfig::tween_ms(200),
+        ));
+
+        VNode::empty()
+    }
+
+    fn assert_sums_to_one(fade: &Crossfade<&str>) {
+        let [first, second] = fade.opacities();
+        assert!((first + second - 1.0).abs() < 1e-6, "{first} + {second}");
+    }
+
+    #[test]
+    fn opacities_stay_complementary_and_reverse_on_toggle() {
+        let fade = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(CrossfadeHost, Rc::clone(&fade));
+        dom.rebuild_in_place();
+        let mut fade = fade.borrow().unwrap();
+        let mut progress = fade.progress();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            assert_eq!(fade.opacities(), [1.0, 0.0]);
+            assert_eq!(
+                fade.slots().map(|(item, _)| *item).collect::<Vec<_>>(),
+                ["play", "pause"]
+            );
+
+            fade.show(1, AnimationConfig::tween_ms(200));
+            for _ in 0..6 {
+                assert!(progress.update(1.0 / 60.0));
+                assert_sums_to_one(&fade);
+            }
+            let midway = fade.opacity(1);
+            assert!(midway > 0.0 && midway < 1.0);
+
+            fade.show(0, AnimationConfig::tween_ms(200));
+            progress.update(1.0 / 60.0);
+            assert!(fade.opacity(1) < midway, "toggling back should reverse");
+            while progress.update(1.0 / 60.0) {
+                assert_sums_to_one(&fade);
+            }
+            assert_eq!(fade.opacities(), [1.0, 0.0]);
+        });
+    }
+}
@@ -0,0 +1,526 @@
+//! Pointer drag gestures.
+//!
+//! [`use_drag`] tracks a pointer drag on one element, keeps the drag offset in a
+//! [`MotionHandle<Vec2>`], rubber-bands past [`DragConstraints`] and flings with the
+//! release velocity when the pointer lets go.
+
+use dioxus::prelude::*;
+
+use crate::{
+    Time,
+    animations::{core::AnimationConfig, platform::TimeProvider, spring::Spring, vec2::Vec2},
+    manager::{AnimationManager, MotionHandle},
+    use_motion,
+};
+
+/// How far ahead, in seconds, the release velocity projects the resting position.
+const INERTIA_TIME: f32 = 0.2;
+
+/// Pointer samples older than this many seconds are ignored for the release velocity.
+const VELOCITY_WINDOW: f32 = 0.1;
+
+/// Bounds for the drag offset, in pixels relative to where the element started.
+///
+/// Each side defaults to unbounded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragConstraints {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl Default for DragConstraints {
+    fn default() -> Self {
+        Self {
+            left: f32::NEG_INFINITY,
+            right: f32::INFINITY,
+            top: f32::NEG_INFINITY,
+            bottom: f32::INFINITY,
+        }
+    }
+}
+
+impl DragConstraints {
+    /// Creates constraints from the minimum and maximum offset on each axis.
+    pub fn new(left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    /// Clamps `offset` into the bounds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::{DragConstraints, Vec2};
+    ///
+    /// let bounds = DragConstraints::new(-100.0, 100.0, 0.0, 0.0);
+    /// assert_eq!(bounds.clamp(Vec2::new(150.0, 20.0)), Vec2::new(100.0, 0.0));
+    /// ```
+    pub fn clamp(&self, offset: Vec2) -> Vec2 {
+        Vec2::new(
+            clamp_axis(offset.x, self.left, self.right),
+            clamp_axis(offset.y, self.top, self.bottom),
+        )
+    }
+
+    /// Applies elastic resistance to the part of `offset` beyond the bounds.
+    ///
+    /// An `elastic` of `0.0` stops dead at the bounds and `1.0` ignores them; values in
+    /// between let the element travel that fraction of the pointer's overshoot.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::{DragConstraints, Vec2};
+    ///
+    /// let bounds = DragConstraints::new(-100.0, 100.0, 0.0, 0.0);
+    /// assert_eq!(bounds.elastic(Vec2::new(140.0, 0.0), 0.5), Vec2::new(120.0, 0.0));
+    /// ```
+    pub fn elastic(&self, offset: Vec2, elastic: f32) -> Vec2 {
+        let clamped = self.clamp(offset);
+        clamped + (offset - clamped) * elastic.clamp(0.0, 1.0)
+    }
+}
+
+fn clamp_axis(value: f32, min: f32, max: f32) -> f32 {
+    if min > max {
+        return value;
+    }
+    value.max(min).min(max)
+}
+
+/// Drag state passed to the [`DragOptions`] callbacks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragInfo {
+    /// Offset of the element, after elastic resistance.
+    pub offset: Vec2,
+    /// Pointer velocity in pixels per second.
+    pub velocity: Vec2,
+}
+
+/// Configuration for [`use_drag`].
+#[derive(Clone)]
+pub struct DragOptions {
+    constraints: Option<DragConstraints>,
+    elastic: f32,
+    inertia: bool,
+    config: AnimationConfig,
+    on_drag_start: Option<EventHandler<DragInfo>>,
+    on_drag: Option<EventHandler<DragInfo>>,
+    on_drag_end: Option<EventHandler<DragInfo>>,
+}
+
+impl Default for DragOptions {
+    fn default() -> Self {
+        Self {
+            constraints: None,
+            elastic: 0.35,
+            inertia: true,
+            config: AnimationConfig::spring(Spring {
+                stiffness: 200.0,
+                damping: 26.0,
+                ..Spring::default()
+            }),
+            on_drag_start: None,
+            on_drag: None,
+            on_drag_end: None,
+        }
+    }
+}
+
+impl DragOptions {
+    /// Unconstrained drag with inertia.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the offset; dragging past the bounds meets elastic resistance.
+    pub fn constraints(mut self, constraints: DragConstraints) -> Self {
+        self.constraints = Some(constraints);
+        self
+    }
+
+    /// Fraction of the overshoot past the constraints that the element follows (default `0.35`).
+    pub fn elastic(mut self, elastic: f32) -> Self {
+        self.elastic = elastic.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Whether releasing keeps moving with the release velocity (default `true`).
+    pub fn inertia(mut self, inertia: bool) -> Self {
+        self.inertia = inertia;
+        self
+    }
+
+    /// Animation used for the fling and for settling back inside the constraints.
+    pub fn config(mut self, config: AnimationConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Called when the pointer goes down on the element.
+    pub fn on_drag_start(mut self, handler: impl FnMut(DragInfo) + 'static) -> Self {
+        self.on_drag_start = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// Called on every pointer move while dragging.
+    pub fn on_drag(mut self, handler: impl FnMut(DragInfo) + 'static) -> Self {
+        self.on_drag = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// Called when the pointer is released, with the release velocity.
+    pub fn on_drag_end(mut self, handler: impl FnMut(DragInfo) + 'static) -> Self {
+        self.on_drag_end = Some(EventHandler::new(handler));
+        self
+    }
+
+    fn resist(&self, offset: Vec2) -> Vec2 {
+        self.constraints.map_or(offset, |constraints| {
+            constraints.elastic(offset, self.elastic)
+        })
+    }
+
+    /// Where a release at `offset` with `velocity` comes to rest.
+    pub(crate) fn resting_offset(&self, offset: Vec2, velocity: Vec2) -> Vec2 {
+        let projected = if self.inertia {
+            offset + velocity * INERTIA_TIME
+        } else {
+            offset
+        };
+        self.constraints
+            .map_or(projected, |constraints| constraints.clamp(projected))
+    }
+}
+
+/// Recent pointer positions used to estimate the release velocity.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct VelocityTracker {
+    samples: Vec<(f32, Vec2)>,
+}
+
+impl VelocityTracker {
+    pub(crate) fn push(&mut self, time: f32, position: Vec2) {
+        self.samples
+            .retain(|(sample_time, _)| time - sample_time <= VELOCITY_WINDOW);
+        self.samples.push((time, position));
+    }
+
+    /// Velocity as of the latest sample.
+    pub(crate) fn velocity(&self) -> Vec2 {
+        self.samples
+            .last()
+            .map_or(Vec2::ZERO, |(time, _)| self.velocity_at(*time))
+    }
+
+    /// Velocity as of `time`, from the samples taken within [`VELOCITY_WINDOW`] of it.
+    ///
+    /// A pointer that stopped moving before `time` has no recent samples and reads as
+    /// still, so holding and then releasing doesn't fling.
+    pub(crate) fn velocity_at(&self, time: f32) -> Vec2 {
+        let mut recent = self
+            .samples
+            .iter()
+            .filter(|(sample_time, _)| time - sample_time <= VELOCITY_WINDOW);
+        let (Some(first), Some(last)) = (recent.next(), self.samples.last()) else {
+            return Vec2::ZERO;
+        };
+        let elapsed = time - first.0;
+        if elapsed <= f32::EPSILON {
+            return Vec2::ZERO;
+        }
+        (last.1 - first.1) * (1.0 / elapsed)
+    }
+}
+
+struct DragSession {
+    pointer_id: i32,
+    pointer_origin: Vec2,
+    offset_origin: Vec2,
+    started: instant::Instant,
+    tracker: VelocityTracker,
+}
+
+/// Handle returned by [`use_drag`]; forward the element's pointer events to it.
+#[derive(Clone, Copy)]
+pub struct Drag {
+    offset: MotionHandle<Vec2>,
+    options: CopyValue<DragOptions>,
+    session: CopyValue<Option<DragSession>>,
+}
+
+impl Drag {
+    /// The drag offset, animated by the fling after release.
+    pub fn offset(&self) -> MotionHandle<Vec2> {
+        self.offset
+    }
+
+    /// Whether a pointer is currently dragging the element.
+    pub fn is_dragging(&self) -> bool {
+        self.session.peek().is_some()
+    }
+
+    /// Starts a drag; use as the element's `onpointerdown` handler.
+    pub fn onpointerdown(mut self, event: PointerEvent) {
+        if !event.is_primary() || self.is_dragging() {
+            return;
+        }
+
+        #[cfg(feature = "web")]
+        capture_pointer(&event);
+
+        let mut offset = self.offset;
+        offset.stop();
+        let current = offset.get_value();
+        let pointer = pointer_position(&event);
+        let mut tracker = VelocityTracker::default();
+        tracker.push(0.0, current);
+        self.session.set(Some(DragSession {
+            pointer_id: event.pointer_id(),
+            pointer_origin: pointer,
+            offset_origin: current,
+            started: Time::now(),
+            tracker,
+        }));
+
+        let handler = self.options.peek().on_drag_start;
+        if let Some(handler) = handler {
+            handler.call(DragInfo {
+                offset: current,
+                velocity: Vec2::ZERO,
+            });
+        }
+    }
+
+    /// Moves the element with the pointer; use as the element's `onpointermove` handler.
+    pub fn onpointermove(mut self, event: PointerEvent) {
+        let info = {
+            let mut session = self.session.write();
+            let Some(session) = session.as_mut() else {
+                return;
+            };
+            if session.pointer_id != event.pointer_id() {
+                return;
+            }
+
+            let raw = session.offset_origin + (pointer_position(&event) - session.pointer_origin);
+            let offset = self.options.peek().resist(raw);
+            let time = Time::now().duration_since(session.started).as_secs_f32();
+            session.tracker.push(time, offset);
+            DragInfo {
+                offset,
+                velocity: session.tracker.velocity(),
+            }
+        };
+
+        let mut handle = self.offset;
+        handle.set_current(info.offset);
+
+        let handler = self.options.peek().on_drag;
+        if let Some(handler) = handler {
+            handler.call(info);
+        }
+    }
+
+    /// Ends the drag and flings; use for both `onpointerup` and `onpointercancel`.
+    pub fn onpointerup(mut self, event: PointerEvent) {
+        let session = self
+            .session
+            .write()
+            .take_if(|session| session.pointer_id == event.pointer_id());
+        let Some(session) = session else {
+            return;
+        };
+
+        let offset = self.offset.get_value();
+        let released = Time::now().duration_since(session.started).as_secs_f32();
+        let velocity = session.tracker.velocity_at(released);
+        let options = self.options.peek().clone();
+        let rest = options.resting_offset(offset, velocity);
+        let initial_velocity = if options.inertia {
+            velocity
+        } else {
+            Vec2::ZERO
+        };
+
+        let mut handle = self.offset;
+        handle.animate_with_velocity(rest, options.config.clone(), initial_velocity);
+
+        if let Some(handler) = options.on_drag_end {
+            handler.call(DragInfo { offset, velocity });
+        }
+    }
+}
+
+fn pointer_position(event: &PointerEvent) -> Vec2 {
+    let point = event.client_coordinates();
+    Vec2::new(point.x as f32, point.y as f32)
+}
+
+/// Keeps pointer events flowing to the element while the pointer is outside it.
+#[cfg(feature = "web")]
+fn capture_pointer(event: &PointerEvent) {
+    use dioxus::web::WebEventExt;
+    use wasm_bindgen::JsCast;
+
+    let web_event = event.data().as_web_event();
+    if let Some(element) = web_event
+        .current_target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+    {
+        let _ = element.set_pointer_capture(event.pointer_id());
+    }
+}
+
+/// Tracks pointer drags on an element and flings it with the release velocity.
+///
+/// Forward `onpointerdown`, `onpointermove`, `onpointerup` and `onpointercancel` to the
+/// returned [`Drag`] and render [`Drag::offset`] as a translation. On the web the pointer
+/// is captured on press, so the drag keeps tracking when the pointer leaves the element.
+///
+/// Past the [`DragConstraints`] the element follows only a fraction of the pointer
+/// (see [`DragOptions::elastic`]). On release the offset animates with
+/// [`DragOptions::config`] toward where the release velocity would carry it, clamped
+/// back inside the constraints; with inertia disabled it only settles into bounds.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(feature = "dioxus")] {
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn app() -> Element {
+///     let mut dragging = use_signal(|| false);
+///     let drag = use_drag(
+///         DragOptions::new()
+///             .constraints(DragConstraints::new(-150.0, 150.0, -80.0, 80.0))
+///             .on_drag_start(move |_| dragging.set(true))
+///             .on_drag_end(move |_| dragging.set(false)),
+///     );
+///     let offset = drag.offset().get_value();
+///     let shadow = if dragging() { 24 } else { 8 };
+///
+///     rsx! {
+///         div {
+///             style: "touch-action: none; transform: translate({offset.x}px, {offset.y}px); \
+///                     box-shadow: 0 {shadow}px {shadow * 2}px rgba(0, 0, 0, 0.2);",
+///             onpointerdown: move |event| drag.onpointerdown(event),
+///             onpointermove: move |event| drag.onpointermove(event),
+///             onpointerup: move |event| drag.onpointerup(event),
+///             onpointercancel: move |event| drag.onpointerup(event),
+///             "Drag me"
+///         }
+///     }
+/// }
+/// # }
+/// ```
+pub fn use_drag(options: DragOptions) -> Drag {
+    let offset = use_motion(Vec2::ZERO);
+    let mut stored = use_hook(|| CopyValue::new(options.clone()));
+    let session = use_hook(|| CopyValue::new(None));
+    stored.set(options);
+
+    Drag {
+        offset,
+        options: stored,
+        session,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DragConstraints, DragOptions, VelocityTracker};
+    use crate::prelude::Vec2;
+
+    #[test]
+    fn constraints_clamp_each_axis() {
+        let bounds = DragConstraints::new(-50.0, 50.0, -10.0, 30.0);
+
+        assert_eq!(bounds.clamp(Vec2::new(0.0, 0.0)), Vec2::new(0.0, 0.0));
+        assert_eq!(bounds.clamp(Vec2::new(-80.0, 40.0)), Vec2::new(-50.0, 30.0));
+        assert_eq!(bounds.clamp(Vec2::new(90.0, -20.0)), Vec2::new(50.0, -10.0));
+        assert_eq!(
+            DragConstraints::default().clamp(Vec2::new(1e6, -1e6)),
+            Vec2::new(1e6, -1e6)
+        );
+    }
+
+    #[test]
+    fn elastic_scales_only_the_overshoot() {
+        let bounds = DragConstraints::new(-50.0, 50.0, 0.0, 0.0);
+
+        assert_eq!(
+            bounds.elastic(Vec2::new(20.0, 0.0), 0.5),
+            Vec2::new(20.0, 0.0)
+        );
+        assert_eq!(
+            bounds.elastic(Vec2::new(90.0, 10.0), 0.5),
+            Vec2::new(70.0, 5.0)
+        );
+        assert_eq!(
+            bounds.elastic(Vec2::new(-90.0, 0.0), 0.0),
+            Vec2::new(-50.0, 0.0)
+        );
+        assert_eq!(
+            bounds.elastic(Vec2::new(-90.0, 0.0), 1.0),
+            Vec2::new(-90.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn release_projects_velocity_and_settles_inside_bounds() {
+        let options =
+            DragOptions::new().constraints(DragConstraints::new(-100.0, 100.0, -100.0, 100.0));
+
+        assert_eq!(
+            options.resting_offset(Vec2::new(0.0, 0.0), Vec2::new(250.0, 0.0)),
+            Vec2::new(50.0, 0.0)
+        );
+        assert_eq!(
+            options.resting_offset(Vec2::new(90.0, 0.0), Vec2::new(1000.0, 0.0)),
+            Vec2::new(100.0, 0.0)
+        );
+        assert_eq!(
+            options
+                .inertia(false)
+                .resting_offset(Vec2::new(120.0, 0.0), Vec2::new(1000.0, 0.0)),
+            Vec2::new(100.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn velocity_uses_recent_samples() {
+        let mut tracker = VelocityTracker::default();
+        assert_eq!(tracker.velocity(), Vec2::ZERO);
+
+        tracker.push(0.0, Vec2::new(0.0, 0.0));
+        tracker.push(0.5, Vec2::new(500.0, 0.0));
+        tracker.push(0.54, Vec2::new(520.0, 0.0));
+        tracker.push(0.58, Vec2::new(540.0, 10.0));
+
+        let velocity = tracker.velocity();
+        assert!((velocity.x - 500.0).abs() < 1e-1);
+        assert!((velocity.y - 125.0).abs() < 1e-1);
+    }
+
+    #[test]
+    fn release_after_holding_still_does_not_fling() {
+        let mut tracker = VelocityTracker::default();
+        tracker.push(0.0, Vec2::new(0.0, 0.0));
+        tracker.push(0.05, Vec2::new(50.0, 0.0));
+        tracker.push(0.1, Vec2::new(100.0, 0.0));
+
+        assert!((tracker.velocity_at(0.1).x - 1000.0).abs() < 1e-1);
+        assert_eq!(tracker.velocity_at(0.5), Vec2::ZERO);
+
+        // Held for part of the window: the stillness slows the release
+        let slowed = tracker.velocity_at(0.14);
+        assert!((slowed.x - 50.0 / 0.09).abs() < 1e-1);
+    }
+}
@@ -1,13 +1,16 @@
 use crate::Duration;
 use crate::animations::core::Animatable;
+use crate::animations::vec2::Vec2;
 use tracing::error;
 
 pub type EasingFn = fn(f32, f32, f32, f32) -> f32;
 
 #[derive(Debug, thiserror::Error)]
 pub enum KeyframeError {
-    #[error("Failed to compare keyframe offsets (possible NaN value)")]
+    #[error("Invalid keyframe offset (NaN, or a percentage outside 0-100)")]
     InvalidOffset,
+    #[error("Hold keyframe has no earlier keyframe to inherit a value from")]
+    NothingToHold,
 }
 
 #[derive(Clone)]
@@ -17,10 +20,26 @@ pub struct Keyframe<T: Animatable> {
     pub easing: Option<EasingFn>,
 }
 
+/// How values are blended between neighbouring keyframes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyframeInterpolation {
+    /// Straight lines between keyframes; speed jumps at every keyframe
+    #[default]
+    Linear,
+    /// A Catmull-Rom spline through the keyframe values
+    ///
+    /// Each keyframe's velocity is taken from its neighbours, so the motion passes
+    /// through every keyframe without a kink. Keyframes where the value turns back, or
+    /// that repeat a neighbour's value as a hold does, come to rest instead, so the
+    /// curve never overshoots them.
+    Spline,
+}
+
 #[derive(Clone)]
 pub struct KeyframeAnimation<T: Animatable> {
     pub keyframes: Vec<Keyframe<T>>,
     pub duration: Duration,
+    pub(crate) interpolation: KeyframeInterpolation,
 }
 
 impl<T: Animatable> KeyframeAnimation<T> {
@@ -28,9 +47,21 @@ impl<T: Animatable> KeyframeAnimation<T> {
         Self {
             keyframes: Vec::new(),
             duration,
+            interpolation: KeyframeInterpolation::Linear,
         }
     }
 
+    /// Sets how values are blended between keyframes; linear by default.
+    pub fn with_interpolation(mut self, interpolation: KeyframeInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// How values are blended between keyframes.
+    pub fn interpolation(&self) -> KeyframeInterpolation {
+        self.interpolation
+    }
+
     pub fn add_keyframe(
         mut self,
         value: T,
@@ -57,4 +88,336 @@ impl<T: Animatable> KeyframeAnimation<T> {
         }
         Ok(self)
     }
+
+    /// Adds a keyframe at `percent` of the way through, like a CSS `@keyframes` selector.
+    ///
+    /// `at_percent(value, 50.0, None)` is `add_keyframe(value, 0.5, None)`, so CSS
+    /// keyframes port over one line at a time. Unlike [`Self::add_keyframe`], which clamps
+    /// its offset, percentages outside `0.0..=100.0` or `NaN` are rejected with
+    /// [`KeyframeError::InvalidOffset`].
+    ///
+    /// ```rust
+    /// use dioxus_motion::KeyframeAnimation;
+    /// use dioxus_motion::prelude::Duration;
+    ///
+    /// // @keyframes pulse { 0% { opacity: 1 } 50% { opacity: 0.5 } 100% { opacity: 1 } }
+    /// let pulse = KeyframeAnimation::new(Duration::from_millis(800))
+    ///     .at_percent(1.0f32, 0.0, None)?
+    ///     .at_percent(0.5, 50.0, None)?
+    ///     .at_percent(1.0, 100.0, None)?;
+    /// assert_eq!(pulse.value_at(0.5), Some(0.5));
+    /// # Ok::<(), dioxus_motion::keyframes::KeyframeError>(())
+    /// ```
+    pub fn at_percent(
+        self,
+        value: T,
+        percent: f32,
+        easing: Option<EasingFn>,
+    ) -> Result<Self, KeyframeError> {
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(KeyframeError::InvalidOffset);
+        }
+        self.add_keyframe(value, percent / 100.0, easing)
+    }
+
+    /// Adds a keyframe at `offset` that holds the value of the keyframe before it.
+    ///
+    /// The value is copied from the closest keyframe at or before `offset`, which is the
+    /// last one added when keyframes are built in order. Returns
+    /// [`KeyframeError::NothingToHold`] if there is no such keyframe.
+    pub fn add_hold(self, offset: f32, easing: Option<EasingFn>) -> Result<Self, KeyframeError> {
+        let offset = offset.clamp(0.0, 1.0);
+        let value = self
+            .keyframes
+            .iter()
+            .rev()
+            .find(|keyframe| keyframe.offset <= offset)
+            .map(|keyframe| keyframe.value.clone())
+            .ok_or(KeyframeError::NothingToHold)?;
+
+        self.add_keyframe(value, offset, easing)
+    }
+
+    /// Samples the animation at a normalized `progress` (0.0 to 1.0).
+    ///
+    /// Returns `None` when the animation has no keyframes.
+    pub fn value_at(&self, progress: f32) -> Option<T> {
+        let progress = progress.clamp(0.0, 1.0);
+        let first = self.keyframes.first()?;
+        let last = self.keyframes.last()?;
+
+        let Some(index) = self
+            .keyframes
+            .windows(2)
+            .position(|window| progress >= window[0].offset && progress <= window[1].offset)
+        else {
+            let edge = if progress <= first.offset {
+                first
+            } else {
+                last
+            };
+            return Some(edge.value.clone());
+        };
+
+        let (start, end) = (&self.keyframes[index], &self.keyframes[index + 1]);
+        if start.offset == end.offset {
+            return Some(end.value.clone());
+        }
+
+        let local_progress = (progress - start.offset) / (end.offset - start.offset);
+        let eased_progress = end
+            .easing
+            .map_or(local_progress, |ease| (ease)(local_progress, 0.0, 1.0, 1.0));
+
+        Some(match self.interpolation {
+            KeyframeInterpolation::Linear => start.value.interpolate(&end.value, eased_progress),
+            KeyframeInterpolation::Spline => self.spline_segment(index, eased_progress),
+        })
+    }
+
+    /// Cubic Hermite segment from keyframe `index` to the next, at local progress `t`.
+    fn spline_segment(&self, index: usize, t: f32) -> T {
+        let start = &self.keyframes[index];
+        let end = &self.keyframes[index + 1];
+        let span = end.offset - start.offset;
+
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let h01 = 3.0 * t2 - 2.0 * t3;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h11 = t3 - t2;
+
+        start.value.clone()
+            + (end.value.clone() - start.value.clone()) * h01
+            + self.tangent(index) * (h10 * span)
+            + self.tangent(index + 1) * (h11 * span)
+    }
+
+    /// Velocity at keyframe `index` per unit of progress, from its neighbours.
+    ///
+    /// The first and last keyframes use their only neighbour. The velocity is zero where
+    /// the value turns back or matches a neighbour, which keeps extremes and holds flat.
+    fn tangent(&self, index: usize) -> T {
+        let before = &self.keyframes[index.saturating_sub(1)];
+        let current = &self.keyframes[index];
+        let after = &self.keyframes[(index + 1).min(self.keyframes.len() - 1)];
+        let span = after.offset - before.offset;
+        if span <= 0.0 {
+            return T::default() * 0.0;
+        }
+
+        let incoming = current.value.clone() - before.value.clone();
+        let outgoing = after.value.clone() - current.value.clone();
+        let is_interior = index > 0 && index + 1 < self.keyframes.len();
+        // Four times the dot product of the two steps, by the polarization identity
+        let turn = (incoming.clone() + outgoing.clone()).magnitude().powi(2)
+            - (incoming.clone() - outgoing.clone()).magnitude().powi(2);
+        if is_interior
+            && (incoming.magnitude() <= f32::EPSILON
+                || outgoing.magnitude() <= f32::EPSILON
+                || turn < 0.0)
+        {
+            return T::default() * 0.0;
+        }
+        (after.value.clone() - before.value.clone()) * (1.0 / span)
+    }
+
+    /// The same animation played backwards, ending where it started.
+    pub(crate) fn reversed(&self) -> Self {
+        let keyframes = self
+            .keyframes
+            .iter()
+            .rev()
+            .map(|keyframe| Keyframe {
+                value: keyframe.value.clone(),
+                offset: 1.0 - keyframe.offset,
+                easing: keyframe.easing,
+            })
+            .collect();
+
+        Self {
+            keyframes,
+            ..self.clone()
+        }
+    }
+}
+
+impl KeyframeAnimation<Vec2> {
+    /// A smooth path through `points`, taking `duration` from the first to the last.
+    ///
+    /// Each point becomes a keyframe, spaced by the distance travelled along the path so
+    /// the speed stays even however far apart the points are, and a spline joins them.
+    /// Points that all coincide are spaced evenly instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::KeyframeAnimation;
+    /// use dioxus_motion::prelude::{Duration, Vec2};
+    ///
+    /// let points = [Vec2::new(0.0, 0.0), Vec2::new(30.0, 0.0), Vec2::new(30.0, 10.0)];
+    /// let path = KeyframeAnimation::through_points(&points, Duration::from_secs(1));
+    /// assert_eq!(path.keyframes[1].offset, 0.75);
+    /// ```
+    pub fn through_points(points: &[Vec2], duration: Duration) -> Self {
+        let mut travelled = Vec::with_capacity(points.len());
+        let mut total = 0.0;
+        for (index, point) in points.iter().enumerate() {
+            if let Some(previous) = index.checked_sub(1).map(|previous| points[previous]) {
+                total += (*point - previous).length();
+            }
+            travelled.push(total);
+        }
+
+        let last = points.len().saturating_sub(1).max(1) as f32;
+        let keyframes = points
+            .iter()
+            .zip(travelled)
+            .enumerate()
+            .map(|(index, (point, distance))| Keyframe {
+                value: *point,
+                offset: if total > 0.0 {
+                    distance / total
+                } else {
+                    index as f32 / last
+                },
+                easing: None,
+            })
+            .collect();
+
+        Self {
+            keyframes,
+            duration,
+            interpolation: KeyframeInterpolation::Spline,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_at_percent_matches_fractional_offsets() {
+        use easer::functions::{Cubic, Easing};
+
+        let by_percent = KeyframeAnimation::new(Duration::from_secs(1))
+            .at_percent(0.0f32, 0.0, None)
+            .unwrap()
+            .at_percent(80.0, 25.0, Some(Cubic::ease_out))
+            .unwrap()
+            .at_percent(100.0, 100.0, None)
+            .unwrap();
+        let by_offset = KeyframeAnimation::new(Duration::from_secs(1))
+            .add_keyframe(0.0f32, 0.0, None)
+            .unwrap()
+            .add_keyframe(80.0, 0.25, Some(Cubic::ease_out))
+            .unwrap()
+            .add_keyframe(100.0, 1.0, None)
+            .unwrap();
+
+        for step in 0..=20 {
+            let progress = step as f32 / 20.0;
+            assert_eq!(by_percent.value_at(progress), by_offset.value_at(progress));
+        }
+
+        for percent in [-1.0, 100.5, f32::NAN] {
+            assert!(matches!(
+                KeyframeAnimation::new(Duration::from_secs(1)).at_percent(0.0f32, percent, None),
+                Err(KeyframeError::InvalidOffset)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_add_hold_keeps_previous_value() {
+        let animation = KeyframeAnimation::new(Duration::from_secs(1))
+            .add_keyframe(0.0f32, 0.0, None)
+            .unwrap()
+            .add_hold(0.5, None)
+            .unwrap()
+            .add_keyframe(100.0, 1.0, None)
+            .unwrap();
+
+        assert_eq!(animation.value_at(0.0), Some(0.0));
+        assert_eq!(animation.value_at(0.25), Some(0.0));
+        assert_eq!(animation.value_at(0.5), Some(0.0));
+        assert_eq!(animation.value_at(0.75), Some(50.0));
+        assert_eq!(animation.value_at(1.0), Some(100.0));
+    }
+
+    #[test]
+    fn test_spline_smooths_velocity_through_keyframes() {
+        let build = |interpolation| {
+            KeyframeAnimation::new(Duration::from_secs(1))
+                .with_interpolation(interpolation)
+                .add_keyframe(0.0f32, 0.0, None)
+                .unwrap()
+                .add_keyframe(20.0, 0.5, None)
+                .unwrap()
+                .add_keyframe(100.0, 1.0, None)
+                .unwrap()
+        };
+        let velocity_jump = |animation: &KeyframeAnimation<f32>| {
+            let h = 1e-3;
+            let at = |progress| animation.value_at(progress).unwrap();
+            let before = (at(0.5) - at(0.5 - h)) / h;
+            let after = (at(0.5 + h) - at(0.5)) / h;
+            (after - before).abs()
+        };
+
+        let linear = build(KeyframeInterpolation::Linear);
+        let spline = build(KeyframeInterpolation::Spline);
+
+        assert!((velocity_jump(&linear) - 120.0).abs() < 1.0);
+        assert!(velocity_jump(&spline) < 2.0);
+        for progress in [0.0, 0.5, 1.0] {
+            assert_eq!(spline.value_at(progress), linear.value_at(progress));
+        }
+    }
+
+    #[test]
+    fn test_spline_keeps_holds_and_peaks_flat() {
+        let hold = KeyframeAnimation::new(Duration::from_secs(1))
+            .with_interpolation(KeyframeInterpolation::Spline)
+            .add_keyframe(0.0f32, 0.0, None)
+            .unwrap()
+            .add_hold(0.5, None)
+            .unwrap()
+            .add_keyframe(100.0, 1.0, None)
+            .unwrap();
+        let peak = KeyframeAnimation::new(Duration::from_secs(1))
+            .with_interpolation(KeyframeInterpolation::Spline)
+            .add_keyframe(0.0f32, 0.0, None)
+            .unwrap()
+            .add_keyframe(100.0, 0.5, None)
+            .unwrap()
+            .add_keyframe(0.0, 1.0, None)
+            .unwrap();
+
+        let mut previous = 0.0;
+        for step in 0..=100 {
+            let progress = step as f32 / 100.0;
+            let value = hold.value_at(progress).unwrap();
+            if progress <= 0.5 {
+                assert_eq!(value, 0.0, "hold drifted at {progress}");
+            }
+            assert!(value >= previous, "hold went backwards at {progress}");
+            previous = value;
+
+            let value = peak.value_at(progress).unwrap();
+            assert!(
+                (0.0..=100.0).contains(&value),
+                "overshot at {progress}: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_hold_requires_previous_keyframe() {
+        let result = KeyframeAnimation::<f32>::new(Duration::from_secs(1)).add_hold(0.5, None);
+
+        assert!(matches!(result, Err(KeyframeError::NothingToHold)));
+    }
 }
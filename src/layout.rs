@@ -0,0 +1,231 @@
+//! Layout animation (FLIP) for elements whose position changes between renders.
+//!
+//! [`use_layout_motion`] measures the element after every render of its component. When
+//! the element has moved, it jumps a transform offset back to the old position and
+//! animates that offset to zero, so reorders and layout shifts slide into place instead
+//! of snapping.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use dioxus_core::queue_effect;
+
+use crate::{
+    animations::{core::AnimationConfig, transform::Transform},
+    manager::{AnimationManager, MotionHandle},
+    use_motion,
+};
+
+/// Position changes smaller than this many pixels are treated as measurement noise.
+const LAYOUT_THRESHOLD: f32 = 0.5;
+
+#[derive(Default)]
+struct LayoutState {
+    node: Option<Rc<MountedData>>,
+    /// Last measured layout position, with the layout offset removed.
+    origin: Option<(f64, f64)>,
+    config: AnimationConfig,
+}
+
+/// Handle returned by [`use_layout_motion`].
+///
+/// Attach it to the element with [`LayoutMotion::mount`] and render
+/// [`LayoutMotion::style`] (or [`LayoutMotion::transform`]) on the same element.
+#[derive(Clone, Copy)]
+pub struct LayoutMotion {
+    offset: MotionHandle<Transform>,
+    state: CopyValue<LayoutState>,
+}
+
+impl PartialEq for LayoutMotion {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl LayoutMotion {
+    /// Records the element to measure; use as the element's `onmounted` handler.
+    pub fn mount(mut self, event: MountedEvent) {
+        self.state.write().node = Some(event.data());
+        self.measure(*self.offset.current().peek());
+    }
+
+    /// The current layout offset, `Transform::identity()` once the element has settled.
+    pub fn transform(&self) -> Transform {
+        self.offset.get_value()
+    }
+
+    /// The layout offset as a `transform` declaration for the element's `style`.
+    pub fn style(&self) -> String {
+        format!("transform: {};", self.transform().to_css())
+    }
+
+    /// The underlying offset motion, e.g. to stop or inspect the layout animation.
+    pub fn handle(&self) -> MotionHandle<Transform> {
+        self.offset
+    }
+
+    fn measure(self, rendered: Transform) {
+        let Some(node) = self.state.peek().node.clone() else {
+            return;
+        };
+
+        spawn(async move {
+            if let Ok(rect) = node.get_client_rect().await {
+                self.record(rendered, rect.origin.x, rect.origin.y);
+            }
+        });
+    }
+
+    /// Compares a measured position with the previous one and starts the layout animation.
+    ///
+    /// `rendered` is the offset that was applied when the element was measured, so the
+    /// stored origin is the element's position without the layout offset.
+    pub(crate) fn record(mut self, rendered: Transform, x: f64, y: f64) {
+        let origin = (x - f64::from(rendered.x), y - f64::from(rendered.y));
+        let previous = self.state.write().origin.replace(origin);
+        let Some(previous) = previous else {
+            return;
+        };
+
+        let dx = (previous.0 - origin.0) as f32;
+        let dy = (previous.1 - origin.1) as f32;
+        if dx.abs() <= LAYOUT_THRESHOLD && dy.abs() <= LAYOUT_THRESHOLD {
+            return;
+        }
+
+        let mut offset = self.offset;
+        let mut current = *offset.current().peek();
+        current.x += dx;
+        current.y += dy;
+        let config = self.state.peek().config.clone();
+        offset.set_current(current);
+        offset.animate_to(Transform::identity(), config);
+    }
+}
+
+/// Animates an element to its new position whenever a re-render moves it in the layout.
+///
+/// After each render of the calling component the element attached with
+/// [`LayoutMotion::mount`] is measured. If it moved, the returned offset jumps back to the
+/// previous position and animates to [`Transform::identity`] with `config`. A move that
+/// happens mid-animation continues from the element's current on-screen position.
+///
+/// Measuring uses `MountedData::get_client_rect`, so it works on web and desktop
+/// renderers; on renderers without element geometry the offset simply stays at identity.
+///
+/// The component must re-render when the element moves. For keyed list items, passing
+/// the item's index as a prop is enough. The layout offset is independent of other
+/// motions: to combine it with an explicit transform animation, render
+/// [`LayoutMotion::style`] on a wrapper element, or compose the two with
+/// [`Transform::compose`] so the layout translation is applied first.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(feature = "dioxus")] {
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// #[component]
+/// fn Item(label: String, index: usize) -> Element {
+///     let layout = use_layout_motion(AnimationConfig::spring(Spring::default()));
+///
+///     rsx! {
+///         li {
+///             style: "{layout.style()}",
+///             onmounted: move |event| layout.mount(event),
+///             "{label}"
+///         }
+///     }
+/// }
+///
+/// fn app() -> Element {
+///     let mut items = use_signal(|| vec!["one", "two", "three"]);
+///
+///     rsx! {
+///         button { onclick: move |_| items.write().rotate_left(1), "Shuffle" }
+///         ul {
+///             for (index, label) in items.read().iter().enumerate() {
+///                 Item { key: "{label}", label: label.to_string(), index }
+///             }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+pub fn use_layout_motion(config: AnimationConfig) -> LayoutMotion {
+    let offset = use_motion(Transform::identity());
+    let mut state = use_hook(|| CopyValue::new(LayoutState::default()));
+    state.write().config = config;
+
+    let layout = LayoutMotion { offset, state };
+    let rendered = *offset.current().peek();
+    queue_effect(move || layout.measure(rendered));
+
+    layout
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{cell::RefCell, rc::Rc};
+
+    use dioxus::prelude::{Element, ScopeId, VNode, VirtualDom};
+
+    use super::{LayoutMotion, use_layout_motion};
+    use crate::{
+        manager::AnimationManager,
+        prelude::{AnimationConfig, Transform},
+    };
+
+    type HostedLayout = Rc<RefCell<Option<LayoutMotion>>>;
+
+    #[allow(non_snake_case)]
+    fn LayoutHost(layout: HostedLayout) -> Element {
+        *layout.borrow_mut() = Some(use_layout_motion(AnimationConfig::tween_ms(200)));
+
+        VNode::empty()
+    }
+
+    #[test]
+    fn moving_element_animates_offset_back_to_identity() {
+        let layout = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(LayoutHost, Rc::clone(&layout));
+        dom.rebuild_in_place();
+        let layout = layout.borrow().unwrap();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            layout.record(Transform::identity(), 0.0, 0.0);
+            assert!(!layout.handle().is_running());
+
+            layout.record(Transform::identity(), 0.0, 100.0);
+            assert_eq!(layout.transform().y, -100.0);
+            assert!(layout.handle().is_running());
+
+            let mut handle = layout.handle();
+            while handle.update(1.0 / 60.0) {}
+            assert_eq!(layout.transform(), Transform::identity());
+        });
+    }
+
+    #[test]
+    fn offset_applied_while_measuring_is_not_a_move() {
+        let layout = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(LayoutHost, Rc::clone(&layout));
+        dom.rebuild_in_place();
+        let layout = layout.borrow().unwrap();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            layout.record(Transform::identity(), 0.0, 0.0);
+            layout.record(Transform::identity(), 40.0, 0.0);
+            let mut handle = layout.handle();
+            handle.update(1.0 / 20.0);
+            let rendered = layout.transform();
+
+            layout.record(rendered, 40.0 + f64::from(rendered.x), 0.0);
+            assert_eq!(layout.transform(), rendered);
+        });
+    }
+}
@@ -99,12 +99,21 @@
 #[cfg(feature = "dioxus")]
 use animations::core::Animatable;
 #[cfg(feature = "dioxus")]
+use animations::platform::{FrameClock, document_hidden};
+#[cfg(feature = "dioxus")]
 use dioxus::prelude::*;
 pub use instant::Duration;
 
+mod animate_macros;
 pub mod animations;
+#[cfg(feature = "dioxus")]
+pub mod crossfade;
+#[cfg(feature = "dioxus")]
+pub mod drag;
 pub mod keyframes;
 #[cfg(feature = "dioxus")]
+pub mod layout;
+#[cfg(feature = "dioxus")]
 pub mod manager;
 pub mod motion;
 #[allow(dead_code)]
@@ -113,8 +122,12 @@ pub(crate) mod pool;
 pub mod presence;
 #[cfg(feature = "dioxus")]
 mod presence_macros;
+#[cfg(feature = "dioxus")]
+pub mod scroll;
 pub mod sequence;
 mod style_macros;
+#[cfg(feature = "dioxus")]
+pub mod timeline;
 #[cfg(feature = "transitions")]
 pub mod transitions;
 
@@ -123,22 +136,55 @@ pub use dioxus_motion_transitions_macro;
 
 pub use animations::platform::{MotionTime, TimeProvider};
 
-pub use keyframes::{Keyframe, KeyframeAnimation};
 #[cfg(feature = "dioxus")]
-pub use manager::{AnimationManager, MotionHandle};
+pub use crossfade::{Crossfade, use_crossfade};
+#[cfg(feature = "dioxus")]
+pub use drag::{Drag, DragConstraints, DragInfo, DragOptions, use_drag};
+
+pub use keyframes::{Keyframe, KeyframeAnimation, KeyframeInterpolation};
+#[cfg(feature = "dioxus")]
+pub use layout::{LayoutMotion, use_layout_motion};
+#[cfg(feature = "dioxus")]
+pub use manager::{AnimationManager, MotionHandle, MotionSubscription, animate_batch, stop_all};
 #[cfg(test)]
 pub(crate) use motion::Motion;
+#[cfg(feature = "dioxus")]
+pub use motion::in_view::{InViewOptions, InViewRef, use_in_view_motion};
+pub use pool::{PoolConfig, PoolGrowth, PoolSizing, PoolStats, resource_pools};
+#[cfg(feature = "dioxus")]
+pub use scroll::{ScrollMotion, ScrollSource, use_scroll_motion};
 
 // Re-exports
 pub mod prelude {
-    pub use crate::animations::core::{AnimationConfig, AnimationMode, LoopMode};
+    pub use crate::animate;
+    pub use crate::animations::core::{AnimationConfig, AnimationMode, LoopMode, RoundingMode};
     pub use crate::animations::css::{CssColor, CssComplexValue, CssValue, IntoCssValue};
     pub use crate::animations::style::MotionStyle;
     pub use crate::animations::{
-        colors::Color, spring::Spring, transform::Transform, tween::Tween,
+        bounds::{BoundaryBehavior, Bounded},
+        colors::{BlendMode, Color, ColorGradient, ColorGradientError, ColorSpace},
+        composite::Composite,
+        duration::AnimatableDuration,
+        modular::Modular,
+        path::PathDraw,
+        precise::Precise,
+        spring::{Spring, TransformSprings},
+        text::TypedText,
+        transform::Transform,
+        tween::{StepJump, StepsError, Tween},
+        vec2::Vec2,
     };
+    #[cfg(feature = "dioxus")]
+    pub use crate::crossfade::{Crossfade, use_crossfade};
     #[cfg(feature = "transitions")]
     pub use crate::dioxus_motion_transitions_macro::MotionTransitions;
+    #[cfg(feature = "dioxus")]
+    pub use crate::drag::{Drag, DragConstraints, DragInfo, DragOptions, use_drag};
+    #[cfg(feature = "dioxus")]
+    pub use crate::layout::{LayoutMotion, use_layout_motion};
+    pub use crate::motion::AnimatedValue;
+    #[cfg(feature = "dioxus")]
+    pub use crate::motion::in_view::{InViewOptions, InViewRef, use_in_view_motion};
     pub use crate::motion_style;
     #[cfg(feature = "dioxus")]
     pub use crate::presence::{
@@ -149,15 +195,22 @@ pub mod prelude {
     };
     #[cfg(feature = "dioxus")]
     pub use crate::presence_style;
+    #[cfg(feature = "dioxus")]
+    pub use crate::scroll::{ScrollMotion, ScrollSource, use_scroll_motion};
     pub use crate::sequence::AnimationSequence;
+    #[cfg(feature = "dioxus")]
+    pub use crate::timeline::Timeline;
     #[cfg(feature = "transitions")]
     pub use crate::transitions::config::TransitionVariant;
     #[cfg(feature = "transitions")]
-    pub use crate::transitions::page_transitions::TransitionVariantResolver;
-    #[cfg(feature = "transitions")]
     pub use crate::transitions::page_transitions::{AnimatableRoute, AnimatedOutlet};
+    #[cfg(feature = "transitions")]
+    pub use crate::transitions::page_transitions::{
+        TransitionDirection, TransitionProgress, TransitionVariantResolver,
+        transition_resolver_by_depth, use_transition_progress,
+    };
     #[cfg(feature = "dioxus")]
-    pub use crate::{AnimationManager, MotionHandle, use_motion};
+    pub use crate::{AnimationManager, MotionHandle, use_motion, use_path_draw};
     pub use crate::{Duration, Time, TimeProvider};
 }
 
@@ -194,6 +247,18 @@ fn calculate_delay(dt: f32, running_frames: u32) -> Duration {
 /// it updates the state using the calculated time delta and dynamically adjusts the update interval to optimize CPU usage;
 /// when the animation is inactive, it waits longer before polling again.
 ///
+/// The loop starts only after the component first mounts. Calls that start an animation
+/// (such as `animate_to`) made while the component is rendering are queued and applied
+/// right after that render, in call order, instead of writing to the store mid-render.
+///
+/// Teardown is built in, so there is no need to stop motions by hand in `use_drop`.
+/// When the component unmounts, the motion is removed from the [`stop_all`] registry
+/// and any animation in flight is stopped without notifying subscribers, so unmounting
+/// never writes to reactive state; `on_complete` callbacks of the interrupted animation
+/// do not fire and [`MotionHandle::subscribe`] callbacks are released. The frame loop
+/// is a task of the component, so Dioxus cancels it with the scope and no further
+/// ticks run.
+///
 /// # Example
 ///
 /// ```no_run
@@ -222,6 +287,12 @@ fn calculate_delay(dt: f32, running_frames: u32) -> Duration {
 #[cfg(feature = "dioxus")]
 pub fn use_motion<T: Animatable + Send + 'static>(initial: T) -> MotionHandle<T> {
     let mut state = MotionHandle::new_hook(initial);
+    let registration = use_hook(move || state.register());
+
+    use_drop(move || {
+        registration.unregister();
+        state.teardown();
+    });
 
     #[cfg(feature = "web")]
     let idle_poll_rate = Duration::from_millis(100);
@@ -229,50 +300,91 @@ pub fn use_motion<T: Animatable + Send + 'static>(initial: T) -> MotionHandle<T>
     #[cfg(not(feature = "web"))]
     let idle_poll_rate = Duration::from_millis(33);
 
+    // Effects only run once the component has mounted, so suspended or hydrating
+    // components never drive the loop early. The effect reads no signals itself (the
+    // spawned task does), so it never re-runs and only one loop is started.
     use_effect(move || {
-        // This executes after rendering is complete
         spawn(async move {
-            let mut last_frame = Time::now();
+            let mut clock = FrameClock::default();
             let mut running_frames = 0u32;
 
             loop {
-                let now = Time::now();
-                let is_running = state.is_running();
-
-                if is_running && running_frames == 0 {
-                    last_frame = now;
-                    running_frames = 1;
-                    Time::delay(Duration::from_millis(8)).await;
+                clock.set_hidden(document_hidden());
+                if clock.is_hidden() || !state.is_running() {
+                    state.clear_just_completed();
+                    running_frames = 0;
+                    clock.reset();
+                    Time::delay(idle_poll_rate).await;
                     continue;
                 }
 
-                let dt = (now.duration_since(last_frame).as_secs_f32()).min(0.1);
-                last_frame = now;
-
-                // Only check if running first, then write to the signal
-                if is_running {
-                    running_frames += 1;
-                    let prev_value = state.get_value();
-                    let updated = state.update(dt);
-                    let new_value = state.get_value();
-                    let epsilon = state.epsilon();
-                    // Only trigger a re-render if the value changed significantly
-                    if (new_value - prev_value).magnitude() <= epsilon && !updated {
-                        // Skip this frame's update to avoid unnecessary re-render
-                        let delay = calculate_delay(dt, running_frames);
-                        Time::delay(delay).await;
-                        continue;
-                    }
+                // The first frame after starting or resuming only sets the baseline
+                let Some(dt) = clock.tick(Time::now()) else {
+                    Time::delay(Duration::from_millis(8)).await;
+                    continue;
+                };
+                let dt = clock.smooth(dt, resource_pools::dt_smoothing());
 
+                running_frames += 1;
+                let prev_value = state.get_value();
+                let updated = state.update(dt);
+                let new_value = state.get_value();
+                let epsilon = state.epsilon();
+                // Only trigger a re-render if the value changed significantly
+                if (new_value - prev_value).magnitude() <= epsilon && !updated {
+                    // Skip this frame's update to avoid unnecessary re-render
                     let delay = calculate_delay(dt, running_frames);
                     Time::delay(delay).await;
-                } else {
-                    running_frames = 0;
-                    Time::delay(idle_poll_rate).await;
+                    continue;
                 }
+
+                let delay = calculate_delay(dt, running_frames);
+                Time::delay(delay).await;
             }
         });
     });
 
     state
 }
+
+/// Animates an SVG path's `stroke-dashoffset` from `total_length` to `0.0` on mount.
+///
+/// The returned handle holds the current dash offset; pair it with
+/// [`animations::path::PathDraw::style`] for the matching dash array. Use
+/// [`LoopMode::Infinite`](animations::core::LoopMode::Infinite) in `config` to keep re-drawing.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(feature = "dioxus")] {
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let draw = PathDraw::new(240.0);
+///     let offset = use_path_draw(draw.total_length(), AnimationConfig::tween_ms(1200));
+///
+///     rsx! {
+///         svg { view_box: "0 0 100 100",
+///             path {
+///                 d: "M10 80 Q 52.5 10, 95 80",
+///                 fill: "none",
+///                 stroke: "currentColor",
+///                 style: "{draw.style(offset.get_value())}",
+///             }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[cfg(feature = "dioxus")]
+pub fn use_path_draw(total_length: f32, config: prelude::AnimationConfig) -> MotionHandle<f32> {
+    let draw = animations::path::PathDraw::new(total_length);
+    let mut offset = use_motion(draw.offset_at(0.0));
+
+    use_effect(move || {
+        offset.animate_to(draw.offset_at(1.0), config.clone());
+    });
+
+    offset
+}
@@ -1,18 +1,124 @@
 use crate::Duration;
-use crate::animations::core::Animatable;
+use crate::animations::bounds::{BoundaryBehavior, Bounded};
+use crate::animations::core::{Animatable, LoopMode, RoundingMode};
+use crate::animations::css::css_var_declaration;
 use crate::keyframes::KeyframeAnimation;
-use crate::motion::Motion;
-use crate::prelude::AnimationConfig;
+use crate::motion::{AnimatedValue, Motion, SeekError};
+use crate::prelude::{AnimationConfig, Color, Spring, Vec2};
 use crate::sequence::AnimationSequence;
 
+use std::{cell::RefCell, collections::HashMap, future::Future, rc::Rc};
+
 use dioxus::{
-    prelude::{ReadStore, Store, use_store},
+    prelude::{
+        CopyValue, Memo, ReadStore, Store, WritableExt, try_use_context, use_hook, use_memo,
+        use_store,
+    },
     signals::ReadableExt,
 };
+use dioxus_core::{Runtime, queue_effect};
 
 const CURRENT_SCOPE: u16 = 0;
 const RUNNING_SCOPE: u16 = 1;
 
+type RegisteredMotion = Rc<RefCell<dyn AnimatedValue>>;
+type ValueCallback<T> = Rc<dyn Fn(T)>;
+
+#[derive(Default)]
+struct ActiveMotions {
+    next_id: u64,
+    motions: HashMap<u64, RegisteredMotion>,
+}
+
+thread_local! {
+    static ACTIVE_MOTIONS: RefCell<ActiveMotions> = RefCell::new(ActiveMotions::default());
+}
+
+/// Identifies a motion registered with [`stop_all`]; see [`MotionHandle::register`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct MotionRegistration(u64);
+
+impl MotionRegistration {
+    /// Removes the motion from the registry so [`stop_all`] no longer reaches it.
+    pub(crate) fn unregister(self) {
+        ACTIVE_MOTIONS.with(|active| {
+            active.borrow_mut().motions.remove(&self.0);
+        });
+    }
+}
+
+/// Stops every motion created with [`crate::use_motion`] that is still mounted.
+///
+/// Useful for route teardown or a global "stop animations" control. Each running motion
+/// is stopped exactly as if [`AnimationManager::stop`] had been called on its handle,
+/// keeping its current value and running its config's `on_cancel` callback. Motions are unregistered by `use_drop` when their
+/// component unmounts, so handles from unmounted components are never touched.
+/// Detached handles created with [`AnimationManager::new`] are not tracked.
+pub fn stop_all() {
+    let motions: Vec<RegisteredMotion> =
+        ACTIVE_MOTIONS.with(|active| active.borrow().motions.values().cloned().collect());
+
+    for motion in motions {
+        // A motion already being stopped further up the stack is skipped
+        let Ok(mut motion) = motion.try_borrow_mut() else {
+            continue;
+        };
+        if motion.is_running() {
+            motion.stop();
+        }
+    }
+}
+
+/// Starts several motions toward their own targets with one shared config.
+///
+/// Every motion is started in the same call, so they all begin on the same frame with
+/// identical timing instead of drifting apart by the order of separate `animate_to`
+/// calls. Inside a render the whole batch is deferred as one unit and applied together
+/// right after the render.
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+/// use dioxus_motion::animate_batch;
+///
+/// fn app() -> Element {
+///     let x = use_motion(0.0f32);
+///     let y = use_motion(0.0f32);
+///     let scale = use_motion(1.0f32);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 animate_batch(
+///                     [(x, 120.0), (y, 40.0), (scale, 1.5)],
+///                     AnimationConfig::spring(Spring::default()),
+///                 );
+///             },
+///             "Move"
+///         }
+///     }
+/// }
+/// ```
+pub fn animate_batch<T: Animatable + Send + 'static>(
+    targets: impl IntoIterator<Item = (MotionHandle<T>, T)>,
+    config: AnimationConfig,
+) {
+    let targets: Vec<(MotionHandle<T>, T)> = targets.into_iter().collect();
+    let start = move || {
+        for (mut handle, target) in targets {
+            handle.write_motion(|motion| motion.animate_to(target, config.clone()));
+        }
+    };
+
+    if Runtime::try_current().is_some_and(|runtime| runtime.vdom_is_rendering()) {
+        queue_effect(start);
+    } else {
+        start();
+    }
+}
+
 fn current_ref<T: Animatable + Send + 'static>(motion: &Motion<T>) -> &T {
     &motion.current
 }
@@ -29,8 +135,40 @@ fn running_mut<T: Animatable + Send + 'static>(motion: &mut Motion<T>) -> &mut b
     &mut motion.running
 }
 
+/// Callbacks registered with [`MotionHandle::subscribe`].
+struct Subscribers<T> {
+    next_id: u64,
+    callbacks: Vec<(u64, ValueCallback<T>)>,
+}
+
+impl<T> Default for Subscribers<T> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            callbacks: Vec::new(),
+        }
+    }
+}
+
+/// Keeps a [`MotionHandle::subscribe`] callback registered; dropping it unsubscribes.
+#[must_use = "the callback is unsubscribed as soon as the subscription is dropped"]
+pub struct MotionSubscription<T: 'static> {
+    subscribers: CopyValue<Subscribers<T>>,
+    id: u64,
+}
+
+impl<T: 'static> Drop for MotionSubscription<T> {
+    fn drop(&mut self) {
+        // The owning component may already be gone, taking the callbacks with it.
+        if let Ok(mut subscribers) = self.subscribers.try_write() {
+            subscribers.callbacks.retain(|(id, _)| *id != self.id);
+        }
+    }
+}
+
 pub struct MotionHandle<T: Animatable + Send + 'static> {
     state: Store<Motion<T>>,
+    subscribers: CopyValue<Subscribers<T>>,
 }
 
 impl<T: Animatable + Send + 'static> Clone for MotionHandle<T> {
@@ -43,17 +181,35 @@ impl<T: Animatable + Send + 'static> Copy for MotionHandle<T> {}
 
 impl<T: Animatable + Send + 'static> MotionHandle<T> {
     pub(crate) fn new_hook(initial: T) -> Self {
+        let default_config = try_use_context::<AnimationConfig>();
         Self {
-            state: use_store(|| Motion::new(initial)),
+            state: use_store(|| {
+                Motion::new(initial).with_default_config(default_config.unwrap_or_default())
+            }),
+            subscribers: use_hook(|| CopyValue::new(Subscribers::default())),
         }
     }
 
     fn new_detached(initial: T) -> Self {
         Self {
             state: Store::new(Motion::new(initial)),
+            subscribers: CopyValue::new(Subscribers::default()),
         }
     }
 
+    /// Adds this motion to the registry reached by [`stop_all`].
+    pub(crate) fn register(self) -> MotionRegistration {
+        let motion: RegisteredMotion = Rc::new(RefCell::new(Registered(self)));
+
+        ACTIVE_MOTIONS.with(|active| {
+            let mut active = active.borrow_mut();
+            let id = active.next_id;
+            active.next_id += 1;
+            active.motions.insert(id, motion);
+            MotionRegistration(id)
+        })
+    }
+
     pub fn current(self) -> ReadStore<T> {
         let scope =
             self.state
@@ -72,6 +228,337 @@ impl<T: Animatable + Send + 'static> MotionHandle<T> {
         store.into()
     }
 
+    /// The value the current animation is heading toward.
+    ///
+    /// Reads without subscribing, so it suits guards like skipping an `animate_to` whose
+    /// target is already in flight. Alternating loops swap this with [`Self::initial`]
+    /// each time they reverse.
+    pub fn target(self) -> T {
+        self.state.peek().target.clone()
+    }
+
+    /// The value the current animation started from, read without subscribing.
+    pub fn initial(self) -> T {
+        self.state.peek().initial.clone()
+    }
+
+    /// Tag of the sequence step playing now, read without subscribing.
+    ///
+    /// See [`Motion::current_step_tag`]; poll it from a component that re-renders with
+    /// the value, for example to sync captions with a tagged intro.
+    pub fn current_step_tag(self) -> Option<String> {
+        self.state.peek().current_step_tag().map(str::to_owned)
+    }
+
+    /// Index of the sequence step playing now and the number of steps, read without subscribing.
+    pub fn step_progress(self) -> (usize, usize) {
+        self.state.peek().step_progress()
+    }
+
+    /// Whether the last frame finished the animation, read without subscribing.
+    ///
+    /// Edge-triggered: true for the frame the motion completes, see
+    /// [`Motion::just_completed`]. The frame loop clears it on its next idle tick, so read
+    /// it while rendering in response to [`AnimationManager::is_running`], which subscribes
+    /// and re-renders on completion.
+    pub fn just_completed(self) -> bool {
+        self.state.peek().just_completed()
+    }
+
+    /// Clears the completion edge for the frame loop once the motion has gone idle.
+    pub(crate) fn clear_just_completed(&mut self) {
+        if self.state.peek().just_completed() {
+            self.write_motion(Motion::clear_just_completed);
+        }
+    }
+
+    /// Whether the motion is running but still waiting out its delay, read without subscribing.
+    ///
+    /// Together with [`MotionHandle::has_started`] this tells apart the idle, waiting and
+    /// animating phases; see [`Motion::is_delayed`].
+    pub fn is_delayed(self) -> bool {
+        self.state.peek().is_delayed()
+    }
+
+    /// Whether the motion is running and past its delay, read without subscribing.
+    pub fn has_started(self) -> bool {
+        self.state.peek().has_started()
+    }
+
+    /// How long the current animation has been playing, read without subscribing.
+    pub fn elapsed(self) -> Duration {
+        self.state.peek().elapsed()
+    }
+
+    /// Time left in the current tween or keyframe animation; `None` for springs.
+    ///
+    /// Read without subscribing; poll it from a component that re-renders with the value.
+    pub fn remaining(self) -> Option<Duration> {
+        self.state.peek().remaining()
+    }
+
+    /// Predicts the value `elapsed` into the current animation without advancing it.
+    ///
+    /// Read without subscribing, e.g. to render motion blur a frame ahead. Unlike
+    /// [`MotionHandle::seek`] nothing is written; see
+    /// [`Motion::get_value_at`] for how each mode is predicted.
+    pub fn get_value_at(self, elapsed: Duration) -> Result<T, SeekError> {
+        self.state.peek().get_value_at(elapsed)
+    }
+
+    /// Springs toward a target that moves every frame, such as the pointer position.
+    ///
+    /// `target` is read on each frame instead of restarting the animation, so the spring
+    /// carries its velocity as the target moves. See [`Motion::follow`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "dioxus")] {
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut pointer = use_signal_sync(|| Vec2::ZERO);
+    ///     let mut dot = use_motion(Vec2::ZERO);
+    ///     use_hook(move || dot.follow(move || pointer(), Spring::default()));
+    ///     let position = dot.get_value();
+    ///
+    ///     rsx! {
+    ///         div {
+    ///             style: "position: fixed; inset: 0;",
+    ///             onpointermove: move |event| {
+    ///                 let point = event.client_coordinates();
+    ///                 pointer.set(Vec2::new(point.x as f32, point.y as f32));
+    ///             },
+    ///             div { style: "transform: translate({position.x}px, {position.y}px);" }
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn follow(&mut self, target: impl Fn() -> T + Send + Sync + 'static, spring: Spring) {
+        self.start_motion(move |motion| motion.follow(target, spring));
+    }
+
+    /// Calls `callback` with the new value every time the animated value changes.
+    ///
+    /// Meant for imperative consumers outside the VDOM, such as a canvas or WebGL
+    /// renderer, that want each frame's value without subscribing a component. The
+    /// callback runs after the change is applied, so it may read or drive this handle.
+    /// It stays registered until the returned [`MotionSubscription`] is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "dioxus")] {
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut angle = use_motion(0.0f32);
+    ///     let _subscription = use_hook(move || {
+    ///         std::rc::Rc::new(angle.subscribe(|angle| {
+    ///             // Draw the frame on a canvas with `angle`.
+    ///             let _ = angle;
+    ///         }))
+    ///     });
+    ///
+    ///     rsx! {
+    ///         canvas {
+    ///             onclick: move |_| angle.animate_to(360.0, AnimationConfig::tween_ms(800)),
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn subscribe(&self, callback: impl Fn(T) + 'static) -> MotionSubscription<T> {
+        let mut subscribers = self.subscribers;
+        let id = {
+            let mut registry = subscribers.write();
+            let id = registry.next_id;
+            registry.next_id += 1;
+            registry.callbacks.push((id, Rc::new(callback)));
+            id
+        };
+
+        MotionSubscription { subscribers, id }
+    }
+
+    fn notify(&self, value: &T) {
+        let callbacks: Vec<ValueCallback<T>> = match self.subscribers.try_peek() {
+            Ok(registry) if !registry.callbacks.is_empty() => registry
+                .callbacks
+                .iter()
+                .map(|(_, callback)| callback.clone())
+                .collect(),
+            _ => return,
+        };
+
+        for callback in callbacks {
+            callback(value.clone());
+        }
+    }
+
+    /// Keeps the last `len` values this motion reached, for drawing motion trails.
+    ///
+    /// The history is a ring buffer allocated once here, so recording adds no per-frame
+    /// allocation. Calling it again resizes and clears the trail; `0` turns it off.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "dioxus")] {
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut x = use_motion(0.0f32);
+    ///     use_hook(move || x.enable_trail(8));
+    ///     let ghosts = x.trail();
+    ///     let count = ghosts.len();
+    ///
+    ///     rsx! {
+    ///         for (i, ghost) in ghosts.into_iter().enumerate() {
+    ///             div {
+    ///                 key: "{i}",
+    ///                 style: "position: absolute; transform: translateX({ghost}px); \
+    ///                         opacity: {(i + 1) as f32 / (count + 1) as f32 * 0.5};",
+    ///             }
+    ///         }
+    ///         div {
+    ///             style: "position: absolute; transform: translateX({x.get_value()}px);",
+    ///             onclick: move |_| x.animate_to(300.0, AnimationConfig::tween_ms(600)),
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn enable_trail(&mut self, len: usize) {
+        self.start_motion(move |motion| motion.enable_trail(len));
+    }
+
+    /// Advances up to `n` frames of `dt` seconds at once and returns the value reached.
+    ///
+    /// For tests and pre-baking; see [`Motion::step_n`]. Subscribers see only the
+    /// final value, not each intermediate frame.
+    pub fn step_n(&mut self, dt: f32, n: usize) -> T {
+        self.write_motion(|motion| motion.step_n(dt, n))
+    }
+
+    /// Recorded trail values, oldest first; empty until [`Self::enable_trail`] is called.
+    ///
+    /// Read without subscribing; the trail moves with the value, so read it from a
+    /// component that already re-renders on [`Self::get_value`].
+    pub fn trail(self) -> Vec<T> {
+        self.state.peek().trail()
+    }
+
+    /// Derives a read-only value from the animated one, recomputed as the motion moves.
+    ///
+    /// This is a hook built on [`use_memo`]: call it unconditionally in the component body,
+    /// in the same place every render. The memo only reads the motion, so it never writes
+    /// during render, and readers re-render only when the mapped value actually changes.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut position = use_motion(0.0f32);
+    ///     let opacity = position.map(|x| (x / 100.0).clamp(0.0, 1.0));
+    ///
+    ///     rsx! {
+    ///         div {
+    ///             style: "opacity: {opacity}; transform: translateX({position.get_value()}px);",
+    ///             onclick: move |_| position.animate_to(100.0, AnimationConfig::tween_ms(400)),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn map<U: PartialEq + 'static>(self, mut f: impl FnMut(T) -> U + 'static) -> Memo<U> {
+        use_memo(move || f(self.get_value()))
+    }
+
+    /// Formats the animated value as text, recomputed in lockstep with the motion.
+    ///
+    /// Like [`MotionHandle::map`], this is a hook: call it unconditionally in the
+    /// component body. Readers re-render only when the formatted text changes, so an
+    /// animated counter rounded to whole numbers skips frames where the label is unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut progress = use_motion(0.0f32);
+    ///     let label = progress.formatted(|v| format!("{v:.0}%"));
+    ///
+    ///     rsx! {
+    ///         button {
+    ///             onclick: move |_| progress.animate_to(100.0, AnimationConfig::tween_ms(1200)),
+    ///             "{label}"
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn formatted(self, f: impl Fn(T) -> String + 'static) -> Memo<String> {
+        self.map(f)
+    }
+
+    /// Starts a sequence and returns a future that resolves once every step has completed.
+    ///
+    /// The sequence's own `on_complete` callback still runs before the future resolves.
+    /// If the sequence is interrupted by `animate_to`, another sequence, or `stop`, the
+    /// future resolves immediately instead of waiting forever, so check
+    /// [`AnimationManager::get_value`] if you need to know where the motion ended up.
+    pub fn animate_sequence_future(
+        mut self,
+        sequence: AnimationSequence<T>,
+    ) -> impl Future<Output = ()> {
+        let (sequence, completion) = sequence.with_completion_future();
+        self.animate_sequence(sequence);
+        completion
+    }
+
+    /// Formats the current value as a CSS custom property declaration for a `style` attribute.
+    ///
+    /// Reading the value subscribes the calling component, so the declaration updates
+    /// every frame and plain CSS can consume the variable.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let progress = use_motion(0.0f32);
+    ///
+    ///     rsx! {
+    ///         div {
+    ///             style: progress.bind_css_var("progress", |value| value.to_string()),
+    ///             div { style: "width: calc(var(--progress) * 100%);" }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn bind_css_var(self, name: &str, format: impl FnOnce(T) -> String) -> String {
+        css_var_declaration(name, &format(self.get_value()))
+    }
+
+    /// Sets the epsilon used by every later animation whose config doesn't set one.
+    ///
+    /// [`AnimationConfig::with_epsilon`] still overrides it for a single animation, and
+    /// without either the type's own [`Animatable::epsilon`] applies.
+    pub fn set_default_epsilon(&mut self, epsilon: f32) {
+        self.write_motion(|motion| motion.set_default_epsilon(epsilon));
+    }
+
     pub(crate) fn epsilon(&self) -> f32 {
         self.state.peek().get_epsilon()
     }
@@ -82,7 +569,103 @@ impl<T: Animatable + Send + 'static> MotionHandle<T> {
         });
     }
 
-    fn write_motion<R>(&mut self, f: impl FnOnce(&mut Motion<T>) -> R) -> R {
+    /// Starts an animation that begins with `velocity` instead of at rest, as after a fling.
+    pub(crate) fn animate_with_velocity(
+        &mut self,
+        target: T,
+        config: AnimationConfig,
+        velocity: T,
+    ) {
+        self.start_motion(move |motion| {
+            motion.animate_to(target, config);
+            motion.velocity = velocity;
+        });
+    }
+
+    /// Stops the motion for good as its component unmounts.
+    ///
+    /// Writes without marking subscribers dirty, since the components reading this
+    /// motion are being torn down, and skips stores Dioxus has already dropped.
+    pub(crate) fn teardown(self) {
+        if self.state.try_peek().is_err() {
+            return;
+        }
+        self.state.into_selector().write_untracked().stop();
+
+        let mut subscribers = self.subscribers;
+        if let Ok(mut subscribers) = subscribers.try_write() {
+            subscribers.callbacks.clear();
+        }
+    }
+
+    /// Jumps a tween or keyframe animation to a normalized `progress` (0.0 to 1.0).
+    ///
+    /// Springs cannot be seeked; see [`Motion::seek`].
+    pub fn seek(&mut self, progress: f32) -> Result<(), SeekError> {
+        self.write_motion(|motion| motion.seek(progress))
+    }
+
+    /// Re-runs the last `animate_to` from its original starting value; does nothing before the first one.
+    pub fn replay(&mut self) {
+        self.write_motion(Motion::replay);
+    }
+
+    /// Changes the target mid-animation while keeping the elapsed progress; see [`Motion::retarget`].
+    pub fn retarget(&mut self, target: T) {
+        self.start_motion(move |motion| motion.retarget(target));
+    }
+
+    /// Animates with the [`AnimationConfig`] provided as context, or the default config.
+    pub fn animate_to_default(&mut self, target: T) {
+        self.start_motion(move |motion| motion.animate_to_default(target));
+    }
+
+    /// Animates by `delta` relative to the in-flight target, or the current value when idle.
+    pub fn animate_by(&mut self, delta: T, config: AnimationConfig) {
+        self.start_motion(move |motion| motion.animate_by(delta, config));
+    }
+
+    /// Aborts an animation still in its delay window without starting anything.
+    ///
+    /// Returns `true` if a pending animation was cancelled; see [`Motion::cancel_delay`].
+    pub fn cancel_delay(&mut self) -> bool {
+        self.write_motion(Motion::cancel_delay)
+    }
+
+    /// Freezes the animation, keeping velocity, progress and sequence position.
+    pub fn pause(&mut self) {
+        self.write_motion(Motion::pause);
+    }
+
+    /// Continues an animation frozen by `pause` or `halt`; does nothing otherwise.
+    pub fn resume(&mut self) {
+        self.write_motion(Motion::resume);
+    }
+
+    /// Stops the motion dead, keeping progress and sequence position but not velocity.
+    pub fn halt(&mut self) {
+        self.write_motion(Motion::halt);
+    }
+
+    /// Applies a change that starts an animation, deferring it while a component renders.
+    ///
+    /// Writing the store during a render pass marks subscribers dirty mid-render, which
+    /// Dioxus warns about. Calls made while rendering (for example directly in a component
+    /// body) are queued as an effect instead and run in call order once the render has
+    /// finished, so the last call made during the render wins.
+    fn start_motion(&mut self, f: impl FnOnce(&mut Motion<T>) + 'static) {
+        if Runtime::try_current().is_some_and(|runtime| runtime.vdom_is_rendering()) {
+            let mut handle = *self;
+            queue_effect(move || {
+                handle.write_motion(f);
+            });
+            return;
+        }
+
+        self.write_motion(f);
+    }
+
+    pub(crate) fn write_motion<R>(&mut self, f: impl FnOnce(&mut Motion<T>) -> R) -> R {
         let selector = self.state.into_selector();
         let mut motion = selector.write_untracked();
         let previous_current = motion.current.clone();
@@ -94,8 +677,9 @@ impl<T: Animatable + Send + 'static> MotionHandle<T> {
         drop(motion);
         let epsilon = self.epsilon();
 
-        if (next_current - previous_current).magnitude() > epsilon {
+        if (next_current.clone() - previous_current).magnitude() > epsilon {
             selector.child_unmapped(CURRENT_SCOPE).mark_dirty();
+            self.notify(&next_current);
         }
 
         if next_running != previous_running {
@@ -106,6 +690,180 @@ impl<T: Animatable + Send + 'static> MotionHandle<T> {
     }
 }
 
+impl<T: Animatable + Send + PartialEq + 'static> MotionHandle<T> {
+    /// Like [`AnimationManager::animate_to`], but does nothing when already heading to `target`.
+    ///
+    /// Safe to call on every render or effect run: a running animation toward the same
+    /// target keeps its progress, and a motion already resting at `target` stays idle.
+    pub fn animate_to_if_changed(&mut self, target: T, config: AnimationConfig) {
+        self.start_motion(move |motion| {
+            let settled = !motion.running && motion.current == target;
+            if motion.target != target || !(motion.running || settled) {
+                motion.animate_to(target, config);
+            }
+        });
+    }
+}
+
+impl MotionHandle<f32> {
+    /// The current value rounded to a whole number, for counters and other integer displays.
+    ///
+    /// The motion itself stays fractional, so it moves smoothly and still lands exactly
+    /// on an integer target. Like [`AnimationManager::get_value`], this subscribes the
+    /// calling component. A spring that overshoots shows the overshoot too; use a tween
+    /// when the displayed number must never pass the target.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut count = use_motion(0.0f32);
+    ///
+    ///     rsx! {
+    ///         button {
+    ///             onclick: move |_| count.animate_to(1000.0, AnimationConfig::tween_ms(2000)),
+    ///             "Count"
+    ///         }
+    ///         span { "{count.get_value_rounded(RoundingMode::Floor)}" }
+    ///     }
+    /// }
+    /// ```
+    pub fn get_value_rounded(&self, mode: RoundingMode) -> i64 {
+        mode.apply(self.get_value())
+    }
+}
+
+impl<T: Bounded + Send + Sync + 'static> MotionHandle<T> {
+    /// Keeps the value within `min..=max`; see [`Motion::set_bounds`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut ball = use_motion(Vec2::new(20.0, 20.0));
+    ///     use_hook(move || {
+    ///         ball.with_bounds(Vec2::ZERO, Vec2::new(280.0, 180.0), BoundaryBehavior::Reflect)
+    ///     });
+    ///     let position = ball.get_value();
+    ///
+    ///     rsx! {
+    ///         div {
+    ///             class: "box",
+    ///             onclick: move |_| {
+    ///                 ball.animate_to(
+    ///                     Vec2::new(270.0, 170.0),
+    ///                     AnimationConfig::spring(Spring::from_response_bounce(0.6, 0.7)),
+    ///                 );
+    ///             },
+    ///             div { class: "ball", style: "transform: translate({position.x}px, {position.y}px);" }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn with_bounds(mut self, min: T, max: T, behavior: BoundaryBehavior) -> Self {
+        self.write_motion(|motion| motion.set_bounds(min, max, behavior));
+        self
+    }
+
+    /// Lets the value move freely again; see [`Motion::clear_bounds`].
+    pub fn clear_bounds(&mut self) {
+        self.write_motion(Motion::clear_bounds);
+    }
+}
+
+impl MotionHandle<Color> {
+    /// Animates only the alpha channel towards `alpha`, keeping the current RGB.
+    ///
+    /// The current color is read without subscribing, so calling this from an event
+    /// handler or effect doesn't re-run the caller on every frame.
+    pub fn fade_to(&mut self, alpha: f32, config: AnimationConfig) {
+        let target = self.state.peek().current.with_alpha(alpha);
+        self.animate_to(target, config);
+    }
+
+    /// Cycles through the full hue wheel; see [`Motion::animate_hue_cycle`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut border = use_motion(Color::RED);
+    ///
+    ///     use_effect(move || {
+    ///         border.animate_hue_cycle(0.9, 0.6, Duration::from_secs(4), LoopMode::Infinite);
+    ///     });
+    ///
+    ///     let (r, g, b, _) = border.get_value().to_rgba();
+    ///     rsx! {
+    ///         div {
+    ///             style: "border: 4px solid rgb({r}, {g}, {b}); border-radius: 12px;",
+    ///             "Rainbow"
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn animate_hue_cycle(
+        &mut self,
+        saturation: f32,
+        lightness: f32,
+        duration: Duration,
+        loop_mode: LoopMode,
+    ) {
+        self.start_motion(move |motion| {
+            motion.animate_hue_cycle(saturation, lightness, duration, loop_mode);
+        });
+    }
+}
+
+impl MotionHandle<Vec2> {
+    /// Moves through `points` in order along a smooth path; see [`Motion::animate_along_path`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dioxus::prelude::*;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut dot = use_motion(Vec2::new(60.0, 0.0));
+    ///
+    ///     use_effect(move || {
+    ///         let orbit: Vec<Vec2> = (0..=12)
+    ///             .map(|step| {
+    ///                 let angle = step as f32 * std::f32::consts::TAU / 12.0;
+    ///                 Vec2::new(60.0 * angle.cos(), 60.0 * angle.sin())
+    ///             })
+    ///             .collect();
+    ///         dot.animate_along_path(
+    ///             &orbit,
+    ///             AnimationConfig::tween_ms(3000).with_loop(LoopMode::Infinite),
+    ///         );
+    ///     });
+    ///
+    ///     let position = dot.get_value();
+    ///     rsx! {
+    ///         div {
+    ///             style: "transform: translate({position.x}px, {position.y}px);",
+    ///             class: "dot",
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn animate_along_path(&mut self, points: &[Vec2], config: AnimationConfig) {
+        let points = points.to_vec();
+        self.start_motion(move |motion| motion.animate_along_path(&points, config));
+    }
+}
+
 pub trait AnimationManager<T: Animatable + Send + 'static>: Clone + Copy {
     fn new(initial: T) -> Self;
     fn animate_to(&mut self, target: T, config: AnimationConfig);
@@ -125,15 +883,15 @@ impl<T: Animatable + Send + 'static> AnimationManager<T> for MotionHandle<T> {
     }
 
     fn animate_to(&mut self, target: T, config: AnimationConfig) {
-        self.write_motion(|motion| motion.animate_to(target, config));
+        self.start_motion(move |motion| motion.animate_to(target, config));
     }
 
     fn animate_sequence(&mut self, sequence: AnimationSequence<T>) {
-        self.write_motion(|motion| motion.animate_sequence(sequence));
+        self.start_motion(move |motion| motion.animate_sequence(sequence));
     }
 
     fn animate_keyframes(&mut self, animation: KeyframeAnimation<T>) {
-        self.write_motion(|motion| motion.animate_keyframes(animation));
+        self.start_motion(move |motion| motion.animate_keyframes(animation));
     }
 
     fn update(&mut self, dt: f32) -> bool {
@@ -161,3 +919,664 @@ impl<T: Animatable + Send + 'static> AnimationManager<T> for MotionHandle<T> {
         self.write_motion(|motion| motion.delay(duration));
     }
 }
+
+/// A registered handle, kept apart from [`MotionHandle`] so its own methods never
+/// clash with [`AnimationManager`]'s in user code.
+struct Registered<T: Animatable + Send + 'static>(MotionHandle<T>);
+
+/// Lets [`stop_all`]'s registry hold handles of every value type side by side.
+///
+/// `is_running` reads without subscribing, so the registry can check motions from
+/// outside any component.
+impl<T: Animatable + Send + 'static> AnimatedValue for Registered<T> {
+    fn update(&mut self, dt: f32) -> bool {
+        self.0.update(dt)
+    }
+
+    fn is_running(&self) -> bool {
+        self.0.state.peek().running
+    }
+
+    fn stop(&mut self) {
+        self.0.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    use dioxus::prelude::{Element, Memo, ScopeId, VNode, VirtualDom, use_context_provider};
+    use dioxus::signals::ReadableExt;
+    use dioxus_core::{NoOpMutations, current_scope_id};
+
+    use super::{ACTIVE_MOTIONS, AnimationManager, MotionHandle, animate_batch, stop_all};
+    use crate::{
+        Duration,
+        prelude::{AnimationConfig, AnimationMode, Color, LoopMode, RoundingMode, Spring},
+        use_motion,
+    };
+
+    #[derive(Clone)]
+    struct MotionsProps {
+        handles: Rc<RefCell<Vec<MotionHandle<f32>>>>,
+    }
+
+    #[allow(non_snake_case)]
+    fn MotionsHost(props: MotionsProps) -> Element {
+        let handles = [use_motion(0.0f32), use_motion(10.0f32), use_motion(20.0f32)];
+        *props.handles.borrow_mut() = handles.to_vec();
+
+        VNode::empty()
+    }
+
+    type MixedHandles = Rc<RefCell<Option<(MotionHandle<f32>, MotionHandle<Color>)>>>;
+
+    #[allow(non_snake_case)]
+    fn MixedHost(handles: MixedHandles) -> Element {
+        let opacity = use_motion(0.0f32);
+        let color = use_motion(Color::new(0.0, 0.0, 0.0, 1.0));
+        *handles.borrow_mut() = Some((opacity, color));
+
+        VNode::empty()
+    }
+
+    #[allow(non_snake_case)]
+    fn DefaultConfigHost(props: MotionsProps) -> Element {
+        use_context_provider(|| AnimationConfig::tween_ms(450));
+        *props.handles.borrow_mut() = vec![use_motion(0.0f32)];
+
+        VNode::empty()
+    }
+
+    #[test]
+    fn animate_to_default_uses_context_config() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            DefaultConfigHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut handle = handles.borrow()[0];
+            handle.animate_to_default(100.0);
+
+            let duration = handle.state.peek().config().get_duration();
+            assert_eq!(duration, Duration::from_millis(450));
+        });
+    }
+
+    #[test]
+    fn animate_by_twice_accumulates() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut handle = handles.borrow()[1];
+            handle.animate_by(10.0, AnimationConfig::tween_ms(300));
+            handle.animate_by(10.0, AnimationConfig::tween_ms(300));
+
+            assert_eq!(handle.get_value(), 10.0);
+            assert_eq!(handle.state.peek().target, 30.0);
+        });
+    }
+
+    #[test]
+    fn target_and_initial_follow_animate_to_and_alternate_loops() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut handle = handles.borrow()[1];
+            assert_eq!((handle.initial(), handle.target()), (10.0, 10.0));
+
+            handle.animate_to(
+                50.0,
+                AnimationConfig::tween_ms(100).with_loop(LoopMode::Alternate),
+            );
+            assert_eq!((handle.initial(), handle.target()), (10.0, 50.0));
+
+            let mut frames = 0;
+            while handle.target() == 50.0 {
+                handle.update(1.0 / 60.0);
+                frames += 1;
+                assert!(frames < 100, "alternate loop never reversed");
+            }
+            assert_eq!((handle.initial(), handle.target()), (50.0, 10.0));
+        });
+    }
+
+    #[test]
+    fn animate_to_if_changed_lets_spring_settle() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut handle = handles.borrow()[0];
+            let config = || AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+
+            handle.animate_to_if_changed(100.0, config());
+            let mut frames = 0;
+            while handle.is_running() {
+                handle.animate_to_if_changed(100.0, config());
+                handle.update(1.0 / 60.0);
+                frames += 1;
+                assert!(frames < 600, "spring kept restarting");
+            }
+
+            handle.animate_to_if_changed(100.0, config());
+            assert!(!handle.is_running());
+            assert_eq!(handle.get_value(), 100.0);
+
+            handle.animate_to_if_changed(50.0, config());
+            assert!(handle.is_running());
+        });
+    }
+
+    #[test]
+    fn cancel_delay_keeps_value_and_stops() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut handle = handles.borrow()[2];
+            handle.animate_to(
+                80.0,
+                AnimationConfig::tween_ms(200).with_delay(Duration::from_secs(1)),
+            );
+            handle.update(1.0 / 60.0);
+            assert!(handle.is_running());
+
+            assert!(handle.cancel_delay());
+            assert!(!handle.is_running());
+
+            for _ in 0..120 {
+                handle.update(1.0 / 60.0);
+            }
+            assert_eq!(handle.get_value(), 20.0);
+        });
+    }
+
+    #[test]
+    fn subscribe_receives_each_frame_until_dropped() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut handle = handles.borrow()[0];
+            let received = Rc::new(RefCell::new(Vec::new()));
+            let subscription = handle.subscribe({
+                let received = Rc::clone(&received);
+                move |value| received.borrow_mut().push(value)
+            });
+
+            handle.animate_to(100.0, AnimationConfig::spring(Spring::default()));
+            let mut expected = Vec::new();
+            for _ in 0..5 {
+                handle.update(1.0 / 60.0);
+                expected.push(handle.get_value());
+            }
+            assert_eq!(*received.borrow(), expected);
+            assert!(expected.windows(2).all(|pair| pair[0] < pair[1]));
+
+            drop(subscription);
+            handle.update(1.0 / 60.0);
+            assert_eq!(received.borrow().len(), 5);
+        });
+    }
+
+    #[test]
+    fn elapsed_and_remaining_track_tween_duration() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut handle = handles.borrow()[0];
+            handle.animate_to(100.0, AnimationConfig::tween_ms(1000));
+            assert_eq!(handle.elapsed(), Duration::ZERO);
+            assert_eq!(handle.remaining(), Some(Duration::from_secs(1)));
+
+            for _ in 0..3 {
+                handle.update(0.25);
+                let remaining = handle.remaining().expect("tweens have a fixed duration");
+                assert!(handle.elapsed() > Duration::ZERO);
+                assert_eq!(handle.elapsed() + remaining, Duration::from_secs(1));
+            }
+
+            while handle.update(0.25) {}
+            assert_eq!(handle.remaining(), Some(Duration::ZERO));
+
+            handle.animate_to(0.0, AnimationConfig::spring(Spring::default()));
+            handle.update(1.0 / 60.0);
+            assert_eq!(handle.remaining(), None);
+        });
+    }
+
+    #[test]
+    fn rounded_counter_rises_monotonically_to_exact_target() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut count = handles.borrow()[0];
+            count.animate_to(1000.0, AnimationConfig::tween_ms(2000));
+
+            let mut previous = count.get_value_rounded(RoundingMode::Floor);
+            assert_eq!(previous, 0);
+            while count.update(1.0 / 60.0) {
+                let rounded = count.get_value_rounded(RoundingMode::Floor);
+                assert!(rounded >= previous);
+                previous = rounded;
+            }
+
+            for mode in [RoundingMode::Floor, RoundingMode::Round, RoundingMode::Ceil] {
+                assert_eq!(count.get_value_rounded(mode), 1000);
+            }
+        });
+    }
+
+    #[test]
+    fn animate_batch_starts_every_handle_in_sync() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let batch = handles.borrow().clone();
+            animate_batch(
+                batch.iter().copied().zip([100.0, 200.0, 300.0]),
+                AnimationConfig::tween_ms(500),
+            );
+
+            for mut handle in batch.iter().copied() {
+                assert!(handle.is_running());
+                handle.update(0.1);
+            }
+
+            let elapsed: Vec<Duration> = batch.iter().map(|handle| handle.elapsed()).collect();
+            assert!(elapsed[0] > Duration::ZERO);
+            assert!(elapsed.iter().all(|&value| value == elapsed[0]));
+            assert_eq!(
+                batch
+                    .iter()
+                    .map(|handle| handle.target())
+                    .collect::<Vec<_>>(),
+                vec![100.0, 200.0, 300.0]
+            );
+        });
+    }
+
+    type MappedMotion = Rc<RefCell<Option<(MotionHandle<f32>, Memo<f32>)>>>;
+
+    #[allow(non_snake_case)]
+    fn MappedHost(mapped: MappedMotion) -> Element {
+        let position = use_motion(0.0f32);
+        let opacity = position.map(|x| x / 100.0);
+        *mapped.borrow_mut() = Some((position, opacity));
+
+        VNode::empty()
+    }
+
+    #[test]
+    fn map_tracks_the_animated_value() {
+        let mapped = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(MappedHost, Rc::clone(&mapped));
+        dom.rebuild_in_place();
+        let (mut position, opacity) = mapped.borrow().expect("host should render");
+
+        dom.in_scope(ScopeId::ROOT, || {
+            assert_eq!(opacity.cloned(), 0.0);
+
+            position.animate_to(100.0, AnimationConfig::tween_ms(200));
+            position.update(1.0 / 10.0);
+            let halfway = opacity.cloned();
+            assert!((halfway - position.get_value() / 100.0).abs() < 1e-6);
+            assert!(halfway > 0.0 && halfway < 1.0);
+
+            while position.update(1.0 / 60.0) {}
+            assert_eq!(opacity.cloned(), 1.0);
+        });
+    }
+
+    type FormattedMotion = Rc<RefCell<Option<(MotionHandle<f32>, Memo<String>)>>>;
+
+    #[allow(non_snake_case)]
+    fn FormattedHost(formatted: FormattedMotion) -> Element {
+        let progress = use_motion(0.0f32);
+        let label = progress.formatted(|v| format!("{v:.0}%"));
+        *formatted.borrow_mut() = Some((progress, label));
+
+        VNode::empty()
+    }
+
+    #[test]
+    fn formatted_label_tracks_the_animated_value() {
+        let formatted = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(FormattedHost, Rc::clone(&formatted));
+        dom.rebuild_in_place();
+        let (mut progress, label) = formatted.borrow().expect("host should render");
+
+        dom.in_scope(ScopeId::ROOT, || {
+            assert_eq!(label.cloned(), "0%");
+
+            progress.animate_to(100.0, AnimationConfig::tween_ms(200));
+            while progress.update(1.0 / 60.0) {
+                assert_eq!(label.cloned(), format!("{:.0}%", progress.get_value()));
+            }
+            assert_eq!(label.cloned(), "100%");
+        });
+    }
+
+    #[test]
+    fn pause_and_resume_toggle_running() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut handle = handles.borrow()[0];
+            handle.animate_to(100.0, AnimationConfig::spring(Spring::default()));
+            handle.update(1.0 / 60.0);
+
+            handle.pause();
+            let value = handle.get_value();
+            assert!(!handle.is_running());
+            assert!(!handle.update(1.0 / 60.0));
+            assert_eq!(handle.get_value(), value);
+
+            handle.resume();
+            assert!(handle.is_running());
+            handle.update(1.0 / 60.0);
+            assert!(handle.get_value() > value);
+        });
+    }
+
+    type RenderedHandle = Rc<RefCell<Option<(MotionHandle<f32>, ScopeId)>>>;
+
+    #[derive(Clone)]
+    struct RenderAnimateProps {
+        handle: RenderedHandle,
+        animate: Rc<Cell<bool>>,
+    }
+
+    #[allow(non_snake_case)]
+    fn RenderAnimateHost(props: RenderAnimateProps) -> Element {
+        let mut handle = MotionHandle::new_hook(0.0f32);
+        if props.animate.get() {
+            handle.animate_to(100.0, AnimationConfig::tween_ms(300));
+        }
+        *props.handle.borrow_mut() = Some((handle, current_scope_id()));
+
+        VNode::empty()
+    }
+
+    #[tokio::test]
+    async fn animate_to_during_render_is_applied_after_render() {
+        let handle = Rc::new(RefCell::new(None));
+        let animate = Rc::new(Cell::new(false));
+        let mut dom = VirtualDom::new_with_props(
+            RenderAnimateHost,
+            RenderAnimateProps {
+                handle: Rc::clone(&handle),
+                animate: Rc::clone(&animate),
+            },
+        );
+        dom.rebuild_in_place();
+        let (motion, scope) = handle.borrow().expect("host should render");
+
+        animate.set(true);
+        dom.mark_dirty(scope);
+        dom.render_immediate(&mut NoOpMutations);
+
+        dom.in_scope(scope, || {
+            assert!(!motion.is_running());
+        });
+
+        let _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+
+        dom.in_scope(scope, || {
+            assert!(motion.is_running());
+            assert_eq!(motion.state.peek().target, 100.0);
+        });
+    }
+
+    #[test]
+    fn teardown_stops_without_notifying() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        };
+
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut handle = handles.borrow()[0];
+            let completed = Arc::new(AtomicBool::new(false));
+            let notified = Rc::new(Cell::new(0));
+            let _subscription = handle.subscribe({
+                let notified = Rc::clone(&notified);
+                move |_| notified.set(notified.get() + 1)
+            });
+            handle.animate_to(
+                100.0,
+                AnimationConfig::tween_ms(300).with_on_complete({
+                    let completed = Arc::clone(&completed);
+                    move || completed.store(true, Ordering::Relaxed)
+                }),
+            );
+            handle.update(1.0 / 60.0);
+            assert_eq!(notified.get(), 1);
+
+            handle.teardown();
+
+            assert!(!handle.state.peek().running);
+            assert!(!handle.update(1.0 / 60.0), "no ticks after teardown");
+            assert_eq!(notified.get(), 1);
+            assert!(!completed.load(Ordering::Relaxed));
+        });
+    }
+
+    #[derive(Clone)]
+    struct ToggleProps {
+        shown: Rc<Cell<bool>>,
+    }
+
+    #[allow(non_snake_case)]
+    fn ToggleHost(props: ToggleProps) -> Element {
+        use dioxus::prelude::*;
+
+        if !props.shown.get() {
+            return VNode::empty();
+        }
+        rsx! { Animated {} }
+    }
+
+    #[allow(non_snake_case)]
+    fn Animated() -> Element {
+        use dioxus::prelude::use_hook;
+
+        let mut motion = use_motion(0.0f32);
+        use_hook(move || motion.animate_to(100.0, AnimationConfig::tween_ms(300)));
+        VNode::empty()
+    }
+
+    #[test]
+    fn unmount_unregisters_the_motion() {
+        let registered = || ACTIVE_MOTIONS.with(|active| active.borrow().motions.len());
+        let shown = Rc::new(Cell::new(true));
+        let mut dom = VirtualDom::new_with_props(
+            ToggleHost,
+            ToggleProps {
+                shown: Rc::clone(&shown),
+            },
+        );
+        dom.rebuild_in_place();
+        assert_eq!(registered(), 1);
+
+        shown.set(false);
+        dom.mark_dirty(ScopeId::APP);
+        dom.render_immediate(&mut NoOpMutations);
+
+        assert_eq!(registered(), 0);
+    }
+
+    // The frame loop reads the browser clock on web, so this runs on native targets only
+    #[cfg(not(feature = "web"))]
+    #[tokio::test]
+    async fn just_completed_is_cleared_once_the_frame_loop_goes_idle() {
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+        let mut handle = handles.borrow()[0];
+        dom.in_scope(ScopeId::ROOT, || {
+            handle.animate_to(100.0, AnimationConfig::tween_ms(50));
+        });
+
+        let mut completed = false;
+        for _ in 0..400 {
+            let _ = tokio::time::timeout(Duration::from_millis(5), dom.wait_for_work()).await;
+            dom.render_immediate(&mut NoOpMutations);
+
+            let (running, just_completed) = dom.in_scope(ScopeId::ROOT, || {
+                (handle.is_running(), handle.just_completed())
+            });
+            if just_completed {
+                assert!(!running);
+                completed = true;
+            } else if completed {
+                assert_eq!(handle.state.peek().current, 100.0);
+                return;
+            }
+        }
+        panic!("the completion edge should be seen and then cleared");
+    }
+
+    #[test]
+    fn stop_all_stops_every_registered_motion() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        let cancelled = Arc::new(AtomicUsize::new(0));
+        let handles = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(
+            MotionsHost,
+            MotionsProps {
+                handles: Rc::clone(&handles),
+            },
+        );
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            for mut handle in handles.borrow().iter().copied() {
+                let cancelled = Arc::clone(&cancelled);
+                handle.animate_to(
+                    100.0,
+                    AnimationConfig::tween_ms(300).with_on_cancel(move || {
+                        cancelled.fetch_add(1, Ordering::SeqCst);
+                    }),
+                );
+                assert!(handle.is_running());
+            }
+
+            stop_all();
+
+            for handle in handles.borrow().iter() {
+                assert!(!handle.is_running());
+            }
+            assert_eq!(cancelled.load(Ordering::SeqCst), handles.borrow().len());
+        });
+    }
+
+    #[test]
+    fn stop_all_reaches_motions_of_every_value_type() {
+        let handles: MixedHandles = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(MixedHost, Rc::clone(&handles));
+        dom.rebuild_in_place();
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let (mut opacity, mut color) = handles.borrow().expect("host should render");
+            opacity.animate_to(1.0, AnimationConfig::tween_ms(300));
+            color.animate_to(
+                Color::new(1.0, 1.0, 1.0, 1.0),
+                AnimationConfig::tween_ms(300),
+            );
+
+            stop_all();
+
+            assert!(!opacity.is_running());
+            assert!(!color.is_running());
+        });
+    }
+}
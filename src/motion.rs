@@ -1,13 +1,36 @@
 use crate::Duration;
+use crate::animations::bounds::{BoundaryBehavior, Bounded};
+use crate::animations::colors::Color;
 use crate::animations::core::{Animatable, AnimationMode, LoopMode};
-use crate::animations::spring::{Spring, SpringState};
-use crate::keyframes::KeyframeAnimation;
+use crate::animations::spring::{Spring, SpringState, TransformSprings, clamp_magnitude};
+use crate::animations::transform::Transform;
+use crate::animations::vec2::Vec2;
+use crate::keyframes::{Keyframe, KeyframeAnimation, KeyframeInterpolation};
 use crate::prelude::AnimationConfig;
 use crate::sequence::AnimationSequence;
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
 
 #[cfg(not(feature = "web"))]
 use crate::pool::SpringIntegrator;
 
+#[cfg(feature = "dioxus")]
+pub mod in_view;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+
+/// Errors returned by [`Motion::seek`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum SeekError {
+    /// Spring motion has no fixed duration, so there is no time to seek to.
+    #[error("Spring animations are not time-parameterized and cannot be seeked")]
+    SpringNotSeekable,
+    /// A spring can only be predicted forward from where it is now.
+    #[error("Spring animations can only be predicted at or after the current elapsed time")]
+    SpringPredictionInPast,
+}
+
 #[derive(Clone)]
 pub struct Motion<T: Animatable + Send + 'static> {
     pub initial: T,
@@ -19,9 +42,69 @@ pub struct Motion<T: Animatable + Send + 'static> {
     pub delay_elapsed: Duration,
     pub current_loop: u8,
     pub reverse: bool,
-    config: AnimationConfig,
+    paused: bool,
+    just_completed: bool,
+    // Boxed once per motion, so growing the config does not grow every motion
+    config: Box<AnimationConfig>,
     pub sequence: Option<AnimationSequence<T>>,
     pub keyframe_animation: Option<KeyframeAnimation<T>>,
+    last_animation: Option<Box<(T, T, AnimationConfig)>>,
+    loop_scale: f32,
+    default_config: Option<Box<AnimationConfig>>,
+    extras: Option<Box<MotionExtras<T>>>,
+}
+
+/// Opt-in features, boxed together so motions that use none of them stay small.
+#[derive(Clone)]
+struct MotionExtras<T> {
+    trail: Option<Trail<T>>,
+    follow: Option<FollowTarget<T>>,
+    jitter_state: u64,
+    default_epsilon: Option<f32>,
+    bounces: u8,
+    bounds: Option<ApplyBounds<T>>,
+}
+
+/// Reads the moving target passed to [`Motion::follow`].
+type FollowTarget<T> = Arc<dyn Fn() -> T + Send + Sync>;
+
+/// Keeps `(current, velocity, target)` inside the range set by [`Motion::set_bounds`].
+type ApplyBounds<T> = Arc<dyn Fn(&mut T, &mut T, &mut T) + Send + Sync>;
+
+impl<T> Default for MotionExtras<T> {
+    fn default() -> Self {
+        Self {
+            trail: None,
+            follow: None,
+            jitter_state: 0,
+            default_epsilon: None,
+            bounces: 0,
+            bounds: None,
+        }
+    }
+}
+
+/// Bounded history of recent values, oldest first.
+#[derive(Clone)]
+struct Trail<T> {
+    values: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T: Clone> Trail<T> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    fn record(&mut self, value: &T) {
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value.clone());
+    }
 }
 
 impl<T: Animatable + Send + 'static> Motion<T> {
@@ -36,21 +119,148 @@ impl<T: Animatable + Send + 'static> Motion<T> {
             delay_elapsed: Duration::default(),
             current_loop: 0,
             reverse: false,
-            config: AnimationConfig::default(),
+            paused: false,
+            just_completed: false,
+            config: Box::default(),
             sequence: None,
             keyframe_animation: None,
+            last_animation: None,
+            loop_scale: 1.0,
+            default_config: None,
+            extras: None,
         }
     }
 
+    /// Sets the config used by [`Motion::animate_to_default`].
+    #[cfg_attr(not(feature = "dioxus"), allow(dead_code))]
+    pub(crate) fn with_default_config(mut self, config: AnimationConfig) -> Self {
+        self.default_config = Some(Box::new(config));
+        self
+    }
+
+    /// Animates to `target` with the default config.
+    ///
+    /// Inside components this is the [`AnimationConfig`] provided as context above the
+    /// motion's hook, falling back to [`AnimationConfig::default`].
+    pub fn animate_to_default(&mut self, target: T) {
+        let config = self.default_config.as_deref().cloned().unwrap_or_default();
+        self.animate_to(target, config);
+    }
+
+    /// Whether an animation of `priority` must leave the current one alone.
+    ///
+    /// A paused or halted animation still holds its priority, since it can be resumed.
+    fn yields_to_current(&self, priority: u8) -> bool {
+        (self.running || self.paused) && priority < self.config.priority
+    }
+
+    /// Animates to `target`, unless a higher-priority animation is still running.
+    ///
+    /// See [`AnimationConfig::with_priority`]; by default every call replaces the last.
     pub fn animate_to(&mut self, target: T, config: AnimationConfig) {
+        if self.yields_to_current(config.priority) {
+            return;
+        }
+        self.sequence = None;
+        self.keyframe_animation = None;
+        self.last_animation = Some(Box::new((
+            self.current.clone(),
+            target.clone(),
+            config.clone(),
+        )));
+        self.start_animation(target, config);
+    }
+
+    /// Chases a moving target with `spring`, reading the target from `target` every frame.
+    ///
+    /// Only the target moves between frames: the spring keeps its velocity and position,
+    /// so there is none of the restart jank of calling [`Motion::animate_to`] each frame.
+    /// Any velocity the motion already has carries over into the chase. The motion stays
+    /// running while it follows, even when caught up, until another animation starts or
+    /// it is stopped. Following has the default priority, so it does not interrupt a
+    /// higher-priority animation.
+    pub fn follow(&mut self, target: impl Fn() -> T + Send + Sync + 'static, spring: Spring) {
+        if self.yields_to_current(0) {
+            return;
+        }
+        let velocity = self.velocity.clone();
+        self.sequence = None;
+        self.keyframe_animation = None;
+        self.start_animation(target(), AnimationConfig::spring(spring));
+        self.velocity = velocity;
+        self.extras.get_or_insert_default().follow = Some(Arc::new(target));
+    }
+
+    /// Animates by a relative `delta` instead of to an absolute target.
+    ///
+    /// While an animation is in flight the delta is added to its target, so repeated
+    /// nudges accumulate; otherwise it is added to the current value.
+    pub fn animate_by(&mut self, delta: T, config: AnimationConfig) {
+        let base = if self.running {
+            self.target.clone()
+        } else {
+            self.current.clone()
+        };
+        self.animate_to(base + delta, config);
+    }
+
+    /// Re-runs the last [`Motion::animate_to`] from its original starting value.
+    ///
+    /// Does nothing if `animate_to` has never been called.
+    pub fn replay(&mut self) {
+        let Some((initial, target, config)) = self.last_animation.as_deref().cloned() else {
+            return;
+        };
         self.sequence = None;
         self.keyframe_animation = None;
+        self.current = initial;
         self.start_animation(target, config);
     }
 
+    /// Changes the target mid-animation while keeping the elapsed progress.
+    ///
+    /// `initial` becomes the current value but `elapsed` is left untouched, so a tween
+    /// that is 70% through continues at 70% progress toward the new target and finishes
+    /// when the original animation would have. Springs already carry their velocity
+    /// into a new target, so for them this only swaps the target. A constant-speed tween
+    /// from [`AnimationConfig::tween_speed`] re-derives its duration from the distance
+    /// to the new target, keeping its progress. An idle motion starts
+    /// toward `target` with its previous timing, like [`Motion::animate_to`], but without
+    /// the finished animation's callbacks or chain.
+    pub fn retarget(&mut self, target: T) {
+        if !self.running {
+            let mut config = (*self.config).clone();
+            config.on_complete = None;
+            config.on_cancel = None;
+            config.on_loop = None;
+            config.chain = None;
+            self.start_animation(target, config);
+            return;
+        }
+
+        self.initial = self.current.clone();
+        self.target = target;
+
+        if let AnimationMode::Tween(tween) = self.config.mode
+            && self.config.tween_speed.is_some()
+            && self.keyframe_animation.is_none()
+        {
+            let progress = progress_at(self.elapsed, self.config.tween_duration(&tween));
+            self.config
+                .resolve_tween_speed((self.target.clone() - self.initial.clone()).magnitude());
+            if let AnimationMode::Tween(tween) = self.config.mode {
+                self.elapsed = self.config.tween_duration(&tween).mul_f32(progress);
+            }
+        }
+    }
+
+    /// Plays `sequence` from its first step, whose config sets the sequence's priority.
     pub fn animate_sequence(&mut self, sequence: AnimationSequence<T>) {
         sequence.reset();
         if let Some(first_step) = sequence.current_step_data() {
+            if self.yields_to_current(first_step.config.priority) {
+                return;
+            }
             self.start_animation(
                 first_step.target.clone(),
                 first_step.config.as_ref().clone(),
@@ -59,15 +269,35 @@ impl<T: Animatable + Send + 'static> Motion<T> {
         }
     }
 
+    /// Plays `animation` once, with the default priority.
     pub fn animate_keyframes(&mut self, animation: KeyframeAnimation<T>) {
+        if self.yields_to_current(0) {
+            return;
+        }
+        self.start_keyframes(animation);
+    }
+
+    fn start_keyframes(&mut self, animation: KeyframeAnimation<T>) {
+        self.stop_following();
         self.sequence = None;
+        // Keyframes play once at normal speed, without the previous animation's
+        // callbacks or priority
+        self.config.loop_mode = None;
+        self.config.on_complete = None;
+        self.config.on_cancel = None;
+        self.config.on_loop = None;
+        self.config.chain = None;
+        self.config.priority = 0;
+        self.config.speed = None;
         self.keyframe_animation = Some(animation);
         self.running = true;
+        self.just_completed = false;
         self.elapsed = Duration::default();
         self.delay_elapsed = Duration::default();
         self.velocity = T::default();
         self.current_loop = 0;
         self.reverse = false;
+        self.loop_scale = 1.0;
     }
 
     pub fn get_value(&self) -> T {
@@ -78,6 +308,7 @@ impl<T: Animatable + Send + 'static> Motion<T> {
         self.running
     }
 
+    /// Stops the animation and jumps back to the value it started from.
     pub fn reset(&mut self) {
         self.stop();
         self.current = self.initial.clone();
@@ -86,25 +317,296 @@ impl<T: Animatable + Send + 'static> Motion<T> {
         self.delay_elapsed = Duration::default();
     }
 
+    /// Stops the animation where it is and discards any sequence or keyframes.
+    ///
+    /// Nothing is left to resume; use [`Motion::pause`] or [`Motion::halt`] to keep it.
     pub fn stop(&mut self) {
+        let cancelled = self.running || self.paused;
+        self.stop_following();
         self.running = false;
+        self.paused = false;
         self.current_loop = 0;
         self.velocity = T::default();
         self.reverse = false;
         self.sequence = None;
         self.keyframe_animation = None;
+        if cancelled {
+            self.config.execute_cancel();
+        }
+    }
+
+    /// Freezes a running animation so [`Motion::resume`] can continue it exactly.
+    ///
+    /// Unlike [`Motion::stop`], the velocity, elapsed time, loop count, sequence step and
+    /// keyframe position are all kept. Does nothing if the motion is not running.
+    pub fn pause(&mut self) {
+        if self.running {
+            self.running = false;
+            self.paused = true;
+        }
+    }
+
+    /// Stops the motion dead while keeping its place in a sequence or keyframe animation.
+    ///
+    /// Like [`Motion::pause`], the sequence step, keyframe position, loop count and elapsed
+    /// time are kept, so [`Motion::resume`] carries on with the choreography. Unlike
+    /// `pause`, the velocity is dropped, so a spring resumes from rest rather than with
+    /// the momentum it had. Does nothing if the motion is not running.
+    pub fn halt(&mut self) {
+        if self.running {
+            self.pause();
+            self.velocity = T::default();
+        }
+    }
+
+    /// Continues an animation frozen by [`Motion::pause`] or [`Motion::halt`]; does nothing otherwise.
+    pub fn resume(&mut self) {
+        if self.paused {
+            self.running = true;
+            self.paused = false;
+        }
+    }
+
+    /// Whether the motion is frozen by [`Motion::pause`].
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Tag of the sequence step playing now, if it was added with
+    /// [`AnimationSequence::then_tagged`].
+    pub fn current_step_tag(&self) -> Option<&str> {
+        self.sequence.as_ref()?.current_tag()
+    }
+
+    /// Zero-based index of the sequence step playing now and the number of steps.
+    ///
+    /// Returns `(0, 0)` when no sequence is playing, including once one has finished.
+    pub fn step_progress(&self) -> (usize, usize) {
+        self.sequence.as_ref().map_or((0, 0), |sequence| {
+            (
+                sequence.current_step_index() as usize,
+                sequence.total_steps(),
+            )
+        })
+    }
+
+    /// Whether the last [`Motion::update`] finished the animation.
+    ///
+    /// True only from the update in which the motion stops running on its own until
+    /// the next update or the next animation starts, so polling code can fire one-shot
+    /// work on completion without a callback. Stopping or pausing never sets it.
+    pub fn just_completed(&self) -> bool {
+        self.just_completed
+    }
+
+    /// Drops the completion edge once the frame that finished has been seen.
+    #[cfg_attr(not(feature = "dioxus"), allow(dead_code))]
+    pub(crate) fn clear_just_completed(&mut self) {
+        self.just_completed = false;
+    }
+
+    /// Whether the motion is running but still waiting out its configured delay.
+    ///
+    /// [`Motion::is_running`] is already true during the delay, before anything moves.
+    pub fn is_delayed(&self) -> bool {
+        self.running && self.delay_elapsed < self.config.delay
+    }
+
+    /// Whether the motion is running and past its delay, i.e. actually animating.
+    pub fn has_started(&self) -> bool {
+        self.running && !self.is_delayed()
+    }
+
+    /// Aborts an animation that is still waiting out its delay, leaving the value untouched.
+    ///
+    /// Returns `true` if a pending animation was cancelled. Like [`Motion::stop`], any
+    /// sequence or keyframes are discarded. Once the delay has elapsed the animation is
+    /// already moving and this does nothing; use [`Motion::stop`] for that.
+    pub fn cancel_delay(&mut self) -> bool {
+        if !self.running || self.delay_elapsed >= self.config.delay {
+            return false;
+        }
+
+        self.stop();
+        self.target = self.current.clone();
+        self.elapsed = Duration::default();
+        self.delay_elapsed = Duration::default();
+        true
     }
 
     pub fn delay(&mut self, duration: Duration) {
         self.config.delay = duration;
     }
 
+    #[cfg(all(test, feature = "dioxus"))]
+    pub(crate) fn config(&self) -> &AnimationConfig {
+        &self.config
+    }
+
     /// Gets the effective epsilon threshold for this animation.
+    ///
+    /// The config's own epsilon wins, then the motion's default from
+    /// [`Motion::set_default_epsilon`], then the app-wide one from
+    /// [`resource_pools::set_global_epsilon`](crate::resource_pools::set_global_epsilon),
+    /// then [`Animatable::epsilon`] for the type.
     pub fn get_epsilon(&self) -> f32 {
-        self.config.epsilon.unwrap_or_else(T::epsilon)
+        self.explicit_epsilon().unwrap_or_else(T::epsilon)
+    }
+
+    /// Sets the epsilon used by every later animation whose config doesn't set one.
+    pub fn set_default_epsilon(&mut self, epsilon: f32) {
+        self.extras.get_or_insert_default().default_epsilon = Some(epsilon);
+    }
+
+    /// Epsilon set by the config, the motion's default or globally, rather than by the type.
+    fn explicit_epsilon(&self) -> Option<f32> {
+        self.config
+            .epsilon
+            .or_else(|| self.extras.as_ref()?.default_epsilon)
+            .or_else(crate::pool::resource_pools::global_epsilon)
+    }
+
+    /// How long the current animation has been playing, excluding any delay.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Time left in the current tween or keyframe animation, clamped at zero.
+    ///
+    /// Springs have no fixed duration and return `None`.
+    pub fn remaining(&self) -> Option<Duration> {
+        let duration = match self.keyframe_animation.as_ref() {
+            Some(animation) => animation.duration,
+            None => match self.active_mode() {
+                AnimationMode::Tween(tween) => self.config.tween_duration(&tween),
+                AnimationMode::Spring(_) | AnimationMode::ComponentSpring(_) => return None,
+            },
+        };
+        Some(duration.saturating_sub(self.elapsed))
+    }
+
+    /// Jumps a tween or keyframe animation to a normalized `progress` (0.0 to 1.0).
+    ///
+    /// `elapsed` is set to `progress * duration` and `current` is recomputed with the
+    /// same interpolation used by [`Motion::update`]. Any pending delay is skipped, and
+    /// `running` is left untouched so a scrubber can seek without starting playback.
+    ///
+    /// Springs are not time-parameterized and return [`SeekError::SpringNotSeekable`].
+    pub fn seek(&mut self, progress: f32) -> Result<(), SeekError> {
+        let progress = progress.clamp(0.0, 1.0);
+
+        if let Some(animation) = self.keyframe_animation.as_ref() {
+            let elapsed = animation.duration.mul_f32(progress);
+            if let Some(current) = animation.value_at(progress) {
+                self.current = current;
+            }
+            self.elapsed = elapsed;
+            self.delay_elapsed = self.config.delay;
+            return Ok(());
+        }
+
+        match self.active_mode() {
+            AnimationMode::Spring(_) | AnimationMode::ComponentSpring(_) => {
+                Err(SeekError::SpringNotSeekable)
+            }
+            AnimationMode::Tween(tween) => {
+                self.elapsed = self.config.tween_duration(&tween).mul_f32(progress);
+                self.delay_elapsed = self.config.delay;
+                self.current = self.tween_value_at(tween, progress);
+                Ok(())
+            }
+        }
+    }
+
+    /// Predicts the value at an `elapsed` offset into the current animation without
+    /// advancing it.
+    ///
+    /// Tweens and keyframes use the same interpolation as [`Motion::update`], clamped to
+    /// the end of the current run. Single springs are solved analytically from the current
+    /// value and velocity, ignoring `max_velocity` and `min_duration`, so the result is a
+    /// close prediction rather than the exact next frames. Springs cannot look back
+    /// ([`SeekError::SpringPredictionInPast`]), and component springs are not predicted.
+    pub fn get_value_at(&self, elapsed: Duration) -> Result<T, SeekError> {
+        if let Some(animation) = self.keyframe_animation.as_ref() {
+            let progress = progress_at(elapsed, animation.duration);
+            return Ok(animation
+                .value_at(progress)
+                .unwrap_or_else(|| self.current.clone()));
+        }
+
+        match self.active_mode() {
+            AnimationMode::Tween(tween) => {
+                let progress = progress_at(elapsed, self.config.tween_duration(&tween));
+                Ok(self.tween_value_at(tween, progress))
+            }
+            AnimationMode::Spring(spring) => {
+                if !self.running {
+                    return Ok(self.current.clone());
+                }
+                let Some(ahead) = elapsed.checked_sub(self.elapsed) else {
+                    return Err(SeekError::SpringPredictionInPast);
+                };
+                let (position, velocity) = spring.response(ahead.as_secs_f32());
+                let displacement = self.current.clone() - self.target.clone();
+                Ok(
+                    self.target.clone()
+                        + displacement * position
+                        + self.velocity.clone() * velocity,
+                )
+            }
+            AnimationMode::ComponentSpring(_) => Err(SeekError::SpringNotSeekable),
+        }
+    }
+
+    /// Starts recording the last `len` values reached by [`Motion::update`].
+    ///
+    /// The buffer is allocated once here, so recording never allocates per frame.
+    /// Enabling again resizes and clears it; a `len` of zero turns the trail off.
+    pub fn enable_trail(&mut self, len: usize) {
+        self.extras.get_or_insert_default().trail = (len > 0).then(|| Trail::new(len));
+    }
+
+    /// Recorded values, oldest first; empty unless [`Motion::enable_trail`] was called.
+    pub fn trail(&self) -> Vec<T> {
+        self.extras
+            .as_ref()
+            .and_then(|extras| extras.trail.as_ref())
+            .map(|trail| trail.values.iter().cloned().collect())
+            .unwrap_or_default()
     }
 
     pub fn update(&mut self, dt: f32) -> bool {
+        let was_running = self.running;
+        let running = self.advance(dt);
+        self.just_completed = was_running && !self.running;
+        self.apply_bounds();
+
+        if was_running
+            && let Some(trail) = self
+                .extras
+                .as_deref_mut()
+                .and_then(|extras| extras.trail.as_mut())
+        {
+            trail.record(&self.current);
+        }
+
+        running
+    }
+
+    /// Advances up to `n` frames of `dt` seconds and returns the value reached.
+    ///
+    /// Each frame is a full [`Motion::update`], so the result matches calling it in a
+    /// loop; stepping stops early once the motion is no longer running.
+    pub fn step_n(&mut self, dt: f32, n: usize) -> T {
+        for _ in 0..n {
+            if !self.update(dt) {
+                break;
+            }
+        }
+        self.current.clone()
+    }
+
+    fn advance(&mut self, dt: f32) -> bool {
         const MIN_DELTA: f32 = 1.0 / 240.0;
 
         if !self.running {
@@ -120,23 +622,46 @@ impl<T: Animatable + Send + 'static> Motion<T> {
             return true;
         }
 
+        let dt = dt * self.config.speed_multiplier() / self.loop_scale;
+
         if self.keyframe_animation.is_some() {
             if self.update_keyframes(dt) {
-                self.finish_motion();
-                return false;
+                return self.handle_completion();
             }
             return true;
         }
 
-        let completed = match self.config.mode {
+        if let Some(follow) = self.follow_target() {
+            self.target = follow();
+        }
+
+        let completed = match self.active_mode() {
             AnimationMode::Spring(spring) => {
-                let state = self.update_spring(spring, dt);
-                matches!(state, SpringState::Completed)
+                self.elapsed += Duration::from_secs_f32(dt);
+                let velocity = self.velocity.clone();
+                let state = self.update_spring(self.bounce_limited(spring), dt);
+                self.count_bounce(velocity);
+                let floor_reached = self.floor_spring_progress();
+                matches!(state, SpringState::Completed) && floor_reached
+            }
+            AnimationMode::ComponentSpring(springs) => {
+                self.elapsed += Duration::from_secs_f32(dt);
+                let velocity = self.velocity.clone();
+                let springs = TransformSprings {
+                    x: self.bounce_limited(springs.x),
+                    y: self.bounce_limited(springs.y),
+                    scale: self.bounce_limited(springs.scale),
+                    rotation: self.bounce_limited(springs.rotation),
+                };
+                let state = self.update_component_spring(springs, dt);
+                self.count_bounce(velocity);
+                let floor_reached = self.floor_spring_progress();
+                matches!(state, SpringState::Completed) && floor_reached
             }
             AnimationMode::Tween(tween) => self.update_tween(tween, dt),
         };
 
-        if !completed {
+        if !completed || self.follow_target().is_some() {
             return true;
         }
 
@@ -147,16 +672,139 @@ impl<T: Animatable + Send + 'static> Motion<T> {
         self.handle_completion()
     }
 
-    fn start_animation(&mut self, target: T, config: AnimationConfig) {
+    /// Critically damps `spring` once the config's bounce limit is used up.
+    fn bounce_limited(&self, spring: Spring) -> Spring {
+        let bounces = self.extras.as_ref().map_or(0, |extras| extras.bounces);
+        match self.config.max_bounces {
+            Some(max_bounces) if bounces >= max_bounces => spring.critically_damped(),
+            _ => spring,
+        }
+    }
+
+    /// Counts a bounce if the velocity reversed since `previous`.
+    fn count_bounce(&mut self, previous: T) {
+        if self.config.max_bounces.is_none() {
+            return;
+        }
+
+        let velocity = self.velocity.clone();
+        let reversed =
+            (previous.clone() + velocity.clone()).magnitude() < (previous - velocity).magnitude();
+        if reversed {
+            let bounces = &mut self.extras.get_or_insert_default().bounces;
+            *bounces = bounces.saturating_add(1);
+        }
+    }
+
+    fn follow_target(&self) -> Option<&FollowTarget<T>> {
+        self.extras.as_ref()?.follow.as_ref()
+    }
+
+    fn apply_bounds(&mut self) {
+        if let Some(bounds) = self
+            .extras
+            .as_deref()
+            .and_then(|extras| extras.bounds.as_ref())
+        {
+            bounds(&mut self.current, &mut self.velocity, &mut self.target);
+        }
+    }
+
+    fn stop_following(&mut self) {
+        if let Some(extras) = self.extras.as_deref_mut() {
+            extras.follow = None;
+        }
+    }
+
+    fn start_animation(&mut self, target: T, mut config: AnimationConfig) {
+        self.stop_following();
+        config.resolve_tween_speed((target.clone() - self.current.clone()).magnitude());
+        let velocity = self.handoff_velocity(&config.mode);
         self.initial = self.current.clone();
         self.target = target;
         self.running = true;
+        self.paused = false;
+        self.just_completed = false;
         self.elapsed = Duration::default();
         self.delay_elapsed = Duration::default();
-        self.velocity = T::default();
+        self.velocity = velocity;
         self.current_loop = 0;
         self.reverse = false;
-        self.config = config;
+        self.loop_scale = 1.0;
+        self.reset_bounces();
+        if let Some(jitter) = config.loop_jitter {
+            self.extras.get_or_insert_default().jitter_state = jitter.initial_state();
+        }
+        *self.config = config;
+        self.apply_time_offset();
+    }
+
+    /// Velocity a new animation in `incoming` mode inherits from the one it replaces.
+    ///
+    /// A spring replacing a running tween starts with the tween's current speed, so the
+    /// switch has no hitch. Tweens follow their own curve and always start from rest, and
+    /// springs replacing springs keep starting from rest as before.
+    fn handoff_velocity(&self, incoming: &AnimationMode) -> T {
+        match (self.active_mode(), incoming) {
+            (
+                AnimationMode::Tween(tween),
+                AnimationMode::Spring(_) | AnimationMode::ComponentSpring(_),
+            ) if self.keyframe_animation.is_none() => self.tween_velocity(tween),
+            _ => T::default(),
+        }
+    }
+
+    /// Current velocity of a running tween, in value units per second, estimated from
+    /// its eased curve around the current progress.
+    fn tween_velocity(&self, tween: crate::prelude::Tween) -> T {
+        const STEP: f32 = 1e-3;
+
+        if !self.running || self.paused || self.delay_elapsed < self.config.delay {
+            return T::default();
+        }
+        let duration = self.config.tween_duration(&tween);
+        let progress = progress_at(self.elapsed, duration);
+        let duration = duration.as_secs_f32();
+        if duration == 0.0 || progress >= 1.0 {
+            return T::default();
+        }
+
+        let before = (progress - STEP).max(0.0);
+        let after = (progress + STEP).min(1.0);
+        let change = self.tween_value_at(tween, after) - self.tween_value_at(tween, before);
+        let playback_rate = self.config.speed_multiplier() / self.loop_scale;
+        change * (playback_rate / ((after - before) * duration))
+    }
+
+    /// Jumps a freshly started animation to its configured time offset.
+    fn apply_time_offset(&mut self) {
+        let Some(offset) = self.config.time_offset.filter(|offset| !offset.is_zero()) else {
+            return;
+        };
+        self.elapsed = offset;
+
+        match self.active_mode() {
+            AnimationMode::Tween(tween) => {
+                let progress = progress_at(self.elapsed, self.config.tween_duration(&tween));
+                self.current = self.tween_value_at(tween, progress);
+            }
+            AnimationMode::Spring(spring) => {
+                // Seeded from the handed-off velocity, so an interrupted tween or
+                // spring keeps its momentum through the skipped time
+                let offset = offset.as_secs_f32();
+                let (from_position, from_velocity) = spring.response(offset);
+                let (velocity_from_position, velocity_from_velocity) =
+                    spring.velocity_response(offset);
+                let displacement = self.current.clone() - self.target.clone();
+                let velocity = self.velocity.clone();
+                self.current = self.target.clone()
+                    + displacement.clone() * from_position
+                    + velocity.clone() * from_velocity;
+                self.velocity =
+                    displacement * velocity_from_position + velocity * velocity_from_velocity;
+            }
+            AnimationMode::ComponentSpring(_) => {}
+        }
     }
 
     fn advance_sequence_step(&mut self) -> bool {
@@ -196,37 +844,12 @@ impl<T: Animatable + Send + 'static> Motion<T> {
                 (next_elapsed_secs / duration_secs).clamp(0.0, 1.0)
             };
 
-            if animation.keyframes.is_empty() {
-                return true;
-            }
-
-            let (start, end) = if let Some(window) = animation
-                .keyframes
-                .windows(2)
-                .find(|window| progress >= window[0].offset && progress <= window[1].offset)
-            {
-                (&window[0], &window[1])
-            } else if progress <= animation.keyframes[0].offset {
-                let first = &animation.keyframes[0];
-                (first, first)
-            } else if let Some(last) = animation.keyframes.last() {
-                (last, last)
-            } else {
+            let Some(current) = animation.value_at(progress) else {
                 return true;
             };
 
-            let local_progress = if start.offset == end.offset {
-                1.0
-            } else {
-                (progress - start.offset) / (end.offset - start.offset)
-            };
-
-            let eased_progress = end
-                .easing
-                .map_or(local_progress, |ease| (ease)(local_progress, 0.0, 1.0, 1.0));
-
             (
-                start.value.interpolate(&end.value, eased_progress),
+                current,
                 Duration::from_secs_f32(next_elapsed_secs),
                 progress >= 1.0,
             )
@@ -239,73 +862,141 @@ impl<T: Animatable + Send + 'static> Motion<T> {
     }
 
     fn update_spring(&mut self, spring: Spring, dt: f32) -> SpringState {
-        let epsilon = self.get_epsilon();
-        let delta = self.target.clone() - self.current.clone();
-
-        if delta.magnitude() < epsilon && self.velocity.magnitude() < epsilon {
-            self.current = self.target.clone();
-            self.velocity = T::default();
+        if self.snap_settled_spring() {
             return SpringState::Completed;
         }
 
-        #[cfg(feature = "web")]
-        {
-            let stiffness = spring.stiffness;
-            let damping = spring.damping;
-            let mass_inv = 1.0 / spring.mass;
-
-            const FIXED_DT: f32 = 1.0 / 120.0;
-            let steps = ((dt / FIXED_DT) as usize).max(1);
-            let step_dt = dt / steps as f32;
-
-            for _ in 0..steps {
-                let step_delta = self.target.clone() - self.current.clone();
-                let force = step_delta * stiffness;
-                let damping_force = self.velocity.clone() * damping;
-                self.velocity =
-                    self.velocity.clone() + (force - damping_force) * (mass_inv * step_dt);
-                self.current = self.current.clone() + self.velocity.clone() * step_dt;
-            }
+        let (current, velocity) = integrate_spring(
+            self.current.clone(),
+            self.velocity.clone(),
+            self.target.clone(),
+            &spring,
+            self.config.max_velocity,
+            dt,
+        );
+        self.current = current;
+        self.velocity = velocity;
+
+        self.check_spring_completion()
+    }
+
+    fn update_component_spring(&mut self, springs: TransformSprings, dt: f32) -> SpringState {
+        if self.snap_settled_spring() {
+            return SpringState::Completed;
         }
 
-        #[cfg(not(feature = "web"))]
-        {
-            let mut integrator = SpringIntegrator::new();
-            let (new_pos, new_vel) = integrator.integrate_rk4(
-                self.current.clone(),
-                self.velocity.clone(),
-                self.target.clone(),
-                &spring,
-                dt,
-            );
-            self.current = new_pos;
-            self.velocity = new_vel;
+        let transform_state = (
+            (&self.current as &dyn Any)
+                .downcast_ref::<Transform>()
+                .copied(),
+            (&self.velocity as &dyn Any)
+                .downcast_ref::<Transform>()
+                .copied(),
+            (&self.target as &dyn Any)
+                .downcast_ref::<Transform>()
+                .copied(),
+        );
+
+        let (Some(current), Some(velocity), Some(target)) = transform_state else {
+            // Per-field springs only apply to Transform; other types use the x spring.
+            return self.update_spring(springs.x, dt);
+        };
+
+        let (new_current, new_velocity) = integrate_transform_springs(
+            current,
+            velocity,
+            target,
+            &springs,
+            self.config.max_velocity,
+            dt,
+        );
+        if let Some(current) = (&mut self.current as &mut dyn Any).downcast_mut::<Transform>() {
+            *current = new_current;
+        }
+        if let Some(velocity) = (&mut self.velocity as &mut dyn Any).downcast_mut::<Transform>() {
+            *velocity = new_velocity;
         }
 
         self.check_spring_completion()
     }
 
-    fn check_spring_completion(&mut self) -> SpringState {
-        let epsilon = self.get_epsilon();
-        let epsilon_sq = epsilon * epsilon;
-        let velocity_sq = self.velocity.magnitude().powi(2);
-        let delta_sq = (self.target.clone() - self.current.clone())
-            .magnitude()
-            .powi(2);
-
-        if velocity_sq < epsilon_sq && delta_sq < epsilon_sq {
+    /// Snaps to the target and returns true if the spring is already at rest.
+    fn snap_settled_spring(&mut self) -> bool {
+        if self.is_settled() {
             self.current = self.target.clone();
             self.velocity = T::default();
+            return true;
+        }
+
+        false
+    }
+
+    fn check_spring_completion(&mut self) -> SpringState {
+        if self.snap_settled_spring() {
             SpringState::Completed
         } else {
             SpringState::Active
         }
     }
 
+    /// Whether both the distance to the target and the velocity are within epsilon.
+    ///
+    /// An explicit epsilon from the config, [`Motion::set_default_epsilon`] or the global
+    /// epsilon compares
+    /// whole magnitudes; otherwise types with [`Animatable::epsilon_components`] are
+    /// judged component by component.
+    fn is_settled(&self) -> bool {
+        let delta = self.target.clone() - self.current.clone();
+
+        T::epsilon_components()
+            .filter(|_| self.explicit_epsilon().is_none())
+            .map_or_else(
+                || {
+                    let epsilon = self.get_epsilon();
+                    delta.magnitude() < epsilon && self.velocity.magnitude() < epsilon
+                },
+                |epsilon| {
+                    delta.within_components(&epsilon) && self.velocity.within_components(&epsilon)
+                },
+            )
+    }
+
+    /// Caps spring progress at `elapsed / min_duration` while the floor has not passed.
+    ///
+    /// The velocity is capped to the pace that covers the whole distance in
+    /// `min_duration`, so the spring doesn't build up speed against the cap and overshoot
+    /// once the floor passes. Returns true once the spring is allowed to complete.
+    fn floor_spring_progress(&mut self) -> bool {
+        let Some(floor) = self.config.min_duration.filter(|floor| !floor.is_zero()) else {
+            return true;
+        };
+
+        let elapsed_secs = self.elapsed.as_secs_f32();
+        let allowed = elapsed_secs / floor.as_secs_f32();
+        if allowed >= 1.0 {
+            return true;
+        }
+
+        let total = (self.target.clone() - self.initial.clone()).magnitude();
+        let remaining = (self.target.clone() - self.current.clone()).magnitude();
+        if total > 0.0 && 1.0 - remaining / total > allowed {
+            self.current = self.initial.interpolate(&self.target, allowed);
+        }
+
+        let pace = total / floor.as_secs_f32();
+        let speed = self.velocity.magnitude();
+        if speed > pace {
+            self.velocity = self.velocity.clone() * (pace / speed);
+        }
+
+        false
+    }
+
     fn update_tween(&mut self, tween: crate::prelude::Tween, dt: f32) -> bool {
-        let elapsed_secs = self.elapsed.as_secs_f32() + dt;
-        self.elapsed = Duration::from_secs_f32(elapsed_secs);
-        let duration_secs = tween.duration.as_secs_f32();
+        // `from_secs_f32` is inexact, so summing through it falls short of whole frames
+        self.elapsed += Duration::from_secs_f64(f64::from(dt));
+        let elapsed_secs = self.elapsed.as_secs_f32();
+        let duration_secs = self.config.tween_duration(&tween).as_secs_f32();
 
         let progress = if duration_secs == 0.0 {
             1.0
@@ -323,62 +1014,103 @@ impl<T: Animatable + Send + 'static> Motion<T> {
             return true;
         }
 
+        self.current = self.tween_value_at(tween, progress);
+
+        false
+    }
+
+    fn tween_value_at(&self, tween: crate::prelude::Tween, progress: f32) -> T {
         let eased_progress = (tween.easing)(progress, 0.0, 1.0, 1.0);
-        self.current = match eased_progress {
+        match eased_progress {
             0.0 => self.initial.clone(),
             1.0 => self.target.clone(),
             _ => self.initial.interpolate(&self.target, eased_progress),
-        };
-
-        false
+        }
     }
 
     fn handle_completion(&mut self) -> bool {
         match self.config.loop_mode.unwrap_or(LoopMode::None) {
-            LoopMode::None => {
-                self.config.execute_completion();
-                self.finish_motion();
-                false
+            LoopMode::None | LoopMode::Times(0 | 1) | LoopMode::AlternateTimes(0) => {
+                self.complete_motion()
             }
             LoopMode::Infinite => {
+                self.current_loop = self.current_loop.wrapping_add(1);
                 self.restart_motion();
+                self.config.execute_loop(self.current_loop);
                 true
             }
             LoopMode::Times(count) => {
                 self.current_loop += 1;
                 if self.current_loop >= count {
-                    self.config.execute_completion();
-                    self.finish_motion();
-                    false
+                    self.complete_motion()
                 } else {
                     self.restart_motion();
+                    self.config.execute_loop(self.current_loop);
                     true
                 }
             }
-            LoopMode::Alternate => {
+            LoopMode::Alternate | LoopMode::PingPong { .. } => {
+                self.current_loop = self.current_loop.wrapping_add(1);
                 self.reverse_motion();
+                self.config.execute_loop(self.current_loop);
                 true
             }
             LoopMode::AlternateTimes(count) => {
                 self.current_loop += 1;
                 if self.current_loop >= count * 2 {
-                    self.config.execute_completion();
-                    self.finish_motion();
-                    false
+                    self.complete_motion()
                 } else {
                     self.reverse_motion();
+                    self.config.execute_loop(self.current_loop);
                     true
                 }
             }
         }
     }
 
-    fn finish_motion(&mut self) {
-        self.running = false;
-        self.current_loop = 0;
-        self.velocity = T::default();
-        self.sequence = None;
-        self.keyframe_animation = None;
+    /// Mode driving the current leg; [`LoopMode::PingPong`] picks one per direction.
+    fn active_mode(&self) -> AnimationMode {
+        match self.config.loop_mode {
+            Some(LoopMode::PingPong { forward, backward }) => {
+                if self.reverse {
+                    backward
+                } else {
+                    forward
+                }
+            }
+            _ => self.config.mode,
+        }
+    }
+
+    /// Runs the completion callback, then starts the chained animation if there is one.
+    ///
+    /// Returns whether the motion is still running.
+    fn complete_motion(&mut self) -> bool {
+        self.config.execute_completion();
+
+        let chained = self
+            .config
+            .chain
+            .as_ref()
+            .and_then(|chain| chain.get::<T>());
+        let Some((target, config)) = chained else {
+            self.finish_motion();
+            return false;
+        };
+        self.keyframe_animation = None;
+
+        let velocity = self.velocity.clone();
+        self.start_animation(target, config);
+        self.velocity = velocity;
+        true
+    }
+
+    fn finish_motion(&mut self) {
+        self.running = false;
+        self.current_loop = 0;
+        self.velocity = T::default();
+        self.sequence = None;
+        self.keyframe_animation = None;
     }
 
     fn restart_motion(&mut self) {
@@ -387,15 +1119,303 @@ impl<T: Animatable + Send + 'static> Motion<T> {
         self.delay_elapsed = Duration::default();
         self.velocity = T::default();
         self.running = true;
+        self.reset_bounces();
+
+        if let Some(jitter) = self.config.loop_jitter {
+            let state = &mut self.extras.get_or_insert_default().jitter_state;
+            self.loop_scale = jitter.next_scale(state);
+        }
+    }
+
+    fn reset_bounces(&mut self) {
+        if let Some(extras) = self.extras.as_deref_mut() {
+            extras.bounces = 0;
+        }
     }
 
     fn reverse_motion(&mut self) {
         self.reverse = !self.reverse;
         std::mem::swap(&mut self.initial, &mut self.target);
+        if let Some(animation) = self.keyframe_animation.as_mut() {
+            *animation = animation.reversed();
+        }
         self.restart_motion();
     }
 }
 
+impl Motion<Vec2> {
+    /// Moves through `points` in order along a smooth path.
+    ///
+    /// The path is built with [`KeyframeAnimation::through_points`], so the motion keeps
+    /// an even speed and passes through every point. A tween `config` sets the time for
+    /// one pass, while springs, which have no fixed duration, take one second; easing
+    /// is not applied. Delay, speed, looping and callbacks work as for
+    /// [`Motion::animate_to`], so [`LoopMode::Infinite`] over a closed path orbits and
+    /// [`LoopMode::Alternate`] runs back and forth.
+    pub fn animate_along_path(&mut self, points: &[Vec2], config: AnimationConfig) {
+        if self.yields_to_current(config.priority) {
+            return;
+        }
+        let duration = match config.mode {
+            AnimationMode::Tween(tween) => config.tween_duration(&tween),
+            AnimationMode::Spring(_) | AnimationMode::ComponentSpring(_) => config.get_duration(),
+        };
+
+        self.start_keyframes(KeyframeAnimation::through_points(points, duration));
+        self.initial = points.first().copied().unwrap_or(self.current);
+        self.target = points.last().copied().unwrap_or(self.current);
+        *self.config = config;
+    }
+}
+
+impl<T: Bounded + Send + Sync + 'static> Motion<T> {
+    /// Keeps the value within `min..=max` after every update.
+    ///
+    /// Springs overshooting the range either stop at its edge or bounce back off it,
+    /// depending on `behavior`; each component is bounded on its own. Targets outside
+    /// the range are pulled onto its edge, so the motion can still settle. Tweens never
+    /// leave the range between in-range endpoints, but are bounded all the same. Bounds
+    /// given the wrong way round are swapped, and a NaN bound leaves that side open.
+    pub fn set_bounds(&mut self, min: T, max: T, behavior: BoundaryBehavior) {
+        let bounds: ApplyBounds<T> = Arc::new(move |current, velocity, target| {
+            T::apply_bounds(current, velocity, &min, &max, behavior);
+            T::apply_bounds(
+                target,
+                &mut T::default(),
+                &min,
+                &max,
+                BoundaryBehavior::Clamp,
+            );
+        });
+        self.extras.get_or_insert_default().bounds = Some(bounds);
+        self.apply_bounds();
+    }
+
+    /// Builder form of [`Motion::set_bounds`].
+    pub fn with_bounds(mut self, min: T, max: T, behavior: BoundaryBehavior) -> Self {
+        self.set_bounds(min, max, behavior);
+        self
+    }
+
+    /// Lets the value move freely again after [`Motion::set_bounds`].
+    pub fn clear_bounds(&mut self) {
+        if let Some(extras) = self.extras.as_deref_mut() {
+            extras.bounds = None;
+        }
+    }
+}
+
+impl Motion<Color> {
+    /// Cycles the color once around the full hue wheel every `duration`.
+    ///
+    /// Starts at red (hue 0) and turns through yellow, green, cyan, blue and magenta back
+    /// to red at the given HSL `saturation` and `lightness`, always the long way round,
+    /// so [`LoopMode::Infinite`] gives a seamless rainbow. Hues are sampled every 15° and
+    /// blended in between, which keeps the color close to the true HSL wheel.
+    pub fn animate_hue_cycle(
+        &mut self,
+        saturation: f32,
+        lightness: f32,
+        duration: Duration,
+        loop_mode: LoopMode,
+    ) {
+        const HUE_STEPS: usize = 24;
+
+        let keyframes = (0..=HUE_STEPS)
+            .map(|step| {
+                let turn = step as f32 / HUE_STEPS as f32;
+                Keyframe {
+                    value: Color::from_hsl(turn * 360.0, saturation, lightness),
+                    offset: turn,
+                    easing: None,
+                }
+            })
+            .collect();
+        self.animate_keyframes(KeyframeAnimation {
+            keyframes,
+            duration,
+            interpolation: KeyframeInterpolation::Linear,
+        });
+        let start = Color::from_hsl(0.0, saturation, lightness);
+        self.initial = start;
+        self.target = start;
+        *self.config = AnimationConfig::tween(duration).with_loop(loop_mode);
+    }
+}
+
+/// Type-erased view of a [`Motion`] for driving motions of different value types together.
+///
+/// A driver can keep `Vec<Box<dyn AnimatedValue>>` holding `Motion<f32>`, `Motion<Color>`
+/// and `Motion<Transform>` side by side and tick them all in one loop. The registry behind
+/// `stop_all` holds every mounted motion this way, whatever its value type.
+pub trait AnimatedValue {
+    /// Advances the animation by `dt` seconds, returning whether it is still running.
+    fn update(&mut self, dt: f32) -> bool;
+
+    /// Whether the animation is currently running.
+    fn is_running(&self) -> bool;
+
+    /// Stops the animation, keeping its current value.
+    fn stop(&mut self);
+}
+
+impl<T: Animatable + Send + 'static> AnimatedValue for Motion<T> {
+    fn update(&mut self, dt: f32) -> bool {
+        Motion::update(self, dt)
+    }
+
+    fn is_running(&self) -> bool {
+        Motion::is_running(self)
+    }
+
+    fn stop(&mut self) {
+        Motion::stop(self)
+    }
+}
+
+/// Normalized progress `elapsed / duration`, clamped to `0.0..=1.0`.
+fn progress_at(elapsed: Duration, duration: Duration) -> f32 {
+    let duration_secs = duration.as_secs_f32();
+    if duration_secs == 0.0 {
+        1.0
+    } else {
+        (elapsed.as_secs_f32() / duration_secs).clamp(0.0, 1.0)
+    }
+}
+
+/// Advances a spring by `dt`, returning the new position and velocity.
+///
+/// With `max_velocity` set, the velocity is capped on every step so the value never
+/// covers more than `max_velocity * dt` in one call.
+fn integrate_spring<U: Animatable>(
+    mut current: U,
+    mut velocity: U,
+    target: U,
+    spring: &Spring,
+    max_velocity: Option<f32>,
+    dt: f32,
+) -> (U, U) {
+    #[cfg(feature = "web")]
+    {
+        let stiffness = spring.stiffness;
+        let damping = spring.damping;
+        let mass_inv = 1.0 / spring.mass;
+
+        const FIXED_DT: f32 = 1.0 / 120.0;
+        let steps = ((dt / FIXED_DT) as usize).max(1);
+        let step_dt = dt / steps as f32;
+
+        for _ in 0..steps {
+            let step_delta = target.clone() - current.clone();
+            let force = step_delta * stiffness;
+            let damping_force = velocity.clone() * damping;
+            velocity = velocity.clone() + (force - damping_force) * (mass_inv * step_dt);
+            velocity = clamp_magnitude(velocity, max_velocity);
+            current = current.clone() + velocity.clone() * step_dt;
+        }
+    }
+
+    #[cfg(not(feature = "web"))]
+    {
+        let start = max_velocity.is_some().then(|| current.clone());
+        let mut integrator = SpringIntegrator::new();
+        velocity = clamp_magnitude(velocity, max_velocity);
+        (current, velocity) = integrator.integrate_rk4(current, velocity, target, spring, dt);
+        velocity = clamp_magnitude(velocity, max_velocity);
+        if let Some(start) = start {
+            // RK4 blends intermediate velocities, which may exceed the cap on their own.
+            let step = current - start.clone();
+            current = start + clamp_magnitude(step, max_velocity.map(|max| max * dt));
+        }
+    }
+
+    (current, velocity)
+}
+
+/// Advances each `Transform` field with its own spring.
+fn integrate_transform_springs(
+    current: Transform,
+    velocity: Transform,
+    target: Transform,
+    springs: &TransformSprings,
+    max_velocity: Option<f32>,
+    dt: f32,
+) -> (Transform, Transform) {
+    let step = |current: f32, velocity: f32, target: f32, spring: &Spring| {
+        integrate_spring(current, velocity, target, spring, max_velocity, dt)
+    };
+
+    let (x, x_velocity) = step(current.x, velocity.x, target.x, &springs.x);
+    let (y, y_velocity) = step(current.y, velocity.y, target.y, &springs.y);
+    let (scale, scale_velocity) = step(current.scale, velocity.scale, target.scale, &springs.scale);
+    let (rotation, rotation_velocity) = step(
+        current.rotation,
+        velocity.rotation,
+        target.rotation,
+        &springs.rotation,
+    );
+    let (rotation_x, rotation_x_velocity) = step(
+        current.rotation_x,
+        velocity.rotation_x,
+        target.rotation_x,
+        &springs.rotation,
+    );
+    let (rotation_y, rotation_y_velocity) = step(
+        current.rotation_y,
+        velocity.rotation_y,
+        target.rotation_y,
+        &springs.rotation,
+    );
+    let (perspective, perspective_velocity) = step(
+        current.perspective,
+        velocity.perspective,
+        target.perspective,
+        &springs.scale,
+    );
+    let (current_origin, velocity_origin, target_origin) = (
+        current.origin_offset(),
+        velocity.origin_offset(),
+        target.origin_offset(),
+    );
+    let (origin_x, origin_x_velocity) = step(
+        current_origin.0,
+        velocity_origin.0,
+        target_origin.0,
+        &springs.scale,
+    );
+    let (origin_y, origin_y_velocity) = step(
+        current_origin.1,
+        velocity_origin.1,
+        target_origin.1,
+        &springs.scale,
+    );
+
+    (
+        Transform {
+            x,
+            y,
+            scale,
+            rotation,
+            rotation_x,
+            rotation_y,
+            perspective,
+            ..current
+        }
+        .with_origin_offset(origin_x, origin_y),
+        Transform {
+            x: x_velocity,
+            y: y_velocity,
+            scale: scale_velocity,
+            rotation: rotation_velocity,
+            rotation_x: rotation_x_velocity,
+            rotation_y: rotation_y_velocity,
+            perspective: perspective_velocity,
+            ..velocity
+        }
+        .with_origin_offset(origin_x_velocity, origin_y_velocity),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
@@ -404,6 +1424,7 @@ mod tests {
     use crate::animations::core::AnimationMode;
     use crate::animations::spring::Spring;
     use crate::prelude::Tween;
+    use easer::functions::{Cubic, Easing};
     use std::sync::{Arc, Mutex};
 
     fn instant_tween() -> AnimationConfig {
@@ -458,6 +1479,77 @@ mod tests {
         assert!(motion.sequence.is_none());
     }
 
+    #[test]
+    fn test_motion_sequence_reports_step_tags_and_progress() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then_tagged("rise", 50.0f32, AnimationConfig::tween_ms(100))
+                .then(80.0, AnimationConfig::tween_ms(100))
+                .then_tagged("settle", 100.0, AnimationConfig::tween_ms(100)),
+        );
+
+        assert_eq!(motion.current_step_tag(), Some("rise"));
+        assert_eq!(motion.step_progress(), (0, 3));
+
+        let step = |motion: &Motion<f32>| {
+            let tag = motion.current_step_tag().map(str::to_owned);
+            (motion.step_progress().0, tag)
+        };
+        let mut seen = vec![step(&motion)];
+        while motion.update(1.0 / 60.0) {
+            let step = step(&motion);
+            if seen.last() != Some(&step) {
+                seen.push(step);
+            }
+        }
+        let expected = [(0, Some("rise")), (1, None), (2, Some("settle"))]
+            .map(|(index, tag)| (index, tag.map(str::to_owned)));
+        assert_eq!(seen, expected);
+
+        assert_eq!(motion.current_step_tag(), None);
+        assert_eq!(motion.step_progress(), (0, 0));
+    }
+
+    #[test]
+    fn test_motion_sequence_completion_future() {
+        use futures_util::FutureExt;
+
+        let mut motion = Motion::new(0.0f32);
+        let (sequence, completion) = AnimationSequence::new()
+            .then(50.0f32, instant_tween())
+            .then(100.0f32, instant_tween())
+            .with_completion_future();
+        let mut completion = Box::pin(completion);
+
+        motion.animate_sequence(sequence);
+        assert!((&mut completion).now_or_never().is_none());
+
+        assert!(motion.update(1.0 / 60.0));
+        assert!((&mut completion).now_or_never().is_none());
+
+        assert!(!motion.update(1.0 / 60.0));
+        assert_eq!(motion.current, 100.0);
+        assert!((&mut completion).now_or_never().is_some());
+    }
+
+    #[test]
+    fn test_motion_sequence_completion_future_resolves_on_interrupt() {
+        use futures_util::FutureExt;
+
+        let mut motion = Motion::new(0.0f32);
+        let (sequence, completion) = AnimationSequence::new()
+            .then(50.0f32, instant_tween())
+            .then(100.0f32, instant_tween())
+            .with_completion_future();
+        let mut completion = Box::pin(completion);
+
+        motion.animate_sequence(sequence);
+        motion.animate_to(10.0, instant_tween());
+
+        assert!((&mut completion).now_or_never().is_some());
+    }
+
     #[test]
     fn test_motion_keyframes_progress_and_complete() {
         let mut motion = Motion::new(0.0f32);
@@ -481,121 +1573,1574 @@ mod tests {
     }
 
     #[test]
-    fn test_motion_stop() {
+    fn test_keyframes_after_a_sped_up_tween_play_at_normal_speed() {
         let mut motion = Motion::new(0.0f32);
-        motion.animate_to(
-            100.0,
-            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        motion.animate_to(100.0, AnimationConfig::tween_ms(400).with_speed(4.0));
+        while motion.update(1.0 / 60.0) {}
+
+        motion.animate_keyframes(
+            KeyframeAnimation::new(Duration::from_secs(1))
+                .add_keyframe(100.0, 0.0, None)
+                .unwrap()
+                .add_keyframe(0.0, 1.0, None)
+                .unwrap(),
         );
+        let mut frames = 1;
+        while motion.update(1.0 / 60.0) {
+            frames += 1;
+        }
+        assert!((59..=61).contains(&frames), "took {frames} frames");
+    }
 
-        motion.stop();
+    #[test]
+    fn test_motion_seek_keyframes() {
+        let mut motion = Motion::new(0.0f32);
 
-        assert!(!motion.running);
-        assert!(motion.sequence.is_none());
-        assert!(motion.keyframe_animation.is_none());
-        assert_eq!(motion.velocity, 0.0);
+        let animation = KeyframeAnimation::new(Duration::from_secs(1))
+            .add_keyframe(0.0, 0.0, None)
+            .unwrap()
+            .add_keyframe(100.0, 1.0, None)
+            .unwrap();
+
+        motion.animate_keyframes(animation);
+        motion.running = false;
+
+        for (progress, expected) in [(0.25, 25.0), (0.5, 50.0), (0.75, 75.0)] {
+            motion.seek(progress).unwrap();
+            assert!((motion.current - expected).abs() < 0.001);
+            assert_eq!(motion.elapsed, Duration::from_secs(1).mul_f32(progress));
+            assert!(!motion.running);
+        }
     }
 
     #[test]
-    fn test_motion_get_epsilon() {
+    fn test_motion_seek_tween() {
         let mut motion = Motion::new(0.0f32);
-        assert_eq!(motion.get_epsilon(), f32::epsilon());
-
         motion.animate_to(
-            1.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween::default())).with_epsilon(0.01),
+            100.0,
+            AnimationConfig::tween(Duration::from_secs(2)).with_delay(Duration::from_secs(1)),
         );
 
-        assert_eq!(motion.get_epsilon(), 0.01);
+        motion.seek(0.5).unwrap();
+
+        assert!((motion.current - 50.0).abs() < 0.001);
+        assert_eq!(motion.elapsed, Duration::from_secs(1));
+        assert!(motion.running);
+
+        // Seeking skips the delay, so the next frame continues from the seek point.
+        assert!(motion.update(0.5));
+        assert!((motion.current - 75.0).abs() < 0.001);
     }
 
     #[test]
-    fn test_motion_delay_prevents_early_update() {
+    fn test_motion_seek_spring_is_rejected() {
         let mut motion = Motion::new(0.0f32);
         motion.animate_to(
             100.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween::default())),
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
         );
-        motion.delay(Duration::from_millis(100));
 
-        assert!(motion.update(1.0 / 60.0));
-        assert_eq!(motion.current, motion.initial);
+        assert_eq!(motion.seek(0.5), Err(SeekError::SpringNotSeekable));
+        assert_eq!(motion.current, 0.0);
     }
 
     #[test]
-    fn test_motion_update_tween_changes_value() {
+    fn test_get_value_at_matches_a_real_keyframe_run() {
+        let animation = KeyframeAnimation::new(Duration::from_secs(1))
+            .add_keyframe(0.0, 0.0, None)
+            .unwrap()
+            .add_keyframe(80.0, 0.4, Some(Cubic::ease_in_out))
+            .unwrap()
+            .add_keyframe(20.0, 1.0, None)
+            .unwrap();
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(animation);
+
+        let predicted: Vec<f32> = [0.25, 0.5, 0.75, 2.0]
+            .map(|secs| motion.get_value_at(Duration::from_secs_f32(secs)).unwrap())
+            .to_vec();
+        assert_eq!(motion.current, 0.0);
+        assert_eq!(motion.elapsed, Duration::ZERO);
+
+        for (index, expected) in predicted.into_iter().take(3).enumerate() {
+            for _ in 0..16 {
+                motion.update(1.0 / 64.0);
+            }
+            assert!(
+                (motion.current - expected).abs() < 1e-3,
+                "frame {index}: {} != {expected}",
+                motion.current
+            );
+        }
+        assert_eq!(motion.get_value_at(Duration::from_secs(2)).unwrap(), 20.0);
+    }
+
+    #[test]
+    fn test_spring_inherits_velocity_from_interrupted_tween() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::tween(Duration::from_secs(1)));
+        motion.step_n(1.0 / 64.0, 32);
+        let halfway = motion.current;
+        assert!((halfway - 50.0).abs() < 1e-3);
+
+        motion.animate_to(200.0, AnimationConfig::spring(Spring::default()));
+        assert_eq!(motion.current, halfway);
+        // A linear tween over 100 units in one second moves at 100 units per second.
+        assert!((motion.velocity - 100.0).abs() < 0.5);
+
+        let mut from_rest = Motion::new(halfway);
+        from_rest.animate_to(200.0, AnimationConfig::spring(Spring::default()));
+        motion.update(1.0 / 64.0);
+        from_rest.update(1.0 / 64.0);
+        assert!(motion.current > from_rest.current);
+    }
+
+    #[test]
+    fn test_step_n_matches_manual_updates() {
+        let config = AnimationConfig::spring(Spring::default());
+        let mut stepped = Motion::new(0.0f32);
+        stepped.animate_to(100.0, config.clone());
+        let mut looped = Motion::new(0.0f32);
+        looped.animate_to(100.0, config);
+
+        let value = stepped.step_n(1.0 / 60.0, 100);
+        for _ in 0..100 {
+            looped.update(1.0 / 60.0);
+        }
+        assert_eq!(value, looped.current);
+        assert_eq!(stepped.velocity, looped.velocity);
+
+        let mut tween = Motion::new(0.0f32);
+        tween.animate_to(100.0, AnimationConfig::tween_ms(100));
+        assert_eq!(tween.step_n(1.0 / 60.0, 1000), 100.0);
+        assert!(!tween.running);
+    }
+
+    #[test]
+    fn test_time_offset_starts_tween_part_way() {
         let mut motion = Motion::new(0.0f32);
         motion.animate_to(
             100.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween::default())),
+            AnimationConfig::tween(Duration::from_secs(1))
+                .with_time_offset(Duration::from_millis(300)),
         );
 
-        assert!(motion.update(1.0 / 60.0));
-        assert!(motion.current > 0.0);
-        assert!(motion.current < 100.0);
+        assert!((motion.get_value() - 30.0).abs() < 1e-3);
+        assert!(motion.update(0.2));
+        assert!((motion.get_value() - 50.0).abs() < 1e-3);
     }
 
     #[test]
-    fn test_motion_spring_completes_when_already_settled() {
-        let mut motion = Motion::new(0.0f32);
-        motion.animate_to(
-            0.0,
-            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+    fn test_time_offset_seeds_spring_mid_flight() {
+        let mut offset = Motion::new(0.0f32);
+        offset.animate_to(
+            100.0,
+            AnimationConfig::spring(Spring::default()).with_time_offset(Duration::from_millis(100)),
         );
-        motion.velocity = 0.0;
+        let mut played = Motion::new(0.0f32);
+        played.animate_to(100.0, AnimationConfig::spring(Spring::default()));
+        for _ in 0..20 {
+            played.update(0.005);
+        }
 
-        assert!(!motion.update(1.0 / 60.0));
-        assert_eq!(motion.current, 0.0);
-        assert!(!motion.running);
+        // The web integrator is semi-implicit Euler and runs slightly ahead of the
+        // closed-form solution the offset uses.
+        assert!((offset.current - played.current).abs() < 5.0);
+        assert!(offset.velocity > 0.0);
+        assert!((offset.velocity - played.velocity).abs() < 0.1 * played.velocity);
     }
 
     #[test]
-    fn test_motion_loop_mode_times() {
+    fn test_time_offset_keeps_handed_off_velocity() {
+        use easer::functions::{Easing, Linear};
+
+        let interrupted = || {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, AnimationConfig::tween_secs(0.2, Linear::ease_in_out));
+            motion.update(0.1);
+            motion
+        };
+
+        let mut offset = interrupted();
+        offset.animate_to(
+            100.0,
+            AnimationConfig::spring(Spring::default()).with_time_offset(Duration::from_millis(100)),
+        );
+        let mut played = interrupted();
+        played.animate_to(100.0, AnimationConfig::spring(Spring::default()));
+        assert!((played.velocity - 500.0).abs() < 1.0);
+        for _ in 0..20 {
+            played.update(0.005);
+        }
+
+        let mut from_rest = Motion::new(50.0f32);
+        from_rest.animate_to(
+            100.0,
+            AnimationConfig::spring(Spring::default()).with_time_offset(Duration::from_millis(100)),
+        );
+
+        assert!((offset.current - played.current).abs() < 5.0);
+        assert!((offset.current - from_rest.current).abs() > 20.0);
+        assert!((offset.velocity - played.velocity).abs() < 0.1 * played.velocity.abs());
+    }
+
+    #[test]
+    fn test_get_value_at_predicts_springs_forward_only() {
         let mut motion = Motion::new(0.0f32);
-        motion.animate_to(100.0, instant_tween().with_loop(LoopMode::Times(2)));
+        motion.animate_to(100.0, AnimationConfig::spring(Spring::default()));
+        for _ in 0..8 {
+            motion.update(1.0 / 64.0);
+        }
 
-        assert!(motion.update(1.0 / 60.0));
-        assert_eq!(motion.current, motion.initial);
-        assert!(motion.running);
+        let ahead = motion.elapsed + Duration::from_millis(250);
+        let predicted = motion.get_value_at(ahead).unwrap();
+        for _ in 0..16 {
+            motion.update(1.0 / 64.0);
+        }
+        // The web integrator is semi-implicit Euler, so it drifts slightly from the
+        // closed-form solution; native RK4 tracks it closely.
+        assert!(
+            (motion.current - predicted).abs() < 3.0,
+            "{} vs {predicted}",
+            motion.current
+        );
+        assert_eq!(
+            motion.get_value_at(Duration::ZERO),
+            Err(SeekError::SpringPredictionInPast)
+        );
+    }
+
+    #[test]
+    fn test_motion_replay_restarts_last_animation() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, instant_tween());
 
         assert!(!motion.update(1.0 / 60.0));
         assert!(!motion.running);
+        assert_eq!(motion.current, 100.0);
+
+        motion.replay();
+
+        assert!(motion.running);
+        assert_eq!(motion.initial, 0.0);
+        assert_eq!(motion.current, 0.0);
+        assert_eq!(motion.target, 100.0);
     }
 
     #[test]
-    fn test_motion_loop_mode_alternate() {
-        let mut motion = Motion::new(0.0f32);
-        motion.animate_to(100.0, instant_tween().with_loop(LoopMode::Alternate));
+    fn test_motion_replay_without_animation_is_noop() {
+        let mut motion = Motion::new(5.0f32);
+        motion.replay();
 
-        assert!(motion.update(1.0 / 60.0));
-        assert!(motion.running);
-        assert!(motion.reverse);
-        assert_eq!(motion.initial, 100.0);
-        assert_eq!(motion.target, 0.0);
+        assert!(!motion.running);
+        assert_eq!(motion.current, 5.0);
+        assert_eq!(motion.target, 5.0);
     }
 
     #[test]
-    fn test_motion_completion_callback() {
-        let called = Arc::new(Mutex::new(false));
-        let called_clone = called.clone();
-        let config = instant_tween().with_on_complete(move || {
-            *called_clone.lock().unwrap() = true;
-        });
+    fn test_motion_component_spring_settles_axes_independently() {
+        let stiff = Spring {
+            stiffness: 400.0,
+            damping: 40.0,
+            ..Spring::default()
+        };
+        let soft = Spring {
+            stiffness: 30.0,
+            damping: 10.0,
+            ..Spring::default()
+        };
+        let springs = TransformSprings {
+            x: stiff,
+            y: soft,
+            ..TransformSprings::default()
+        };
 
-        let mut motion = Motion::new(0.0f32);
-        motion.animate_to(100.0, config);
+        let mut motion = Motion::new(Transform::identity());
+        motion.animate_to(
+            Transform::new(100.0, 100.0, 1.0, 0.0),
+            AnimationConfig::new(AnimationMode::ComponentSpring(springs)),
+        );
 
-        assert!(!motion.update(1.0 / 60.0));
-        assert!(*called.lock().unwrap());
+        let mut x_settled_at = None;
+        let mut y_settled_at = None;
+        for frame in 0..600 {
+            motion.update(1.0 / 60.0);
+            let current = motion.current;
+            if x_settled_at.is_none() && (current.x - 100.0).abs() < 0.5 {
+                x_settled_at = Some(frame);
+            }
+            if y_settled_at.is_none() && (current.y - 100.0).abs() < 0.5 {
+                y_settled_at = Some(frame);
+            }
+        }
+
+        assert!(x_settled_at.unwrap() < y_settled_at.unwrap());
+        assert!(!motion.running);
     }
 
     #[test]
-    fn test_motion_get_value_tracks_current_directly() {
+    fn test_motion_stop() {
         let mut motion = Motion::new(0.0f32);
-        motion.current = 12.5;
-        assert_eq!(motion.get_value(), 12.5);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
 
-        motion.current = 42.0;
-        assert_eq!(motion.get_value(), 42.0);
+        motion.stop();
+
+        assert!(!motion.running);
+        assert!(motion.sequence.is_none());
+        assert!(motion.keyframe_animation.is_none());
+        assert_eq!(motion.velocity, 0.0);
+    }
+
+    #[test]
+    fn test_motion_get_epsilon() {
+        let mut motion = Motion::new(0.0f32);
+        assert_eq!(motion.get_epsilon(), f32::epsilon());
+
+        motion.animate_to(
+            1.0,
+            AnimationConfig::new(AnimationMode::Tween(Tween::default())).with_epsilon(0.01),
+        );
+
+        assert_eq!(motion.get_epsilon(), 0.01);
+    }
+
+    #[test]
+    fn test_motion_delay_prevents_early_update() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Tween(Tween::default())),
+        );
+        motion.delay(Duration::from_millis(100));
+
+        assert!(motion.update(1.0 / 60.0));
+        assert_eq!(motion.current, motion.initial);
+    }
+
+    #[test]
+    fn test_just_completed_is_true_for_exactly_one_update() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(100));
+
+        let mut completions = 0;
+        for _ in 0..20 {
+            motion.update(1.0 / 60.0);
+            completions += usize::from(motion.just_completed());
+        }
+        assert_eq!(completions, 1);
+        assert!(!motion.just_completed(), "cleared by the next update");
+
+        motion.animate_to(0.0, AnimationConfig::tween_ms(100));
+        motion.update(1.0 / 60.0);
+        motion.stop();
+        motion.update(1.0 / 60.0);
+        assert!(!motion.just_completed(), "stopping is not completing");
+    }
+
+    #[test]
+    fn test_motion_delay_phases() {
+        let mut motion = Motion::new(0.0f32);
+        assert!(!motion.is_running() && !motion.is_delayed() && !motion.has_started());
+
+        motion.animate_to(
+            100.0,
+            AnimationConfig::tween_ms(100).with_delay(Duration::from_millis(50)),
+        );
+        assert!(motion.is_running() && motion.is_delayed() && !motion.has_started());
+        motion.update(0.03);
+        assert!(motion.is_delayed());
+        assert_eq!(motion.get_value(), 0.0);
+
+        motion.update(0.03);
+        assert!(!motion.is_delayed() && motion.has_started());
+        motion.update(0.03);
+        assert!(motion.get_value() > 0.0);
+
+        motion.pause();
+        assert!(!motion.is_delayed() && !motion.has_started());
+        motion.resume();
+        while motion.update(0.03) {}
+        assert!(!motion.is_running() && !motion.is_delayed() && !motion.has_started());
+    }
+
+    #[test]
+    fn test_tween_speed_scales_duration_with_distance() {
+        use easer::functions::{Easing, Linear};
+
+        let config = AnimationConfig::tween_speed(200.0, Linear::ease_in_out);
+        let mut short = Motion::new(0.0f32);
+        let mut long = Motion::new(50.0f32);
+        short.animate_to(100.0, config.clone());
+        long.animate_to(-250.0, config.clone());
+
+        assert_eq!(short.remaining(), Some(Duration::from_millis(500)));
+        assert_eq!(long.remaining(), Some(Duration::from_millis(1500)));
+        while long.update(1.0 / 60.0) {}
+        assert_eq!(long.get_value(), -250.0);
+
+        // Staying put takes no time at all.
+        short.animate_to(short.get_value(), config);
+        assert_eq!(short.remaining(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_hue_cycle_turns_around_the_whole_wheel() {
+        let mut motion = Motion::new(Color::BLACK);
+        motion.animate_hue_cycle(1.0, 0.5, Duration::from_secs(1), LoopMode::Infinite);
+
+        let mut hues = Vec::new();
+        for _ in 0..12 {
+            motion.update(1.0 / 12.0);
+            let (hue, saturation, _) = motion.get_value().to_hsl();
+            assert!(saturation > 0.9, "stays vivid: {saturation}");
+            hues.push(hue);
+        }
+
+        // Each sample moves forward about 30°, so every sextant of the wheel is visited.
+        for pair in hues.windows(2) {
+            let step = (pair[1] - pair[0]).rem_euclid(360.0);
+            assert!((step - 30.0).abs() < 5.0, "{hues:?}");
+        }
+        let mut sextants: Vec<u8> = hues.iter().map(|hue| (hue / 60.0) as u8 % 6).collect();
+        sextants.dedup();
+        sextants.sort_unstable();
+        sextants.dedup();
+        assert_eq!(sextants, [0, 1, 2, 3, 4, 5]);
+        assert!(motion.is_running(), "infinite cycles keep going");
+    }
+
+    fn frames_to_complete(config: AnimationConfig) -> usize {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, config);
+
+        let mut frames = 0;
+        while motion.update(1.0 / 64.0) {
+            frames += 1;
+            assert!(frames < 1000, "animation never completed");
+        }
+        frames + 1
+    }
+
+    #[test]
+    fn test_motion_speed_scales_tween_duration() {
+        let base = frames_to_complete(AnimationConfig::tween_ms(500));
+
+        assert_eq!(base, 32);
+        assert_eq!(
+            frames_to_complete(AnimationConfig::tween_ms(500).with_speed(2.0)),
+            base / 2
+        );
+        assert_eq!(
+            frames_to_complete(AnimationConfig::tween_ms(500).with_speed(0.5)),
+            base * 2
+        );
+    }
+
+    #[test]
+    fn test_motion_speed_does_not_scale_delay() {
+        let delayed = frames_to_complete(
+            AnimationConfig::tween_ms(500)
+                .with_delay(Duration::from_millis(250))
+                .with_speed(2.0),
+        );
+
+        assert_eq!(delayed, 16 + 16);
+    }
+
+    #[test]
+    fn test_motion_speed_scales_spring_time_step() {
+        let spring = AnimationConfig::spring(Spring::default());
+        let base = frames_to_complete(spring.clone());
+        let fast = frames_to_complete(spring.with_speed(2.0));
+
+        assert!(fast < base);
+    }
+
+    #[test]
+    fn test_motion_retarget_preserves_tween_progress() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(500));
+
+        let mut frames = 0;
+        while frames < 22 {
+            assert!(motion.update(1.0 / 64.0));
+            frames += 1;
+        }
+        let elapsed = motion.elapsed;
+        let current = motion.current;
+
+        motion.retarget(200.0);
+
+        assert_eq!(motion.initial, current);
+        assert_eq!(motion.target, 200.0);
+        assert_eq!(motion.elapsed, elapsed);
+
+        while motion.update(1.0 / 64.0) {
+            frames += 1;
+        }
+        assert_eq!(
+            frames + 1,
+            frames_to_complete(AnimationConfig::tween_ms(500))
+        );
+        assert_eq!(motion.current, 200.0);
+    }
+
+    #[test]
+    fn test_motion_retarget_resolves_tween_speed_for_the_new_distance() {
+        use easer::functions::{Easing, Linear};
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::tween_speed(100.0, Linear::ease_in_out),
+        );
+        for _ in 0..32 {
+            motion.update(1.0 / 64.0);
+        }
+        assert_eq!(motion.current, 50.0);
+
+        // 200 units from 50 to 250 at 100 units per second, halfway through already
+        motion.retarget(250.0);
+        assert_eq!(motion.config.get_duration(), Duration::from_secs(2));
+        assert_eq!(motion.elapsed, Duration::from_secs(1));
+
+        let mut frames = 0;
+        while motion.update(1.0 / 64.0) {
+            frames += 1;
+        }
+        assert_eq!(frames + 1, 64);
+        assert_eq!(motion.current, 250.0);
+    }
+
+    #[test]
+    fn test_motion_retarget_when_idle_starts_animation() {
+        let mut motion = Motion::new(0.0f32);
+
+        motion.retarget(50.0);
+
+        assert!(motion.running);
+        assert_eq!(motion.initial, 0.0);
+        assert_eq!(motion.target, 50.0);
+    }
+
+    #[test]
+    fn test_motion_retarget_when_idle_does_not_rerun_callbacks() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let completions = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&completions);
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::tween_ms(100).with_on_complete(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }),
+        );
+        while motion.update(1.0 / 60.0) {}
+        assert_eq!(completions.load(Ordering::SeqCst), 1);
+
+        motion.retarget(50.0);
+        while motion.update(1.0 / 60.0) {}
+
+        assert_eq!(completions.load(Ordering::SeqCst), 1);
+        assert_eq!(motion.get_value(), 50.0);
+    }
+
+    #[test]
+    fn test_motion_animate_to_default_uses_default_config() {
+        let mut motion = Motion::new(0.0f32).with_default_config(AnimationConfig::tween_ms(500));
+
+        motion.animate_to_default(100.0);
+
+        assert_eq!(motion.target, 100.0);
+        assert!(matches!(
+            motion.config.mode,
+            AnimationMode::Tween(Tween { duration, .. }) if duration == Duration::from_millis(500)
+        ));
+        assert_eq!(frames_to_complete((*motion.config).clone()), 32);
+    }
+
+    #[test]
+    fn test_motion_update_tween_changes_value() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Tween(Tween::default())),
+        );
+
+        assert!(motion.update(1.0 / 60.0));
+        assert!(motion.current > 0.0);
+        assert!(motion.current < 100.0);
+    }
+
+    #[test]
+    fn test_motion_spring_completes_when_already_settled() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            0.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        motion.velocity = 0.0;
+
+        assert!(!motion.update(1.0 / 60.0));
+        assert_eq!(motion.current, 0.0);
+        assert!(!motion.running);
+    }
+
+    #[test]
+    fn test_transform_spring_settles_per_component() {
+        use crate::animations::transform::Transform;
+
+        let target = Transform::new(200.0, 0.0, 1.0, std::f32::consts::FRAC_PI_2);
+        let zero = Transform::new(0.0, 0.0, 0.0, 0.0);
+        let settle_from = |offset: Transform| {
+            let mut motion = Motion::new(Transform::identity());
+            motion.animate_to(target, AnimationConfig::spring(Spring::default()));
+            motion.current = target + offset;
+            motion.velocity = zero;
+            motion.update(1.0 / 60.0)
+        };
+
+        // A 0.005 rad rotation is under the old whole-value epsilon but still visible.
+        let rotation_off = Transform {
+            rotation: 0.005,
+            ..zero
+        };
+        assert!(rotation_off.magnitude() < Transform::epsilon());
+        assert!(
+            settle_from(rotation_off),
+            "rotation should keep the spring running"
+        );
+
+        // Sub-0.01px offsets on both axes exceed the old epsilon combined, yet each is done.
+        let position_off = Transform {
+            x: 0.009,
+            y: 0.009,
+            ..zero
+        };
+        assert!(position_off.magnitude() > Transform::epsilon());
+        assert!(
+            !settle_from(position_off),
+            "position is within its own epsilon"
+        );
+
+        let mut motion = Motion::new(Transform::identity());
+        motion.animate_to(target, AnimationConfig::spring(Spring::default()));
+        while motion.update(1.0 / 60.0) {}
+        assert_eq!(motion.get_value(), target);
+    }
+
+    #[test]
+    fn test_on_loop_fires_on_each_restart() {
+        let loops = Arc::new(Mutex::new(Vec::new()));
+        let completions = Arc::new(Mutex::new(0));
+        let (loop_log, completion_count) = (Arc::clone(&loops), Arc::clone(&completions));
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::tween_ms(100)
+                .with_loop(LoopMode::Times(3))
+                .with_on_loop(move |index| loop_log.lock().expect("loop log").push(index))
+                .with_on_complete(move || *completion_count.lock().expect("count") += 1),
+        );
+        while motion.update(1.0 / 60.0) {}
+
+        assert_eq!(*loops.lock().expect("loop log"), [1, 2]);
+        assert_eq!(*completions.lock().expect("count"), 1);
+    }
+
+    #[test]
+    fn test_animate_along_path_visits_waypoints_in_order() {
+        let waypoints = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(100.0, 0.0),
+            Vec2::new(100.0, 100.0),
+            Vec2::new(0.0, 100.0),
+            Vec2::new(0.0, 0.0),
+        ];
+        let mut motion = Motion::new(Vec2::ZERO);
+        motion.animate_along_path(&waypoints, AnimationConfig::tween_ms(2000));
+
+        let mut next = 1;
+        while motion.update(1.0 / 120.0) {
+            if next < waypoints.len() && (motion.get_value() - waypoints[next]).length() < 2.0 {
+                next += 1;
+            }
+        }
+        assert_eq!(next, waypoints.len(), "stopped before waypoint {next}");
+        assert_eq!(motion.get_value(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_animate_along_path_loops_as_an_orbit() {
+        let waypoints = [
+            Vec2::new(50.0, 0.0),
+            Vec2::new(0.0, 50.0),
+            Vec2::new(-50.0, 0.0),
+            Vec2::new(0.0, -50.0),
+            Vec2::new(50.0, 0.0),
+        ];
+        let mut motion = Motion::new(Vec2::ZERO);
+        motion.animate_along_path(
+            &waypoints,
+            AnimationConfig::tween_ms(400).with_loop(LoopMode::Times(3)),
+        );
+
+        let mut frames = 0;
+        while motion.update(1.0 / 60.0) {
+            frames += 1;
+        }
+        assert!(frames >= 3 * 24 - 3, "only ran {frames} frames");
+        assert_eq!(motion.get_value(), Vec2::new(50.0, 0.0));
+    }
+
+    #[test]
+    fn test_default_epsilon_sits_between_config_and_type() {
+        let mut motion = Motion::new(0.0f32);
+        assert_eq!(motion.get_epsilon(), f32::epsilon());
+
+        motion.set_default_epsilon(0.001);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(100));
+        assert_eq!(motion.get_epsilon(), 0.001);
+
+        motion.animate_to(0.0, AnimationConfig::tween_ms(100).with_epsilon(0.05));
+        assert_eq!(motion.get_epsilon(), 0.05);
+
+        motion.animate_to(50.0, AnimationConfig::spring(Spring::default()));
+        assert_eq!(motion.get_epsilon(), 0.001);
+    }
+
+    #[test]
+    fn test_global_epsilon_sits_between_motion_default_and_type() {
+        use crate::resource_pools;
+
+        resource_pools::reset();
+        resource_pools::set_global_epsilon(0.002);
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::spring(Spring::default()));
+        assert_eq!(motion.get_epsilon(), 0.002);
+        let mut transform = Motion::new(Transform::default());
+        transform.animate_to(Transform::default(), AnimationConfig::tween_ms(100));
+        assert_eq!(transform.get_epsilon(), 0.002);
+
+        motion.animate_to(0.0, AnimationConfig::tween_ms(100).with_epsilon(0.05));
+        assert_eq!(motion.get_epsilon(), 0.05);
+        motion.set_default_epsilon(0.001);
+        motion.animate_to(50.0, AnimationConfig::tween_ms(100));
+        assert_eq!(motion.get_epsilon(), 0.001);
+
+        // Springs settle within the global epsilon.
+        let mut spring = Motion::new(0.0f32);
+        spring.animate_to(100.0, AnimationConfig::spring(Spring::default()));
+        let mut frames = 0;
+        while spring.update(1.0 / 60.0) {
+            frames += 1;
+            assert!(frames < 2000, "spring should settle");
+        }
+        assert_eq!(spring.get_value(), 100.0);
+
+        resource_pools::clear_global_epsilon();
+        assert_eq!(spring.get_epsilon(), f32::epsilon());
+        resource_pools::reset();
+    }
+
+    #[test]
+    fn test_lower_priority_animate_to_is_ignored_while_running() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(500).with_priority(2));
+        motion.update(1.0 / 60.0);
+
+        motion.animate_to(-50.0, AnimationConfig::tween_ms(100).with_priority(1));
+        assert_eq!(motion.target, 100.0);
+        motion.animate_to(-50.0, AnimationConfig::tween_ms(100));
+        assert_eq!(motion.target, 100.0);
+
+        while motion.update(1.0 / 60.0) {}
+        assert_eq!(motion.get_value(), 100.0);
+
+        motion.animate_to(0.0, AnimationConfig::tween_ms(100));
+        assert_eq!(motion.target, 0.0, "a finished animation no longer blocks");
+        motion.animate_to(50.0, AnimationConfig::tween_ms(100));
+        assert_eq!(motion.target, 50.0, "equal priorities keep last-wins");
+    }
+
+    #[test]
+    fn test_priority_guards_every_way_to_start_an_animation() {
+        let keyframes = || {
+            KeyframeAnimation::new(Duration::from_secs(1))
+                .add_keyframe(0.0f32, 0.0, None)
+                .unwrap()
+                .add_keyframe(50.0, 1.0, None)
+                .unwrap()
+        };
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(500).with_priority(2));
+        motion.update(1.0 / 60.0);
+
+        motion.animate_keyframes(keyframes());
+        motion.animate_sequence(
+            AnimationSequence::new().then(-50.0, AnimationConfig::tween_ms(100).with_priority(1)),
+        );
+        motion.follow(|| -50.0, Spring::default());
+        assert!(motion.keyframe_animation.is_none());
+        assert!(motion.sequence.is_none());
+        assert!(motion.follow_target().is_none());
+        assert_eq!(motion.target, 100.0);
+
+        // Paused and halted animations can resume, so they keep their priority
+        motion.pause();
+        motion.animate_to(-50.0, AnimationConfig::tween_ms(100));
+        assert_eq!(motion.target, 100.0);
+        motion.resume();
+        motion.halt();
+        motion.animate_keyframes(keyframes());
+        assert!(motion.keyframe_animation.is_none());
+
+        motion.animate_sequence(
+            AnimationSequence::new().then(-50.0, AnimationConfig::tween_ms(100).with_priority(2)),
+        );
+        assert_eq!(motion.target, -50.0, "equal priority replaces");
+
+        let mut path = Motion::new(Vec2::ZERO);
+        path.animate_to(
+            Vec2::new(1.0, 1.0),
+            AnimationConfig::tween_ms(500).with_priority(1),
+        );
+        let points = [Vec2::ZERO, Vec2::new(10.0, 0.0)];
+        path.animate_along_path(&points, AnimationConfig::tween_ms(500));
+        assert!(path.keyframe_animation.is_none());
+        path.animate_along_path(&points, AnimationConfig::tween_ms(500).with_priority(1));
+        assert!(path.keyframe_animation.is_some());
+    }
+
+    #[test]
+    fn test_keyframes_do_not_inherit_a_finished_animations_priority() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(100).with_priority(5));
+        while motion.update(1.0 / 60.0) {}
+
+        motion.animate_keyframes(
+            KeyframeAnimation::new(Duration::from_secs(1))
+                .add_keyframe(0.0, 0.0, None)
+                .unwrap()
+                .add_keyframe(50.0, 1.0, None)
+                .unwrap(),
+        );
+        motion.update(1.0 / 60.0);
+
+        motion.animate_to(10.0, AnimationConfig::tween_ms(100));
+        assert_eq!(motion.target, 10.0);
+        assert!(motion.keyframe_animation.is_none());
+    }
+
+    #[test]
+    fn test_max_bounces_settles_after_the_cap() {
+        let bouncy = Spring {
+            stiffness: 200.0,
+            damping: 2.0,
+            ..Spring::default()
+        };
+        let run = |config: AnimationConfig| {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, config);
+            let (mut reversals, mut frames) = (0, 0);
+            let mut previous = motion.velocity;
+            while motion.update(1.0 / 60.0) {
+                if previous * motion.velocity < 0.0 {
+                    reversals += 1;
+                }
+                previous = motion.velocity;
+                frames += 1;
+                assert!(frames < 5000, "spring should settle");
+            }
+            assert_eq!(motion.get_value(), 100.0);
+            (reversals, frames)
+        };
+
+        let (free_reversals, free_frames) = run(AnimationConfig::spring(bouncy));
+        let (capped_reversals, capped_frames) = run(bouncy.with_max_bounces(2));
+        assert!(
+            free_reversals > 2,
+            "test spring should ring: {free_reversals}"
+        );
+        assert_eq!(capped_reversals, 2);
+        assert!(capped_frames < free_frames / 2);
+    }
+
+    #[test]
+    fn test_motion_loop_mode_times() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, instant_tween().with_loop(LoopMode::Times(2)));
+
+        assert!(motion.update(1.0 / 60.0));
+        assert_eq!(motion.current, motion.initial);
+        assert!(motion.running);
+
+        assert!(!motion.update(1.0 / 60.0));
+        assert!(!motion.running);
+    }
+
+    #[test]
+    fn test_motion_loop_counts_of_zero_and_one_play_once() {
+        for loop_mode in [
+            LoopMode::Times(0),
+            LoopMode::Times(1),
+            LoopMode::AlternateTimes(0),
+        ] {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, instant_tween().with_loop(loop_mode));
+
+            assert!(!motion.update(1.0 / 60.0), "{loop_mode:?} should stop");
+            assert_eq!(motion.current, 100.0);
+            assert!(!motion.reverse);
+            assert_eq!(
+                AnimationConfig::tween_ms(100)
+                    .with_loop(loop_mode)
+                    .get_duration(),
+                Duration::from_millis(100)
+            );
+        }
+    }
+
+    #[test]
+    fn test_motion_loop_mode_alternate_times_ends_at_start() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            instant_tween().with_loop(LoopMode::AlternateTimes(1)),
+        );
+
+        assert!(motion.update(1.0 / 60.0));
+        assert!(motion.reverse);
+        assert!(!motion.update(1.0 / 60.0));
+        assert_eq!(motion.current, 0.0);
+    }
+
+    #[test]
+    fn test_motion_loop_mode_alternate() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, instant_tween().with_loop(LoopMode::Alternate));
+
+        assert!(motion.update(1.0 / 60.0));
+        assert!(motion.running);
+        assert!(motion.reverse);
+        assert_eq!(motion.initial, 100.0);
+        assert_eq!(motion.target, 0.0);
+    }
+
+    #[test]
+    fn test_motion_loop_mode_ping_pong_uses_backward_mode() {
+        use easer::functions::{Easing, Linear, Quad};
+
+        let forward = AnimationMode::Tween(Tween {
+            duration: Duration::from_millis(500),
+            easing: Linear::ease_in_out,
+        });
+        let backward = AnimationMode::Tween(Tween {
+            duration: Duration::from_millis(500),
+            easing: Quad::ease_in,
+        });
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(forward).with_loop(LoopMode::PingPong { forward, backward }),
+        );
+
+        for _ in 0..16 {
+            assert!(motion.update(1.0 / 64.0));
+        }
+        assert!((motion.current - 50.0).abs() < 1e-3);
+
+        for _ in 0..16 {
+            assert!(motion.update(1.0 / 64.0));
+        }
+        assert!(motion.reverse);
+        assert_eq!(motion.initial, 100.0);
+        assert_eq!(motion.target, 0.0);
+
+        for _ in 0..16 {
+            assert!(motion.update(1.0 / 64.0));
+        }
+        // Quad ease-in covers a quarter of the distance by the halfway point.
+        assert!((motion.current - 75.0).abs() < 1e-3);
+
+        for _ in 0..16 {
+            assert!(motion.update(1.0 / 64.0));
+        }
+        assert!(!motion.reverse);
+        assert_eq!(motion.initial, 0.0);
+    }
+
+    #[test]
+    fn test_motion_loop_jitter_is_deterministic_with_seed() {
+        let config = AnimationConfig::tween_ms(500)
+            .with_loop(LoopMode::Infinite)
+            .with_loop_jitter(0.25, Some(42));
+        let loop_scales = || {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, config.clone());
+            (0..8)
+                .map(|_| {
+                    motion.handle_completion();
+                    motion.loop_scale
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let scales = loop_scales();
+
+        assert_eq!(scales, loop_scales());
+        assert!(scales.iter().all(|scale| (0.75..=1.25).contains(scale)));
+        assert!(scales.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn test_motion_loop_jitter_scales_repetition_duration() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::tween_ms(500)
+                .with_loop(LoopMode::Infinite)
+                .with_loop_jitter(0.5, Some(3)),
+        );
+        for _ in 0..32 {
+            motion.update(1.0 / 64.0);
+        }
+        let scale = motion.loop_scale;
+        assert_ne!(scale, 1.0);
+
+        let mut frames = 0;
+        loop {
+            motion.update(1.0 / 64.0);
+            frames += 1;
+            if motion.elapsed == Duration::default() {
+                break;
+            }
+            assert!(frames < 1000, "loop never repeated");
+        }
+
+        assert!((frames as f32 - 32.0 * scale).abs() <= 1.0);
+    }
+
+    #[test]
+    fn test_keyframes_after_a_jittered_loop_play_unscaled() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::tween_ms(500)
+                .with_loop(LoopMode::Infinite)
+                .with_loop_jitter(0.5, Some(3)),
+        );
+        while motion.loop_scale == 1.0 {
+            assert!(motion.update(1.0 / 64.0));
+        }
+
+        motion.animate_keyframes(
+            KeyframeAnimation::new(Duration::from_secs(1))
+                .add_keyframe(0.0, 0.0, None)
+                .unwrap()
+                .add_keyframe(100.0, 1.0, None)
+                .unwrap(),
+        );
+        let mut frames = 1;
+        while motion.update(1.0 / 64.0) {
+            frames += 1;
+        }
+        assert!((63..=65).contains(&frames), "took {frames} frames");
+    }
+
+    #[test]
+    fn test_motion_completion_callback() {
+        let called = Arc::new(Mutex::new(false));
+        let called_clone = called.clone();
+        let config = instant_tween().with_on_complete(move || {
+            *called_clone.lock().unwrap() = true;
+        });
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, config);
+
+        assert!(!motion.update(1.0 / 60.0));
+        assert!(*called.lock().unwrap());
+    }
+
+    #[test]
+    fn test_motion_get_value_tracks_current_directly() {
+        let mut motion = Motion::new(0.0f32);
+        motion.current = 12.5;
+        assert_eq!(motion.get_value(), 12.5);
+
+        motion.current = 42.0;
+        assert_eq!(motion.get_value(), 42.0);
+    }
+
+    #[test]
+    fn test_motion_animate_by_accumulates_in_flight() {
+        let mut motion = Motion::new(5.0f32);
+        motion.animate_by(10.0, AnimationConfig::tween_ms(300));
+        motion.update(1.0 / 60.0);
+        motion.animate_by(10.0, AnimationConfig::tween_ms(300));
+
+        assert_eq!(motion.target, 25.0);
+
+        while motion.update(1.0 / 60.0) {}
+        motion.animate_by(-5.0, instant_tween());
+        assert_eq!(motion.target, 20.0);
+    }
+
+    #[test]
+    fn test_motion_on_cancel_runs_only_when_stopped_early() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let cancelled = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&cancelled);
+        let config = AnimationConfig::tween_ms(100).with_on_cancel(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let mut motion = Motion::new(0.0f32);
+
+        motion.animate_to(100.0, config.clone());
+        while motion.update(1.0 / 60.0) {}
+        motion.stop();
+        assert_eq!(cancelled.load(Ordering::SeqCst), 0);
+
+        motion.animate_to(0.0, config.clone());
+        motion.update(1.0 / 60.0);
+        motion.stop();
+        motion.stop();
+        assert_eq!(cancelled.load(Ordering::SeqCst), 1);
+
+        motion.animate_to(100.0, config.clone());
+        motion.pause();
+        motion.reset();
+        assert_eq!(cancelled.load(Ordering::SeqCst), 2);
+
+        motion.animate_to(100.0, config.with_delay(Duration::from_millis(500)));
+        motion.update(1.0 / 60.0);
+        assert!(motion.cancel_delay());
+        assert_eq!(cancelled.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_motion_cancel_delay() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::tween_ms(200).with_delay(Duration::from_millis(500)),
+        );
+        motion.update(1.0 / 60.0);
+
+        assert!(motion.cancel_delay());
+        assert!(!motion.is_running());
+        assert!(!motion.update(1.0 / 60.0));
+        assert_eq!(motion.get_value(), 0.0);
+        assert_eq!(motion.target, 0.0);
+
+        motion.animate_to(100.0, AnimationConfig::tween_ms(200));
+        motion.update(1.0 / 60.0);
+        assert!(!motion.cancel_delay());
+        assert!(motion.is_running());
+
+        let delayed = AnimationConfig::tween_ms(200).with_delay(Duration::from_millis(500));
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then_tagged("in", 50.0, delayed.clone())
+                .then(0.0, delayed),
+        );
+        motion.update(1.0 / 60.0);
+        assert!(motion.cancel_delay());
+        assert!(motion.sequence.is_none());
+        assert_eq!(motion.step_progress(), (0, 0));
+        assert_eq!(motion.current_step_tag(), None);
+
+        motion.animate_keyframes(
+            KeyframeAnimation::new(Duration::from_secs(1))
+                .add_keyframe(0.0, 0.0, None)
+                .unwrap()
+                .add_keyframe(100.0, 1.0, None)
+                .unwrap(),
+        );
+        motion.delay(Duration::from_millis(500));
+        motion.update(1.0 / 60.0);
+        assert!(motion.cancel_delay());
+        assert!(motion.keyframe_animation.is_none());
+        assert_eq!(motion.velocity, 0.0);
+        assert!(!motion.update(1.0 / 60.0));
+    }
+
+    fn frames_until_done(motion: &mut Motion<f32>) -> u32 {
+        let mut frames = 0;
+        while motion.update(1.0 / 60.0) {
+            frames += 1;
+            assert!(frames < 1000, "animation never finished");
+        }
+        frames + 1
+    }
+
+    #[test]
+    fn test_motion_min_duration_slows_tiny_spring() {
+        let mut unfloored = Motion::new(0.0f32);
+        unfloored.animate_to(0.02, AnimationConfig::spring(Spring::default()));
+        let unfloored_frames = frames_until_done(&mut unfloored);
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            0.02,
+            AnimationConfig::spring(Spring::default())
+                .with_min_duration(Duration::from_millis(300)),
+        );
+        motion.update(1.0 / 60.0);
+        assert!(motion.get_value() < 0.02 * 0.1);
+
+        let frames = frames_until_done(&mut motion) + 1;
+        assert!(frames >= 18);
+        assert!(frames > unfloored_frames);
+        assert_eq!(motion.get_value(), 0.02);
+    }
+
+    #[test]
+    fn test_motion_min_duration_keeps_spring_velocity_in_pace() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::spring(Spring {
+                stiffness: 1000.0,
+                damping: 64.0,
+                ..Spring::default()
+            })
+            .with_min_duration(Duration::from_secs(1)),
+        );
+
+        let mut peak = 0.0f32;
+        while motion.update(1.0 / 60.0) {
+            if motion.elapsed < Duration::from_secs(1) {
+                assert!(motion.velocity.abs() <= 100.0 + 1e-3, "{}", motion.velocity);
+            }
+            peak = peak.max(motion.current);
+        }
+        assert!(peak < 100.5, "overshot to {peak}");
+        assert_eq!(motion.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_motion_min_duration_stretches_short_tween() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            1.0,
+            AnimationConfig::tween_ms(50).with_min_duration(Duration::from_millis(300)),
+        );
+
+        assert!(frames_until_done(&mut motion) >= 18);
+        assert_eq!(motion.get_value(), 1.0);
+
+        let mut long = Motion::new(0.0f32);
+        long.animate_to(
+            1.0,
+            AnimationConfig::tween_ms(497).with_min_duration(Duration::from_millis(300)),
+        );
+        assert_eq!(frames_until_done(&mut long), 30);
+    }
+
+    #[test]
+    fn test_motion_chain_starts_follow_up() {
+        let called = Arc::new(Mutex::new(false));
+        let called_clone = called.clone();
+        let config = AnimationConfig::tween_ms(100)
+            .with_on_complete(move || {
+                *called_clone.lock().unwrap() = true;
+            })
+            .chain(100.0f32, AnimationConfig::tween_ms(200));
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(120.0, config);
+
+        let mut frames = 0;
+        while motion.target == 120.0 {
+            assert!(motion.update(1.0 / 60.0));
+            frames += 1;
+            assert!(frames < 100, "first phase never completed");
+        }
+
+        assert!(*called.lock().unwrap());
+        assert_eq!(motion.target, 100.0);
+        assert_eq!(motion.initial, 120.0);
+        assert!(motion.is_running());
+
+        while motion.update(1.0 / 60.0) {}
+        assert_eq!(motion.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_motion_chain_ignores_mismatched_type() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            1.0,
+            instant_tween().chain(
+                crate::prelude::Color::new(1.0, 0.0, 0.0, 1.0),
+                instant_tween(),
+            ),
+        );
+
+        assert!(!motion.update(1.0 / 60.0));
+        assert_eq!(motion.get_value(), 1.0);
+    }
+
+    #[test]
+    fn test_motion_pause_resume_keeps_spring_velocity() {
+        let config = AnimationConfig::spring(Spring::default());
+        let mut paused = Motion::new(0.0f32);
+        paused.animate_to(100.0, config.clone());
+        let mut reference = Motion::new(0.0f32);
+        reference.animate_to(100.0, config);
+
+        for _ in 0..5 {
+            paused.update(1.0 / 60.0);
+            reference.update(1.0 / 60.0);
+        }
+
+        paused.pause();
+        let (value, velocity) = (paused.get_value(), paused.velocity);
+        assert!(paused.is_paused());
+        assert!(!paused.is_running());
+        assert!(velocity > 0.0);
+
+        for _ in 0..30 {
+            assert!(!paused.update(1.0 / 60.0));
+        }
+        assert_eq!(paused.get_value(), value);
+        assert_eq!(paused.velocity, velocity);
+
+        paused.resume();
+        assert!(paused.is_running());
+        assert!(!paused.is_paused());
+
+        paused.update(1.0 / 60.0);
+        reference.update(1.0 / 60.0);
+        assert_eq!(paused.get_value(), reference.get_value());
+        assert_eq!(paused.velocity, reference.velocity);
+    }
+
+    #[test]
+    fn test_motion_pause_keeps_sequence_step() {
+        let sequence = AnimationSequence::new()
+            .then(10.0f32, instant_tween())
+            .then(20.0, AnimationConfig::tween_ms(200));
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(sequence);
+        motion.update(1.0 / 60.0);
+        motion.update(1.0 / 60.0);
+        let step = motion
+            .sequence
+            .as_ref()
+            .map(|sequence| sequence.current_step());
+
+        motion.pause();
+        motion.update(1.0 / 60.0);
+        motion.resume();
+
+        assert_eq!(
+            motion
+                .sequence
+                .as_ref()
+                .map(|sequence| sequence.current_step()),
+            step
+        );
+        assert_eq!(motion.target, 20.0);
+
+        motion.stop();
+        motion.resume();
+        assert!(!motion.is_running());
+    }
+
+    #[test]
+    fn test_motion_halt_resumes_sequence_at_the_same_step() {
+        let sequence = AnimationSequence::new()
+            .then(10.0f32, instant_tween())
+            .then(20.0, AnimationConfig::spring(Spring::default()))
+            .then(30.0, instant_tween());
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(sequence);
+        for _ in 0..5 {
+            motion.update(1.0 / 60.0);
+        }
+        let step = |motion: &Motion<f32>| motion.sequence.as_ref().map(|s| s.current_step());
+        let halted_step = step(&motion);
+        let value = motion.get_value();
+        assert!(motion.velocity > 0.0);
+
+        motion.halt();
+        assert!(motion.is_paused());
+        assert_eq!(motion.velocity, 0.0);
+        assert!(!motion.update(1.0 / 60.0));
+        assert_eq!(motion.get_value(), value);
+
+        motion.resume();
+        assert_eq!(step(&motion), halted_step);
+        assert_eq!(motion.target, 20.0);
+        while motion.update(1.0 / 60.0) {}
+        assert_eq!(motion.get_value(), 30.0);
+    }
+
+    #[test]
+    fn test_max_velocity_bounds_spring_step() {
+        const CAP: f32 = 600.0;
+        const DT: f32 = 1.0 / 60.0;
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            1000.0,
+            AnimationConfig::spring(Spring::default()).with_max_velocity(CAP),
+        );
+        motion.velocity = 1.0e6;
+
+        let mut previous = motion.get_value();
+        let mut frames = 0;
+        while motion.update(DT) {
+            let value = motion.get_value();
+            assert!(
+                (value - previous).abs() <= CAP * DT + 1e-3,
+                "moved {} in one frame",
+                value - previous
+            );
+            assert!(motion.velocity.abs() <= CAP + 1e-3);
+            previous = value;
+            frames += 1;
+            assert!(frames < 2000, "spring should settle");
+        }
+        assert_eq!(motion.get_value(), 1000.0);
+    }
+
+    #[test]
+    fn test_motion_is_send() {
+        fn assert_send<S: Send>() {}
+        assert_send::<Motion<f32>>();
+    }
+
+    #[test]
+    fn test_follow_chases_moving_target_smoothly() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        const DT: f32 = 1.0 / 60.0;
+        let source = Arc::new(AtomicU32::new(0.0f32.to_bits()));
+        let read = |source: &AtomicU32| f32::from_bits(source.load(Ordering::Relaxed));
+        let mut motion = Motion::new(0.0f32);
+        motion.follow(
+            {
+                let source = Arc::clone(&source);
+                move || read(&source)
+            },
+            Spring {
+                stiffness: 200.0,
+                damping: 28.0,
+                ..Spring::default()
+            },
+        );
+
+        let mut previous_step = 0.0f32;
+        let mut previous = motion.get_value();
+        for frame in 1..=240 {
+            let time = frame as f32 * DT;
+            source.store((100.0 * time.min(1.0)).to_bits(), Ordering::Relaxed);
+            assert!(motion.update(DT), "following never completes on its own");
+
+            let value = motion.get_value();
+            let step = value - previous;
+            assert!(
+                (step - previous_step).abs() < 0.5,
+                "velocity jumped at frame {frame}"
+            );
+            if frame == 30 {
+                assert!(value < read(&source) - 5.0, "spring should lag behind");
+                assert_eq!(motion.target, read(&source));
+            }
+            previous_step = step;
+            previous = value;
+        }
+
+        assert!((motion.get_value() - 100.0).abs() < 0.01);
+        assert!(motion.is_running());
+
+        motion.animate_to(0.0, AnimationConfig::tween_ms(100));
+        source.store(500.0f32.to_bits(), Ordering::Relaxed);
+        while motion.update(DT) {}
+        assert_eq!(motion.get_value(), 0.0);
+    }
+
+    #[test]
+    fn test_trail_keeps_most_recent_values_in_order() {
+        let mut motion = Motion::new(0.0f32);
+        assert!(motion.trail().is_empty());
+
+        motion.enable_trail(3);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(1000));
+        let mut seen = Vec::new();
+        for _ in 0..6 {
+            motion.update(0.1);
+            seen.push(motion.get_value());
+        }
+
+        assert_eq!(motion.trail(), seen[3..].to_vec());
+
+        while motion.update(0.1) {}
+        let trail = motion.trail();
+        assert_eq!(trail.len(), 3);
+        assert_eq!(trail.last(), Some(&100.0));
+        assert!(trail.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        motion.update(0.1);
+        assert_eq!(motion.trail(), trail, "idle frames are not recorded");
+
+        motion.enable_trail(0);
+        assert!(motion.trail().is_empty());
+    }
+
+    #[test]
+    fn test_animated_values_tick_together() {
+        use crate::prelude::Color;
+
+        let mut opacity = Motion::new(0.0f32);
+        opacity.animate_to(1.0, AnimationConfig::tween_ms(200));
+        let mut color = Motion::new(Color::new(0.0, 0.0, 0.0, 1.0));
+        color.animate_to(
+            Color::new(1.0, 1.0, 1.0, 1.0),
+            AnimationConfig::tween_ms(400),
+        );
+
+        let mut values: Vec<Box<dyn AnimatedValue>> = vec![Box::new(opacity), Box::new(color)];
+
+        let mut frames = 0;
+        let mut running = values.len();
+        while running > 0 {
+            running = values
+                .iter_mut()
+                .map(|value| value.update(1.0 / 64.0))
+                .filter(|still_running| *still_running)
+                .count();
+            frames += 1;
+            if frames == 20 {
+                assert!(!values[0].is_running());
+                assert!(values[1].is_running());
+            }
+            assert!(frames < 1000, "animations never finished");
+        }
+
+        assert!(values.iter().all(|value| !value.is_running()));
+    }
+
+    #[test]
+    fn test_animated_value_stop() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(500));
+        let mut values: Vec<Box<dyn AnimatedValue>> = vec![Box::new(motion)];
+
+        values.iter_mut().for_each(|value| value.stop());
+
+        assert!(!values[0].is_running());
+        assert!(!values[0].update(1.0 / 64.0));
     }
 }
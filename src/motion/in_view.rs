@@ -0,0 +1,335 @@
+//! Viewport-triggered motion.
+//!
+//! [`use_in_view_motion`] starts an animation when its element scrolls into view. On the
+//! web this uses an `IntersectionObserver`; other renderers have no viewport information,
+//! so the animation starts as soon as the element mounts.
+
+use dioxus::prelude::*;
+
+use crate::{
+    animations::core::{Animatable, AnimationConfig},
+    manager::{AnimationManager, MotionHandle},
+    use_motion,
+};
+
+/// When a [`use_in_view_motion`] element counts as in view, and whether it replays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InViewOptions {
+    threshold: f32,
+    once: bool,
+}
+
+impl Default for InViewOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 0.0,
+            once: true,
+        }
+    }
+}
+
+impl InViewOptions {
+    /// Triggers as soon as any part of the element is visible, and only once.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fraction of the element, clamped to `0.0..=1.0`, that must be visible to trigger.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = if threshold.is_finite() {
+            threshold.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self
+    }
+
+    /// With `false`, leaving the viewport animates back and re-entering plays again.
+    pub fn once(mut self, once: bool) -> Self {
+        self.once = once;
+        self
+    }
+
+    /// The configured visibility threshold.
+    pub fn get_threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Whether the animation only plays on the first entry.
+    pub fn is_once(&self) -> bool {
+        self.once
+    }
+
+    /// Decides how an intersection update changes the view state.
+    ///
+    /// Returns `Some(true)` to animate in, `Some(false)` to animate back out and `None`
+    /// when nothing should happen.
+    pub(crate) fn change(
+        &self,
+        state: InViewState,
+        intersecting: bool,
+        ratio: f32,
+    ) -> Option<bool> {
+        if self.once && state.triggered {
+            return None;
+        }
+
+        let visible = intersecting && ratio >= self.threshold;
+        (visible != state.visible).then_some(visible)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct InViewState {
+    visible: bool,
+    triggered: bool,
+}
+
+impl InViewState {
+    fn apply(&mut self, visible: bool) {
+        self.visible = visible;
+        self.triggered |= visible;
+    }
+}
+
+#[cfg(feature = "web")]
+struct Observer {
+    observer: web_sys::IntersectionObserver,
+    _callback: wasm_bindgen::closure::Closure<dyn FnMut(Vec<wasm_bindgen::JsValue>)>,
+}
+
+/// Attaches a [`use_in_view_motion`] animation to its element.
+pub struct InViewRef<T: Animatable + Send + 'static> {
+    handle: MotionHandle<T>,
+    initial: CopyValue<T>,
+    target: CopyValue<T>,
+    config: CopyValue<AnimationConfig>,
+    options: CopyValue<InViewOptions>,
+    state: CopyValue<InViewState>,
+    #[cfg(feature = "web")]
+    observer: CopyValue<Option<Observer>>,
+}
+
+impl<T: Animatable + Send + 'static> Clone for InViewRef<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Animatable + Send + 'static> Copy for InViewRef<T> {}
+
+impl<T: Animatable + Send + 'static> InViewRef<T> {
+    /// Starts watching the element; use as its `onmounted` handler.
+    pub fn mount(self, event: MountedEvent) {
+        #[cfg(feature = "web")]
+        if self.observe(&event.data()) {
+            return;
+        }
+        #[cfg(not(feature = "web"))]
+        let _ = event;
+
+        self.update(true, 1.0);
+    }
+
+    /// Whether the element is currently considered in view.
+    pub fn is_in_view(&self) -> bool {
+        self.state.peek().visible
+    }
+
+    fn update(mut self, intersecting: bool, ratio: f32) {
+        let Some(visible) = self
+            .options
+            .peek()
+            .change(*self.state.peek(), intersecting, ratio)
+        else {
+            return;
+        };
+        self.state.write().apply(visible);
+
+        let value = if visible {
+            self.target.peek().clone()
+        } else {
+            self.initial.peek().clone()
+        };
+        let mut handle = self.handle;
+        handle.animate_to(value, self.config.peek().clone());
+
+        // Runs inside the observer callback, so the closure must stay alive until unmount.
+        #[cfg(feature = "web")]
+        if visible && self.options.peek().is_once() {
+            if let Some(observer) = self.observer.peek().as_ref() {
+                observer.observer.disconnect();
+            }
+        }
+    }
+
+    #[cfg(feature = "web")]
+    fn observe(mut self, mounted: &std::rc::Rc<MountedData>) -> bool {
+        use dioxus::web::WebEventExt;
+        use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+
+        let Ok(element) = mounted
+            .as_ref()
+            .as_web_event()
+            .dyn_into::<web_sys::Element>()
+        else {
+            return false;
+        };
+
+        self.disconnect();
+        let callback = Closure::<dyn FnMut(Vec<JsValue>)>::new(move |entries: Vec<JsValue>| {
+            for entry in entries {
+                if let Ok(entry) = entry.dyn_into::<web_sys::IntersectionObserverEntry>() {
+                    self.update(entry.is_intersecting(), entry.intersection_ratio() as f32);
+                }
+            }
+        });
+
+        let init = web_sys::IntersectionObserverInit::new();
+        init.set_threshold_f64(f64::from(self.options.peek().get_threshold()));
+        let Ok(observer) = web_sys::IntersectionObserver::new_with_options(
+            callback.as_ref().unchecked_ref(),
+            &init,
+        ) else {
+            return false;
+        };
+
+        observer.observe(&element);
+        self.observer.set(Some(Observer {
+            observer,
+            _callback: callback,
+        }));
+        true
+    }
+
+    #[cfg(feature = "web")]
+    fn disconnect(mut self) {
+        if let Some(observer) = self.observer.write().take() {
+            observer.observer.disconnect();
+        }
+    }
+}
+
+/// Animates from `initial` to `target` when the element enters the viewport.
+///
+/// Attach the returned [`InViewRef`] with `onmounted: move |event| in_view.mount(event)`.
+/// On the web an `IntersectionObserver` watches the element and starts the animation once
+/// at least [`InViewOptions::threshold`] of it is visible. With
+/// [`InViewOptions::once`] set to `false`, leaving the viewport animates back to
+/// `initial` so the reveal replays on the next entry. The observer is disconnected after a
+/// one-shot reveal and when the component unmounts.
+///
+/// Renderers without an `IntersectionObserver` animate to `target` right after mount.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(feature = "dioxus")] {
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// #[component]
+/// fn Card(index: u64) -> Element {
+///     let config = AnimationConfig::tween_ms(400)
+///         .with_delay(Duration::from_millis(index * 80));
+///     let (reveal, in_view) =
+///         use_in_view_motion(0.0f32, 1.0, config, InViewOptions::new().threshold(0.25));
+///     let progress = reveal.get_value();
+///
+///     rsx! {
+///         div {
+///             style: "opacity: {progress}; transform: translateY({(1.0 - progress) * 24.0}px);",
+///             onmounted: move |event| in_view.mount(event),
+///             "Card {index}"
+///         }
+///     }
+/// }
+///
+/// fn app() -> Element {
+///     rsx! {
+///         for index in 0..12 {
+///             Card { key: "{index}", index: index % 3 }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+pub fn use_in_view_motion<T: Animatable + Send + 'static>(
+    initial: T,
+    target: T,
+    config: AnimationConfig,
+    options: InViewOptions,
+) -> (MotionHandle<T>, InViewRef<T>) {
+    let handle = use_motion(initial.clone());
+    let mut in_view = use_hook(|| InViewRef {
+        handle,
+        initial: CopyValue::new(initial.clone()),
+        target: CopyValue::new(target.clone()),
+        config: CopyValue::new(config.clone()),
+        options: CopyValue::new(options),
+        state: CopyValue::new(InViewState::default()),
+        #[cfg(feature = "web")]
+        observer: CopyValue::new(None),
+    });
+    in_view.initial.set(initial);
+    in_view.target.set(target);
+    in_view.config.set(config);
+    in_view.options.set(options);
+
+    #[cfg(feature = "web")]
+    use_drop(move || in_view.disconnect());
+
+    (handle, in_view)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InViewOptions, InViewState};
+
+    fn state(visible: bool, triggered: bool) -> InViewState {
+        InViewState { visible, triggered }
+    }
+
+    #[test]
+    fn threshold_is_clamped_and_gates_entry() {
+        assert_eq!(InViewOptions::new().threshold(1.5).get_threshold(), 1.0);
+        assert_eq!(InViewOptions::new().threshold(-1.0).get_threshold(), 0.0);
+        assert_eq!(
+            InViewOptions::new().threshold(f32::NAN).get_threshold(),
+            0.0
+        );
+
+        let options = InViewOptions::new().threshold(0.5);
+        assert_eq!(options.change(state(false, false), true, 0.25), None);
+        assert_eq!(options.change(state(false, false), true, 0.5), Some(true));
+        assert_eq!(options.change(state(false, false), false, 0.0), None);
+        assert_eq!(
+            InViewOptions::new().change(state(false, false), true, 0.0),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn once_ignores_updates_after_first_entry() {
+        let options = InViewOptions::new();
+        let mut current = InViewState::default();
+        assert_eq!(options.change(current, true, 1.0), Some(true));
+        current.apply(true);
+
+        assert_eq!(options.change(current, false, 0.0), None);
+        assert_eq!(options.change(current, true, 1.0), None);
+    }
+
+    #[test]
+    fn repeat_animates_out_and_back_in() {
+        let options = InViewOptions::new().once(false).threshold(0.5);
+        let mut current = InViewState::default();
+
+        assert_eq!(options.change(current, true, 0.6), Some(true));
+        current.apply(true);
+        assert_eq!(options.change(current, true, 0.8), None);
+        assert_eq!(options.change(current, true, 0.3), Some(false));
+        current.apply(false);
+        assert_eq!(options.change(current, true, 0.9), Some(true));
+    }
+}
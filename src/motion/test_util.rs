@@ -0,0 +1,129 @@
+//! Helpers for testing code that drives a [`Motion`], enabled by the `test-util` feature.
+//!
+//! ```rust
+//! # #[cfg(feature = "test-util")] {
+//! use dioxus_motion::motion::{Motion, test_util::assert_reaches};
+//! use dioxus_motion::prelude::AnimationConfig;
+//!
+//! let mut motion = Motion::new(0.0f32);
+//! motion.animate_to(100.0, AnimationConfig::tween_ms(300));
+//! assert_reaches(&mut motion, 100.0, 0.01);
+//! # }
+//! ```
+
+use crate::animations::core::Animatable;
+use crate::motion::Motion;
+
+/// Frame length used by [`assert_reaches`]: 60 frames per second
+pub const FRAME: f32 = 1.0 / 60.0;
+
+/// Frames [`assert_reaches`] runs before giving up, about three minutes at 60 fps
+pub const MAX_FRAMES: usize = 10_000;
+
+/// Returned by [`run_until_complete`] when the motion is still running after the limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("motion still running after {frames} frames")]
+pub struct TimeoutError {
+    /// Frames that were run before giving up
+    pub frames: usize,
+}
+
+/// Updates `motion` by `dt` until it stops, returning the number of frames it took.
+///
+/// A motion that is not running completes in zero frames. Infinite loops and
+/// followed targets never stop and return [`TimeoutError`] after `max_frames`.
+pub fn run_until_complete<T: Animatable + Send + 'static>(
+    motion: &mut Motion<T>,
+    dt: f32,
+    max_frames: usize,
+) -> Result<usize, TimeoutError> {
+    if !motion.is_running() {
+        return Ok(0);
+    }
+
+    for frame in 1..=max_frames {
+        if !motion.update(dt) {
+            return Ok(frame);
+        }
+    }
+
+    Err(TimeoutError { frames: max_frames })
+}
+
+/// Runs `motion` to completion at 60 fps and asserts it settled within `epsilon` of
+/// `target`.
+///
+/// # Panics
+///
+/// If the motion is still running after [`MAX_FRAMES`], or stops further than
+/// `epsilon` from `target`.
+#[track_caller]
+pub fn assert_reaches<T: Animatable + Send + std::fmt::Debug + 'static>(
+    motion: &mut Motion<T>,
+    target: T,
+    epsilon: f32,
+) {
+    let finished = run_until_complete(motion, FRAME, MAX_FRAMES);
+    assert!(
+        finished.is_ok(),
+        "motion still running after {MAX_FRAMES} frames; value is {:?}",
+        motion.get_value()
+    );
+
+    let value = motion.get_value();
+    let distance = (value.clone() - target.clone()).magnitude();
+    assert!(
+        distance <= epsilon,
+        "motion stopped at {value:?}, {distance} away from {target:?} (epsilon {epsilon})"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TimeoutError, assert_reaches, run_until_complete};
+    use crate::{
+        Motion,
+        animations::{core::LoopMode, spring::Spring},
+        prelude::AnimationConfig,
+    };
+
+    #[test]
+    fn tween_completes_in_its_frame_count() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(500));
+
+        assert_eq!(run_until_complete(&mut motion, 0.1, 100), Ok(5));
+        assert_eq!(run_until_complete(&mut motion, 0.1, 100), Ok(0));
+    }
+
+    #[test]
+    fn spring_reaches_its_target() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::spring(Spring::default()));
+
+        assert_reaches(&mut motion, 100.0, 0.01);
+    }
+
+    #[test]
+    fn infinite_loop_times_out() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::tween_ms(100).with_loop(LoopMode::Infinite),
+        );
+
+        assert_eq!(
+            run_until_complete(&mut motion, 1.0 / 60.0, 120),
+            Err(TimeoutError { frames: 120 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "away from")]
+    fn wrong_target_fails_the_assertion() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::tween_ms(100));
+
+        assert_reaches(&mut motion, 50.0, 0.01);
+    }
+}
@@ -9,7 +9,7 @@ use crate::animations::spring::Spring;
 use std::collections::HashMap;
 
 use std::any::{Any, TypeId};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 /// A pool for reusing AnimationConfig instances to reduce allocations
 pub struct ConfigPool {
@@ -347,6 +347,9 @@ pub struct SpringIntegratorPool<T: Animatable> {
     available: Vec<SpringIntegrator<T>>,
     in_use: HashMap<usize, SpringIntegrator<T>>,
     next_id: usize,
+    max_available: usize,
+    grow_factor: Option<f32>,
+    growth: PoolGrowth,
 }
 
 impl<T: Animatable> SpringIntegratorPool<T> {
@@ -357,16 +360,47 @@ impl<T: Animatable> SpringIntegratorPool<T> {
 
     /// Creates a new integrator pool with specified capacity
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_sizing(PoolSizing {
+            capacity,
+            max_size: usize::MAX,
+        })
+    }
+
+    /// Creates a new integrator pool with an initial capacity and a cap on idle integrators
+    ///
+    /// Integrators returned while `max_size` are already idle are dropped instead of kept.
+    pub fn with_sizing(sizing: PoolSizing) -> Self {
         Self {
-            available: Vec::with_capacity(capacity),
-            in_use: HashMap::with_capacity(capacity),
+            available: Vec::with_capacity(sizing.capacity),
+            in_use: HashMap::with_capacity(sizing.capacity),
             next_id: 0,
+            max_available: sizing.max_size,
+            grow_factor: None,
+            growth: PoolGrowth::default(),
         }
     }
 
+    /// Grows the pool geometrically whenever it runs dry
+    ///
+    /// On a miss, `factor - 1` times the integrators allocated so far are created at
+    /// once (at least one, and never more than the idle cap), so a burst of new springs
+    /// costs a handful of larger allocations instead of one per spring. A factor that is
+    /// not above 1.0 leaves the pool growing one at a time.
+    pub fn with_auto_grow(mut self, factor: f32) -> Self {
+        self.grow_factor = (factor.is_finite() && factor > 1.0).then_some(factor);
+        self
+    }
+
     /// Gets an integrator from the pool
     pub fn get_integrator(&mut self) -> SpringIntegratorHandle {
-        let mut integrator = self.available.pop().unwrap_or_default();
+        if self.available.is_empty() {
+            self.growth.miss_count += 1;
+            self.grow();
+        }
+        let mut integrator = self.available.pop().unwrap_or_else(|| {
+            self.growth.capacity += 1;
+            SpringIntegrator::default()
+        });
         integrator.reset(); // Ensure clean state
 
         let id = self.next_id;
@@ -378,7 +412,9 @@ impl<T: Animatable> SpringIntegratorPool<T> {
 
     /// Returns an integrator to the pool
     pub fn return_integrator(&mut self, handle: SpringIntegratorHandle) {
-        if let Some(integrator) = self.in_use.remove(&handle.id) {
+        if let Some(integrator) = self.in_use.remove(&handle.id)
+            && self.available.len() < self.max_available
+        {
             self.available.push(integrator);
         }
     }
@@ -396,6 +432,24 @@ impl<T: Animatable> SpringIntegratorPool<T> {
         (self.in_use.len(), self.available.len())
     }
 
+    /// Integrators allocated so far and how often the pool ran dry
+    pub fn growth(&self) -> PoolGrowth {
+        self.growth
+    }
+
+    fn grow(&mut self) {
+        let Some(factor) = self.grow_factor else {
+            return;
+        };
+        let wanted = (self.growth.capacity as f32 * (factor - 1.0)).ceil() as usize;
+        let room = self.max_available.saturating_sub(self.available.len());
+        let extra = wanted.max(1).min(room);
+
+        self.available
+            .extend(std::iter::repeat_with(SpringIntegrator::default).take(extra));
+        self.growth.capacity += extra;
+    }
+
     /// Clears the pool
     pub fn clear(&mut self) {
         self.available.clear();
@@ -421,6 +475,10 @@ pub struct GlobalIntegratorPools {
     pools: HashMap<TypeId, Box<dyn Any + Send>>,
     // Track stats separately since we can't easily downcast trait objects
     stats_tracker: HashMap<TypeId, (usize, usize)>,
+    growth_tracker: HashMap<TypeId, PoolGrowth>,
+    default_sizing: PoolSizing,
+    type_sizing: HashMap<TypeId, PoolSizing>,
+    grow_factor: Option<f32>,
 }
 
 impl Default for GlobalIntegratorPools {
@@ -431,27 +489,53 @@ impl Default for GlobalIntegratorPools {
 
 impl GlobalIntegratorPools {
     pub fn new() -> Self {
+        Self::with_config(&PoolConfig::default())
+    }
+
+    /// Creates pools sized by the integrator settings of `config`
+    pub fn with_config(config: &PoolConfig) -> Self {
         Self {
             pools: HashMap::new(),
             stats_tracker: HashMap::new(),
+            growth_tracker: HashMap::new(),
+            default_sizing: config.integrator_pool_sizing(),
+            type_sizing: config.integrator_type_sizing.clone(),
+            grow_factor: config.grow_factor,
         }
     }
 
+    /// Sizing used for the pool of type T
+    pub fn sizing_for(&self, type_id: TypeId) -> PoolSizing {
+        self.type_sizing
+            .get(&type_id)
+            .copied()
+            .unwrap_or(self.default_sizing)
+    }
+
     /// Gets or creates a pool for type T
     pub fn get_pool<T: Animatable + Send + 'static>(&mut self) -> &mut SpringIntegratorPool<T> {
         let type_id = TypeId::of::<T>();
+        let sizing = self.sizing_for(type_id);
+        let grow_factor = self.grow_factor;
 
         // Get or create the pool
         let pool = self
             .pools
             .entry(type_id)
-            .or_insert_with(|| Box::new(SpringIntegratorPool::<T>::new()))
+            .or_insert_with(|| {
+                let pool = SpringIntegratorPool::<T>::with_sizing(sizing);
+                Box::new(match grow_factor {
+                    Some(factor) => pool.with_auto_grow(factor),
+                    None => pool,
+                })
+            })
             .downcast_mut::<SpringIntegratorPool<T>>()
             .expect("Type mismatch in integrator pool");
 
         // Update stats tracker
         let stats = pool.stats();
         self.stats_tracker.insert(type_id, stats);
+        self.growth_tracker.insert(type_id, pool.growth());
 
         pool
     }
@@ -460,6 +544,7 @@ impl GlobalIntegratorPools {
     pub fn clear(&mut self) {
         self.pools.clear();
         self.stats_tracker.clear();
+        self.growth_tracker.clear();
     }
 
     /// Gets statistics for all pools
@@ -467,6 +552,19 @@ impl GlobalIntegratorPools {
         self.stats_tracker.clone()
     }
 
+    /// Gets the growth of every pool created so far
+    pub fn growth(&self) -> HashMap<TypeId, PoolGrowth> {
+        self.growth_tracker.clone()
+    }
+
+    /// Gets the sizing of every pool created so far
+    pub fn sizing(&self) -> HashMap<TypeId, PoolSizing> {
+        self.pools
+            .keys()
+            .map(|type_id| (*type_id, self.sizing_for(*type_id)))
+            .collect()
+    }
+
     /// Updates stats for a specific type (called when integrators are returned)
     pub fn update_stats<T: Animatable + Send + 'static>(&mut self) {
         let type_id = TypeId::of::<T>();
@@ -475,6 +573,7 @@ impl GlobalIntegratorPools {
         {
             let stats = pool.stats();
             self.stats_tracker.insert(type_id, stats);
+            self.growth_tracker.insert(type_id, pool.growth());
         }
     }
 }
@@ -526,12 +625,22 @@ impl MotionResourcePools {
         let (closure_in_use, closure_available) = (0, 0);
 
         // Get integrator stats from the global integrator pools
-        let integrator_stats = INTEGRATOR_POOLS.with(|pools| pools.borrow().stats());
+        let (integrator_stats, integrator_sizing, integrator_growth) =
+            INTEGRATOR_POOLS.with(|pools| {
+                let pools = pools.borrow();
+                (pools.stats(), pools.sizing(), pools.growth())
+            });
 
         PoolStats {
             config_pool: (config_in_use, config_available),
+            config_pool_sizing: PoolSizing {
+                capacity: self.config.config_pool_capacity,
+                max_size: self.config.max_config_pool_size,
+            },
             closure_pool: (closure_in_use, closure_available),
             integrator_pools: integrator_stats,
+            integrator_pool_sizing: integrator_sizing,
+            integrator_pool_growth: integrator_growth,
             total_memory_saved_bytes: self.estimate_memory_savings(),
         }
     }
@@ -601,6 +710,73 @@ pub struct PoolConfig {
     pub auto_maintain: bool,
     /// Interval for automatic maintenance (in animation frames)
     pub maintenance_interval: u32,
+    // Set through `with_integrator_pool_sizing` and `per_type_sizing`
+    integrator_pool_capacity: usize,
+    max_integrator_pool_size: usize,
+    integrator_type_sizing: HashMap<TypeId, PoolSizing>,
+    // Set through `with_auto_grow`
+    grow_factor: Option<f32>,
+}
+
+impl PoolConfig {
+    /// Sets the initial capacity and maximum idle size of every spring integrator pool
+    /// without a per-type override
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::{PoolConfig, resource_pools};
+    ///
+    /// resource_pools::configure(PoolConfig::default().with_integrator_pool_sizing(32, 128));
+    /// ```
+    pub fn with_integrator_pool_sizing(mut self, capacity: usize, max_size: usize) -> Self {
+        self.integrator_pool_capacity = capacity;
+        self.max_integrator_pool_size = max_size;
+        self
+    }
+
+    /// Sizes the spring integrator pool for `T` separately from the other types
+    ///
+    /// A color-heavy app can give `Color` a large pool while keeping the rest small.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Color;
+    /// use dioxus_motion::{PoolConfig, resource_pools};
+    ///
+    /// resource_pools::configure(PoolConfig::default().per_type_sizing::<Color>(64, 256));
+    /// ```
+    pub fn per_type_sizing<T: Animatable + 'static>(
+        mut self,
+        capacity: usize,
+        max_size: usize,
+    ) -> Self {
+        self.integrator_type_sizing
+            .insert(TypeId::of::<T>(), PoolSizing { capacity, max_size });
+        self
+    }
+
+    /// Makes integrator pools that run dry grow by `factor` at once instead of
+    /// allocating one integrator per miss. A factor that is not above 1.0 leaves pools
+    /// growing one at a time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::{PoolConfig, resource_pools};
+    ///
+    /// resource_pools::configure(PoolConfig::default().with_auto_grow(2.0));
+    /// ```
+    pub fn with_auto_grow(mut self, factor: f32) -> Self {
+        self.grow_factor = Some(factor);
+        self
+    }
+
+    /// Sizing for integrator pools without a per-type override
+    pub fn integrator_pool_sizing(&self) -> PoolSizing {
+        PoolSizing {
+            capacity: self.integrator_pool_capacity,
+            max_size: self.max_integrator_pool_size,
+        }
+    }
 }
 
 impl Default for PoolConfig {
@@ -611,10 +787,32 @@ impl Default for PoolConfig {
             target_config_pool_size: 32,
             auto_maintain: true,
             maintenance_interval: 1000, // Every ~16 seconds at 60fps
+            integrator_pool_capacity: 8,
+            max_integrator_pool_size: 32,
+            integrator_type_sizing: HashMap::new(),
+            grow_factor: None,
         }
     }
 }
 
+/// Initial capacity and maximum idle size of a single pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolSizing {
+    /// Capacity reserved when the pool is created
+    pub capacity: usize,
+    /// Maximum number of idle items kept for reuse
+    pub max_size: usize,
+}
+
+/// How far a pool has grown under load
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PoolGrowth {
+    /// Items allocated by the pool so far, idle or in use
+    pub capacity: usize,
+    /// Times an item was requested while none were idle
+    pub miss_count: usize,
+}
+
 /// Statistics about all resource pools
 #[derive(Debug, Clone)]
 pub struct PoolStats {
@@ -626,12 +824,36 @@ pub struct PoolStats {
     pub integrator_pools: HashMap<TypeId, (usize, usize)>,
     /// Estimated memory saved by pooling (in bytes)
     pub total_memory_saved_bytes: usize,
+    // Read through the accessors below
+    config_pool_sizing: PoolSizing,
+    integrator_pool_sizing: HashMap<TypeId, PoolSizing>,
+    integrator_pool_growth: HashMap<TypeId, PoolGrowth>,
+}
+
+impl PoolStats {
+    /// Configured capacity and maximum size of the config pool
+    pub fn config_pool_sizing(&self) -> PoolSizing {
+        self.config_pool_sizing
+    }
+
+    /// Configured capacity and maximum size of each integrator pool by type
+    pub fn integrator_pool_sizing(&self) -> &HashMap<TypeId, PoolSizing> {
+        &self.integrator_pool_sizing
+    }
+
+    /// Allocated capacity and miss count of each integrator pool by type
+    pub fn integrator_pool_growth(&self) -> &HashMap<TypeId, PoolGrowth> {
+        &self.integrator_pool_growth
+    }
 }
 
 // Thread-local resource pools
 thread_local! {
     static MOTION_RESOURCE_POOLS: RefCell<MotionResourcePools> = RefCell::new(MotionResourcePools::new());
     static INTEGRATOR_POOLS: RefCell<GlobalIntegratorPools> = RefCell::new(GlobalIntegratorPools::new());
+    // Frame-loop settings live outside `PoolConfig` so `configure` doesn't reset them
+    static DT_SMOOTHING: Cell<f32> = const { Cell::new(0.0) };
+    static GLOBAL_EPSILON: Cell<Option<f32>> = const { Cell::new(None) };
 }
 
 /// Global functions for integrator pool management
@@ -692,14 +914,21 @@ pub mod integrator {
 pub mod resource_pools {
     use super::*;
 
+    /// Strongest frame-time smoothing allowed by [`set_dt_smoothing`]
+    pub const MAX_DT_SMOOTHING: f32 = 0.9;
+
     /// Gets statistics for all resource pools
     pub fn stats() -> PoolStats {
         MOTION_RESOURCE_POOLS.with(|pools| pools.borrow().stats())
     }
 
     /// Configures the global resource pools
-    /// This should be called early in your application startup for optimal performance
+    /// This should be called early in your application startup for optimal performance.
+    /// Integrator pools are rebuilt with the new sizing, dropping any pooled integrators.
     pub fn configure(config: PoolConfig) {
+        INTEGRATOR_POOLS.with(|pools| {
+            *pools.borrow_mut() = GlobalIntegratorPools::with_config(&config);
+        });
         MOTION_RESOURCE_POOLS.with(|pools| {
             *pools.borrow_mut() = MotionResourcePools::with_config(config);
         });
@@ -708,25 +937,33 @@ pub mod resource_pools {
     /// Initializes resource pools with high-performance defaults
     /// Recommended for applications with many concurrent animations
     pub fn init_high_performance() {
-        configure(PoolConfig {
-            config_pool_capacity: 64,
-            max_config_pool_size: 256,
-            target_config_pool_size: 128,
-            auto_maintain: true,
-            maintenance_interval: 500, // More frequent maintenance
-        });
+        configure(
+            PoolConfig {
+                config_pool_capacity: 64,
+                max_config_pool_size: 256,
+                target_config_pool_size: 128,
+                auto_maintain: true,
+                maintenance_interval: 500, // More frequent maintenance
+                ..PoolConfig::default()
+            }
+            .with_integrator_pool_sizing(32, 128),
+        );
     }
 
     /// Initializes resource pools with memory-conservative defaults
     /// Recommended for memory-constrained environments
     pub fn init_memory_conservative() {
-        configure(PoolConfig {
-            config_pool_capacity: 8,
-            max_config_pool_size: 32,
-            target_config_pool_size: 16,
-            auto_maintain: true,
-            maintenance_interval: 2000, // Less frequent maintenance
-        });
+        configure(
+            PoolConfig {
+                config_pool_capacity: 8,
+                max_config_pool_size: 32,
+                target_config_pool_size: 16,
+                auto_maintain: true,
+                maintenance_interval: 2000, // Less frequent maintenance
+                ..PoolConfig::default()
+            }
+            .with_integrator_pool_sizing(4, 16),
+        );
     }
 
     /// Performs maintenance on all resource pools
@@ -736,6 +973,27 @@ pub mod resource_pools {
         });
     }
 
+    /// Restores every global pool and its configuration to the defaults
+    ///
+    /// Intended for test setup: pool state is thread-local and outlives individual
+    /// tests, so call this at the start of a test that configures or inspects the pools
+    /// to avoid seeing state left behind by an earlier test on the same thread. Pooled
+    /// configs and integrators are dropped; outstanding handles fall back to unpooled
+    /// values. Not meant for use while animations are running.
+    pub fn reset() {
+        CONFIG_POOL.with(|pool| {
+            *pool.borrow_mut() = ConfigPool::new();
+        });
+        INTEGRATOR_POOLS.with(|pools| {
+            *pools.borrow_mut() = GlobalIntegratorPools::new();
+        });
+        MOTION_RESOURCE_POOLS.with(|pools| {
+            *pools.borrow_mut() = MotionResourcePools::new();
+        });
+        DT_SMOOTHING.set(0.0);
+        GLOBAL_EPSILON.set(None);
+    }
+
     /// Clears all resource pools (primarily for testing)
     #[cfg(test)]
     pub fn clear_all() {
@@ -744,6 +1002,62 @@ pub mod resource_pools {
         });
     }
 
+    /// Smooths the frame time fed to animations with a low-pass filter
+    ///
+    /// Each frame's time step becomes `factor * average + (1 - factor) * dt`, where
+    /// `average` is the smoothed step of the previous frame. Noisy frame times from a
+    /// janky page then jitter spring velocities less. `factor` is clamped to
+    /// `0.0..=`[`MAX_DT_SMOOTHING`] so a genuine frame-rate change still comes through
+    /// within a few frames; `0.0`, the default, disables smoothing. The setting is kept
+    /// by [`configure`] and the `init_*` presets, and only [`reset`] clears it.
+    pub fn set_dt_smoothing(factor: f32) {
+        let factor = if factor.is_nan() {
+            0.0
+        } else {
+            factor.clamp(0.0, MAX_DT_SMOOTHING)
+        };
+        DT_SMOOTHING.set(factor);
+    }
+
+    /// The frame-time smoothing factor set by [`set_dt_smoothing`]
+    pub fn dt_smoothing() -> f32 {
+        DT_SMOOTHING.get()
+    }
+
+    /// Sets the completion epsilon for every motion that doesn't choose its own
+    ///
+    /// A motion settles once its distance to the target and its velocity are both
+    /// below its epsilon, which is looked up in this order:
+    ///
+    /// 1. the animation's own [`AnimationConfig::with_epsilon`]
+    /// 2. the motion's default from [`Motion::set_default_epsilon`](crate::motion::Motion::set_default_epsilon)
+    /// 3. this global epsilon
+    /// 4. the value type's [`Animatable::epsilon`]
+    ///
+    /// Use it to tighten completion app-wide, for example on high-DPI displays, instead
+    /// of setting an epsilon on every config. The global epsilon applies to every value
+    /// type alike, and as a single threshold it replaces per-component thresholds such as
+    /// [`Transform`](crate::prelude::Transform)'s.
+    ///
+    /// An epsilon that is zero, negative or not finite would stop motions from ever
+    /// settling, so it is ignored and the previous setting kept. Like
+    /// [`set_dt_smoothing`], the setting survives [`configure`] and the `init_*` presets.
+    pub fn set_global_epsilon(epsilon: f32) {
+        if epsilon.is_finite() && epsilon > 0.0 {
+            GLOBAL_EPSILON.set(Some(epsilon));
+        }
+    }
+
+    /// Removes the epsilon set by [`set_global_epsilon`], restoring the type defaults
+    pub fn clear_global_epsilon() {
+        GLOBAL_EPSILON.set(None);
+    }
+
+    /// The epsilon set by [`set_global_epsilon`], if any
+    pub fn global_epsilon() -> Option<f32> {
+        GLOBAL_EPSILON.get()
+    }
+
     /// Gets the current pool configuration
     pub fn get_config() -> PoolConfig {
         MOTION_RESOURCE_POOLS.with(|pools| pools.borrow().config.clone())
@@ -1131,6 +1445,7 @@ mod tests {
             target_config_pool_size: 64,
             auto_maintain: false,
             maintenance_interval: 500,
+            ..PoolConfig::default()
         };
 
         let pools = MotionResourcePools::with_config(config.clone());
@@ -1164,6 +1479,7 @@ mod tests {
             target_config_pool_size: 48,
             auto_maintain: true,
             maintenance_interval: 750,
+            ..PoolConfig::default()
         };
 
         resource_pools::configure(config.clone());
@@ -1184,6 +1500,105 @@ mod tests {
         resource_pools::maintain();
     }
 
+    #[test]
+    fn test_auto_grow_expands_geometrically_and_absorbs_bursts() {
+        let burst = |pool: &mut SpringIntegratorPool<f32>| {
+            let handles: Vec<_> = (0..64).map(|_| pool.get_integrator()).collect();
+            for handle in handles {
+                pool.return_integrator(handle);
+            }
+        };
+        let sizing = PoolSizing {
+            capacity: 0,
+            max_size: usize::MAX,
+        };
+
+        let mut growing = SpringIntegratorPool::<f32>::with_sizing(sizing).with_auto_grow(2.0);
+        let mut capacities = Vec::new();
+        for _ in 0..16 {
+            growing.get_integrator();
+            capacities.push(growing.growth().capacity);
+        }
+        assert_eq!(capacities[..5], [1, 2, 4, 4, 8]);
+        assert_eq!(capacities[15], 16);
+
+        let mut growing = SpringIntegratorPool::<f32>::with_sizing(sizing).with_auto_grow(2.0);
+        let mut single = SpringIntegratorPool::<f32>::with_sizing(sizing);
+        burst(&mut growing);
+        burst(&mut single);
+        assert_eq!(single.growth().miss_count, 64);
+        assert_eq!(growing.growth().miss_count, 7);
+
+        burst(&mut growing);
+        assert_eq!(growing.growth().miss_count, 7);
+    }
+
+    #[test]
+    fn test_dt_smoothing_is_off_by_default_and_clamped() {
+        resource_pools::reset();
+        assert_eq!(resource_pools::dt_smoothing(), 0.0);
+
+        resource_pools::set_dt_smoothing(0.5);
+        resource_pools::init_high_performance();
+        assert_eq!(
+            resource_pools::dt_smoothing(),
+            0.5,
+            "configure keeps the factor"
+        );
+        resource_pools::set_dt_smoothing(5.0);
+        assert_eq!(
+            resource_pools::dt_smoothing(),
+            resource_pools::MAX_DT_SMOOTHING
+        );
+        resource_pools::set_dt_smoothing(f32::NAN);
+        assert_eq!(resource_pools::dt_smoothing(), 0.0);
+        resource_pools::reset();
+    }
+
+    #[test]
+    fn test_global_epsilon_ignores_invalid_values_and_survives_configure() {
+        resource_pools::reset();
+        assert_eq!(resource_pools::global_epsilon(), None);
+
+        for invalid in [f32::NAN, 0.0, -0.01, f32::INFINITY] {
+            resource_pools::set_global_epsilon(invalid);
+            assert_eq!(resource_pools::global_epsilon(), None);
+        }
+
+        resource_pools::set_global_epsilon(0.002);
+        resource_pools::set_global_epsilon(f32::NAN);
+        assert_eq!(resource_pools::global_epsilon(), Some(0.002));
+
+        resource_pools::configure(PoolConfig::default());
+        resource_pools::init_memory_conservative();
+        assert_eq!(resource_pools::global_epsilon(), Some(0.002));
+        resource_pools::reset();
+        assert_eq!(resource_pools::global_epsilon(), None);
+    }
+
+    #[test]
+    fn test_auto_grow_is_reported_in_stats() {
+        resource_pools::reset();
+        resource_pools::configure(PoolConfig::default().with_auto_grow(2.0));
+
+        let handles: Vec<_> = (0..5)
+            .map(|_| integrator::get_integrator::<f32>())
+            .collect();
+        for handle in handles {
+            integrator::return_integrator::<f32>(handle);
+        }
+
+        let growth = resource_pools::stats().integrator_pool_growth()[&TypeId::of::<f32>()];
+        assert_eq!(
+            growth,
+            PoolGrowth {
+                capacity: 8,
+                miss_count: 4
+            }
+        );
+        resource_pools::reset();
+    }
+
     #[test]
     fn test_pool_config_default() {
         let config = PoolConfig::default();
@@ -1194,6 +1609,92 @@ mod tests {
         assert_eq!(config.maintenance_interval, 1000);
     }
 
+    #[test]
+    fn test_per_type_sizing_is_reflected_in_stats() {
+        use crate::animations::transform::Transform;
+        use crate::prelude::Color;
+
+        resource_pools::configure(
+            PoolConfig {
+                config_pool_capacity: 4,
+                max_config_pool_size: 12,
+                ..PoolConfig::default()
+            }
+            .with_integrator_pool_sizing(2, 1)
+            .per_type_sizing::<Color>(64, 256),
+        );
+
+        let color = integrator::get_integrator::<Color>();
+        let transform = integrator::get_integrator::<Transform>();
+        let transform_extra = integrator::get_integrator::<Transform>();
+
+        let stats = resource_pools::stats();
+        assert_eq!(
+            stats.config_pool_sizing(),
+            PoolSizing {
+                capacity: 4,
+                max_size: 12
+            }
+        );
+        assert_eq!(
+            stats.integrator_pool_sizing()[&TypeId::of::<Color>()],
+            PoolSizing {
+                capacity: 64,
+                max_size: 256
+            }
+        );
+        assert_eq!(
+            stats.integrator_pool_sizing()[&TypeId::of::<Transform>()],
+            PoolSizing {
+                capacity: 2,
+                max_size: 1
+            }
+        );
+
+        integrator::return_integrator::<Color>(color);
+        integrator::return_integrator::<Transform>(transform);
+        integrator::return_integrator::<Transform>(transform_extra);
+
+        assert_eq!(integrator::pool_stats::<Color>(), (0, 1));
+        assert_eq!(integrator::pool_stats::<Transform>(), (0, 1));
+
+        resource_pools::configure(PoolConfig::default());
+    }
+
+    #[test]
+    fn test_reset_restores_default_pools() {
+        use crate::prelude::Color;
+
+        resource_pools::init_high_performance();
+        let config = global::get_config();
+        let color = integrator::get_integrator::<Color>();
+        assert_eq!(global::pool_stats(), (1, 0));
+        assert_eq!(resource_pools::get_config().config_pool_capacity, 64);
+
+        resource_pools::reset();
+
+        let defaults = PoolConfig::default();
+        let restored = resource_pools::get_config();
+        assert_eq!(restored.config_pool_capacity, defaults.config_pool_capacity);
+        assert_eq!(restored.max_config_pool_size, defaults.max_config_pool_size);
+        assert_eq!(
+            restored.integrator_pool_sizing(),
+            defaults.integrator_pool_sizing()
+        );
+        assert_eq!(global::pool_stats(), (0, 0));
+        assert_eq!(integrator::pool_stats::<Color>(), (0, 0));
+        assert_eq!(
+            resource_pools::stats().config_pool_sizing(),
+            PoolSizing {
+                capacity: defaults.config_pool_capacity,
+                max_size: defaults.max_config_pool_size,
+            }
+        );
+
+        global::return_config(config);
+        integrator::return_integrator::<Color>(color);
+    }
+
     #[test]
     fn test_pool_stats_memory_estimation() {
         let pools = MotionResourcePools::new();
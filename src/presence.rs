@@ -1911,6 +1911,50 @@ where
 }
 
 /// Creates a motion handle with separate enter and exit transitions.
+///
+/// `enter_config` drives the move to `animate` and `exit_config` the move to `exit`,
+/// so a child can pop in quickly and fade out slowly.
+///
+/// If the child is shown again while its exit is still running, the same component
+/// instance is kept: the exit is abandoned, `safe_to_remove` is never called for it,
+/// and the motion animates back to `animate` with `enter_config` from wherever the
+/// exit had reached. `initial` is only used on the first mount.
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// #[component]
+/// fn Card() -> Element {
+///     let opacity = use_presence_motion_with_transitions(
+///         0.0f32,
+///         1.0,
+///         0.0,
+///         AnimationConfig::tween_ms(120),
+///         AnimationConfig::tween_ms(600),
+///     );
+///
+///     rsx! {
+///         div { style: "opacity: {opacity.get_value()}", "Pops in, fades out slowly" }
+///     }
+/// }
+///
+/// fn app() -> Element {
+///     let mut visible = use_signal(|| true);
+///     let card_key = "card";
+///
+///     rsx! {
+///         button { onclick: move |_| visible.toggle(), "Toggle" }
+///         AnimatePresence {
+///             if visible() {
+///                 Card { key: "{card_key}" }
+///             }
+///         }
+///     }
+/// }
+/// ```
 pub fn use_presence_motion_with_transitions<T>(
     initial: T,
     animate: T,
@@ -0,0 +1,224 @@
+//! Scroll-linked motion values.
+//!
+//! [`use_scroll_motion`] drives a [`MotionHandle`] from scroll position instead of time:
+//! the value is the scroll progress between `0.0` (top) and `1.0` (bottom) of the page or
+//! of a scrollable element.
+
+use dioxus::prelude::*;
+
+use crate::{
+    animations::core::AnimationConfig,
+    manager::{AnimationManager, MotionHandle},
+    use_motion,
+};
+
+/// Which scroll position drives a [`ScrollMotion`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScrollSource {
+    /// The document scroll position; listened to automatically with the `web` feature.
+    #[default]
+    Page,
+    /// A scrollable element that forwards its events through [`ScrollMotion::onscroll`].
+    Element,
+}
+
+/// Maps a scroll offset onto `0.0..=1.0` progress.
+///
+/// `scroll_top` is the distance scrolled, `scroll_height` the full content height and
+/// `client_height` the visible height. Content that does not scroll has zero progress.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::scroll::scroll_progress;
+///
+/// assert_eq!(scroll_progress(250.0, 1500.0, 500.0), 0.25);
+/// assert_eq!(scroll_progress(0.0, 400.0, 500.0), 0.0);
+/// ```
+pub fn scroll_progress(scroll_top: f64, scroll_height: f64, client_height: f64) -> f32 {
+    let range = scroll_height - client_height;
+    if !range.is_finite() || range <= 0.0 {
+        return 0.0;
+    }
+
+    (scroll_top / range).clamp(0.0, 1.0) as f32
+}
+
+/// Handle returned by [`use_scroll_motion`].
+#[derive(Clone, Copy)]
+pub struct ScrollMotion {
+    progress: MotionHandle<f32>,
+    smoothing: CopyValue<Option<AnimationConfig>>,
+}
+
+impl ScrollMotion {
+    /// Scroll progress between `0.0` and `1.0`, smoothed if a config was given.
+    pub fn get_value(&self) -> f32 {
+        self.progress.get_value()
+    }
+
+    /// The underlying progress motion, e.g. to [`MotionHandle::map`] it onto other values.
+    pub fn handle(&self) -> MotionHandle<f32> {
+        self.progress
+    }
+
+    /// Updates progress from a scrollable element; use as its `onscroll` handler.
+    pub fn onscroll(self, event: ScrollEvent) {
+        self.set_progress(scroll_progress(
+            event.scroll_top(),
+            f64::from(event.scroll_height()),
+            f64::from(event.client_height()),
+        ));
+    }
+
+    /// Jumps to `progress`, or springs toward it when smoothing is configured.
+    pub fn set_progress(self, progress: f32) {
+        let mut handle = self.progress;
+        match self.smoothing.peek().clone() {
+            Some(config) => handle.animate_to(progress, config),
+            None => {
+                handle.stop();
+                handle.set_current(progress);
+            }
+        }
+    }
+}
+
+/// Drives a motion value from scroll progress instead of time.
+///
+/// With [`ScrollSource::Page`] and the `web` feature, a passive `scroll` listener is added
+/// to the window when the component mounts and removed when it unmounts. Passive
+/// listeners never block the browser's scrolling, so the page stays smooth even while
+/// the value re-renders. For [`ScrollSource::Element`], forward the element's `onscroll`
+/// events to [`ScrollMotion::onscroll`]; this also works on desktop.
+///
+/// Scroll events are not debounced. Each one is a cheap store write, and writes that do
+/// not move the value past its epsilon skip the re-render entirely, so bursts of events
+/// collapse into at most one render per frame. Pass `smoothing` to spring toward the
+/// scroll value instead of jumping to it; every event simply retargets the animation.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(feature = "dioxus")] {
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn app() -> Element {
+///     let scroll = use_scroll_motion(
+///         ScrollSource::Page,
+///         Some(AnimationConfig::spring(Spring::default())),
+///     );
+///
+///     rsx! {
+///         div {
+///             style: "position: fixed; top: 0; left: 0; height: 4px; width: 100%; \
+///                     background: tomato; transform-origin: left; \
+///                     transform: scaleX({scroll.get_value()});",
+///         }
+///         div { style: "height: 300vh;", "Scroll me" }
+///     }
+/// }
+/// # }
+/// ```
+pub fn use_scroll_motion(source: ScrollSource, smoothing: Option<AnimationConfig>) -> ScrollMotion {
+    let progress = use_motion(0.0f32);
+    let mut config = use_hook(|| CopyValue::new(None));
+    config.set(smoothing);
+
+    let scroll = ScrollMotion {
+        progress,
+        smoothing: config,
+    };
+
+    #[cfg(feature = "web")]
+    {
+        let listener = use_hook(|| std::rc::Rc::new(std::cell::RefCell::new(None)));
+        let mounted = listener.clone();
+        use_effect(move || {
+            if source == ScrollSource::Page && mounted.borrow().is_none() {
+                *mounted.borrow_mut() = page_scroll::listen(scroll);
+            }
+        });
+        use_drop(move || {
+            if let Some(closure) = listener.borrow_mut().take() {
+                page_scroll::unlisten(&closure);
+            }
+        });
+    }
+    #[cfg(not(feature = "web"))]
+    let _ = source;
+
+    scroll
+}
+
+#[cfg(feature = "web")]
+mod page_scroll {
+    use wasm_bindgen::{JsCast, closure::Closure};
+
+    use super::{ScrollMotion, scroll_progress};
+
+    pub(super) type Listener = Closure<dyn FnMut()>;
+
+    fn page_progress() -> Option<f32> {
+        let window = web_sys::window()?;
+        let root = window.document()?.document_element()?;
+        Some(scroll_progress(
+            window.scroll_y().ok()?,
+            f64::from(root.scroll_height()),
+            f64::from(root.client_height()),
+        ))
+    }
+
+    pub(super) fn listen(scroll: ScrollMotion) -> Option<Listener> {
+        let window = web_sys::window()?;
+        let closure = Closure::<dyn FnMut()>::new(move || {
+            if let Some(progress) = page_progress() {
+                scroll.set_progress(progress);
+            }
+        });
+
+        let options = web_sys::AddEventListenerOptions::new();
+        options.set_passive(true);
+        window
+            .add_event_listener_with_callback_and_add_event_listener_options(
+                "scroll",
+                closure.as_ref().unchecked_ref(),
+                &options,
+            )
+            .ok()?;
+
+        if let Some(progress) = page_progress() {
+            scroll.set_progress(progress);
+        }
+
+        Some(closure)
+    }
+
+    pub(super) fn unlisten(closure: &Listener) {
+        if let Some(window) = web_sys::window() {
+            let _ = window
+                .remove_event_listener_with_callback("scroll", closure.as_ref().unchecked_ref());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scroll_progress;
+
+    #[test]
+    fn progress_maps_scroll_range_onto_unit_interval() {
+        assert_eq!(scroll_progress(0.0, 2000.0, 1000.0), 0.0);
+        assert_eq!(scroll_progress(500.0, 2000.0, 1000.0), 0.5);
+        assert_eq!(scroll_progress(1000.0, 2000.0, 1000.0), 1.0);
+    }
+
+    #[test]
+    fn progress_clamps_overscroll_and_handles_unscrollable_content() {
+        assert_eq!(scroll_progress(-40.0, 2000.0, 1000.0), 0.0);
+        assert_eq!(scroll_progress(1100.0, 2000.0, 1000.0), 1.0);
+        assert_eq!(scroll_progress(0.0, 800.0, 1000.0), 0.0);
+        assert_eq!(scroll_progress(10.0, 1000.0, 1000.0), 0.0);
+        assert_eq!(scroll_progress(10.0, f64::NAN, 1000.0), 0.0);
+    }
+}
@@ -3,6 +3,8 @@
 use crate::animations::core::Animatable;
 use crate::prelude::AnimationConfig;
 
+use futures_util::FutureExt;
+use std::future::Future;
 use std::sync::Mutex;
 use std::sync::{Arc, MutexGuard};
 
@@ -11,6 +13,8 @@ pub struct AnimationStep<T: Animatable> {
     pub target: T,
     pub config: Arc<AnimationConfig>,
     pub predicted_next: Option<T>,
+    /// Name of the step, set by [`AnimationSequence::then_tagged`]
+    pub tag: Option<Arc<str>>,
 }
 
 struct SequenceState {
@@ -87,7 +91,29 @@ impl<T: Animatable> AnimationSequence<T> {
     }
 
     /// Adds a new step to the sequence and returns a new sequence
-    pub fn then(mut self, target: T, config: AnimationConfig) -> Self {
+    pub fn then(self, target: T, config: AnimationConfig) -> Self {
+        self.push_step(target, config, None)
+    }
+
+    /// Adds a named step, so the motion can report which step is playing
+    ///
+    /// See [`Motion::current_step_tag`](crate::motion::Motion::current_step_tag).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::{AnimationConfig, AnimationSequence};
+    ///
+    /// let intro = AnimationSequence::new()
+    ///     .then_tagged("rise", 1.0f32, AnimationConfig::tween_ms(300))
+    ///     .then(0.8, AnimationConfig::tween_ms(100));
+    /// assert_eq!(intro.steps()[0].tag.as_deref(), Some("rise"));
+    /// assert_eq!(intro.steps()[1].tag, None);
+    /// ```
+    pub fn then_tagged(self, tag: &str, target: T, config: AnimationConfig) -> Self {
+        self.push_step(target, config, Some(Arc::from(tag)))
+    }
+
+    fn push_step(mut self, target: T, config: AnimationConfig, tag: Option<Arc<str>>) -> Self {
         let predicted_next = if self.steps.is_empty() {
             None
         } else {
@@ -100,12 +126,51 @@ impl<T: Animatable> AnimationSequence<T> {
             target,
             config: Arc::new(config),
             predicted_next,
+            tag,
         };
 
         self.steps.push(new_step);
         self
     }
 
+    /// Returns a sequence that plays this one backward, ending at `start`
+    ///
+    /// A sequence only stores its targets, so pass the value it starts from (the
+    /// motion's value when the forward sequence was played); it becomes the final
+    /// target. Each leg reuses the config of the forward leg it mirrors, so the outro
+    /// retraces the intro leg by leg. The completion callback is not copied.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::{AnimationConfig, AnimationSequence};
+    ///
+    /// let intro = AnimationSequence::new()
+    ///     .then(10.0f32, AnimationConfig::tween_ms(100))
+    ///     .then(20.0, AnimationConfig::tween_ms(200));
+    /// let outro = intro.reversed(0.0);
+    /// let targets: Vec<f32> = outro.steps().iter().map(|step| step.target).collect();
+    /// assert_eq!(targets, vec![10.0, 0.0]);
+    /// ```
+    pub fn reversed(&self, start: T) -> Self {
+        let targets = self
+            .steps
+            .iter()
+            .rev()
+            .skip(1)
+            .map(|step| step.target.clone())
+            .chain(std::iter::once(start));
+        let configs = self
+            .steps
+            .iter()
+            .rev()
+            .map(|step| step.config.as_ref().clone());
+
+        targets.zip(configs).fold(
+            Self::with_capacity(self.steps.len().min(u8::MAX as usize) as u8),
+            |sequence, (target, config)| sequence.then(target, config),
+        )
+    }
+
     /// Sets a completion callback
     pub fn on_complete<F: FnOnce() + Send + 'static>(self, f: F) -> Self {
         let mut state = self.lock_state();
@@ -114,6 +179,26 @@ impl<T: Animatable> AnimationSequence<T> {
         self
     }
 
+    /// Returns the sequence together with a future that resolves once it completes.
+    ///
+    /// Any completion callback already registered still runs first. The future also
+    /// resolves if the sequence is dropped before finishing, which happens when it is
+    /// interrupted by `animate_to`, another sequence, `stop` or `cancel_delay`.
+    #[cfg_attr(not(feature = "dioxus"), allow(dead_code))]
+    pub(crate) fn with_completion_future(self) -> (Self, impl Future<Output = ()>) {
+        let (sender, receiver) = futures_channel::oneshot::channel::<()>();
+        let mut state = self.lock_state();
+        let previous = state.on_complete.take();
+        state.on_complete = Some(Box::new(move || {
+            if let Some(callback) = previous {
+                callback();
+            }
+            let _ = sender.send(());
+        }));
+        drop(state);
+        (self, receiver.map(|_| ()))
+    }
+
     /// Advances to the next step in the sequence
     /// Returns true if advanced, false if already at the end
     pub fn advance_step(&self) -> bool {
@@ -157,6 +242,11 @@ impl<T: Animatable> AnimationSequence<T> {
         self.steps.get(current)
     }
 
+    /// Gets the tag of the current step, if it was added with [`Self::then_tagged`]
+    pub fn current_tag(&self) -> Option<&str> {
+        self.current_step_data()?.tag.as_deref()
+    }
+
     /// Gets all steps (for backward compatibility)
     pub fn steps(&self) -> &[AnimationStep<T>] {
         &self.steps
@@ -214,6 +304,7 @@ impl<T: Animatable> Default for AnimationSequence<T> {
 mod tests {
     #![allow(clippy::unwrap_used)]
     use super::*;
+    use crate::Duration;
     use crate::animations::core::AnimationMode;
     use crate::animations::spring::Spring;
     use std::sync::{Arc, Mutex};
@@ -227,6 +318,7 @@ mod tests {
                     Spring::default(),
                 ))),
                 predicted_next: None,
+                tag: None,
             },
             AnimationStep {
                 target: 20.0f32,
@@ -234,6 +326,7 @@ mod tests {
                     Spring::default(),
                 ))),
                 predicted_next: None,
+                tag: None,
             },
             AnimationStep {
                 target: 30.0f32,
@@ -241,6 +334,7 @@ mod tests {
                     Spring::default(),
                 ))),
                 predicted_next: None,
+                tag: None,
             },
         ];
 
@@ -273,6 +367,37 @@ mod tests {
         assert!(!sequence.is_complete());
     }
 
+    #[test]
+    fn reversed_retraces_targets_back_to_start() {
+        let sequence = AnimationSequence::new()
+            .then(10.0f32, AnimationConfig::tween_ms(100))
+            .then(20.0, AnimationConfig::tween_ms(200))
+            .then(30.0, AnimationConfig::tween_ms(300));
+
+        let reversed = sequence.reversed(0.0);
+        let targets: Vec<f32> = reversed.steps().iter().map(|step| step.target).collect();
+        assert_eq!(targets, vec![20.0, 10.0, 0.0]);
+
+        let durations: Vec<_> = reversed
+            .steps()
+            .iter()
+            .map(|step| step.config.get_duration())
+            .collect();
+        assert_eq!(
+            durations,
+            vec![
+                Duration::from_millis(300),
+                Duration::from_millis(200),
+                Duration::from_millis(100)
+            ]
+        );
+        assert_eq!(reversed.current_step_index(), 0);
+        assert_eq!(
+            AnimationSequence::<f32>::new().reversed(5.0).total_steps(),
+            0
+        );
+    }
+
     #[test]
     fn test_animation_sequence_builder_pattern() {
         let sequence = AnimationSequence::new()
@@ -312,6 +437,7 @@ mod tests {
                 Spring::default(),
             ))),
             predicted_next: None,
+            tag: None,
         }];
 
         let sequence = AnimationSequence::with_on_complete(steps, move || {
@@ -335,6 +461,7 @@ mod tests {
                 Spring::default(),
             ))),
             predicted_next: None,
+            tag: None,
         }];
 
         let sequence = AnimationSequence::with_on_complete(steps, move || {
@@ -369,6 +496,7 @@ mod tests {
                 Spring::default(),
             ))),
             predicted_next: None,
+            tag: None,
         }];
 
         let sequence1 = AnimationSequence::from_steps(steps);
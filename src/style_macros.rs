@@ -115,6 +115,18 @@ macro_rules! motion_style_assign {
     ($style:ident, transform_perspective, $value:expr) => {
         $style.perspective = ($value) as f32;
     };
+    ($style:ident, blur, $value:expr) => {
+        $style.blur = ($value) as f32;
+    };
+    ($style:ident, brightness, $value:expr) => {
+        $style.brightness = ($value) as f32;
+    };
+    ($style:ident, backdropBlur, $value:expr) => {
+        $style.backdrop_blur = ($value) as f32;
+    };
+    ($style:ident, backdrop_blur, $value:expr) => {
+        $style.backdrop_blur = ($value) as f32;
+    };
     ($style:ident, rotation, $value:expr) => {
         compile_error!("use `rotate` instead of `rotation`; `rotate` is expressed in degrees");
     };
@@ -0,0 +1,347 @@
+//! Timelines that start motions at absolute offsets.
+//!
+//! Where an [`AnimationSequence`](crate::sequence::AnimationSequence) chains steps of one
+//! value one after another, a [`Timeline`] schedules animations of many motions against a
+//! shared clock: "at 0.2s move X, at 0.5s change the color". Playing, pausing and seeking
+//! the timeline applies to every motion on it at once.
+
+use crate::{
+    Duration,
+    animations::core::{Animatable, AnimationConfig},
+    manager::{AnimationManager, MotionHandle},
+    motion::{Motion, SeekError},
+};
+
+/// One scheduled animation, type-erased so motions of different value types share a timeline.
+trait TimelineTrack {
+    /// Restarts the animation as it stands `elapsed` into the timeline.
+    fn seek(&mut self, elapsed: Duration) -> Result<(), SeekError>;
+    fn pause(&mut self);
+    fn resume(&mut self);
+}
+
+struct Track<T: Animatable + Send + 'static> {
+    handle: MotionHandle<T>,
+    at: Duration,
+    target: T,
+    config: AnimationConfig,
+    /// Value the motion had when the timeline first touched it, restored on every seek.
+    from: Option<T>,
+}
+
+impl<T: Animatable + Send + 'static> Track<T> {
+    /// Offset into the timeline at which the animation leaves its starting value.
+    fn start(&self) -> Duration {
+        self.at + self.config.delay
+    }
+
+    /// Starts `motion` from the recorded value so it plays as it would `elapsed` in.
+    fn start_at(&self, motion: &mut Motion<T>, elapsed: Duration) -> Result<(), SeekError> {
+        let start = self.start();
+        motion.current = self.from.clone().unwrap_or_else(|| motion.current.clone());
+        motion.animate_to(
+            self.target.clone(),
+            self.config
+                .clone()
+                .with_delay(start.saturating_sub(elapsed)),
+        );
+
+        if elapsed <= start {
+            return Ok(());
+        }
+        let duration = motion.remaining().ok_or(SeekError::SpringNotSeekable)?;
+        if duration.is_zero() {
+            return motion.seek(1.0);
+        }
+        motion.seek((elapsed - start).as_secs_f32() / duration.as_secs_f32())
+    }
+}
+
+impl<T: Animatable + Send + 'static> TimelineTrack for Track<T> {
+    fn seek(&mut self, elapsed: Duration) -> Result<(), SeekError> {
+        if self.from.is_none() {
+            self.from = Some(self.handle.get_value());
+        }
+        let mut handle = self.handle;
+        handle.write_motion(|motion| self.start_at(motion, elapsed))
+    }
+
+    fn pause(&mut self) {
+        self.handle.pause();
+    }
+
+    fn resume(&mut self) {
+        self.handle.resume();
+    }
+}
+
+/// Animations of several motions scheduled at absolute offsets on one clock.
+///
+/// Each [`Timeline::add`] starts a motion towards its target `at` a given time after the
+/// timeline begins; the motions then animate on their own frame loops, so they stay
+/// independent of each other. A motion's [`AnimationConfig::delay`] is added to its
+/// offset. Every motion starts from the value it had when the timeline was first played
+/// or seeked, and returns to it when the timeline restarts.
+///
+/// Add each motion once: a later entry for the same motion replaces the earlier one
+/// when the timeline plays.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "dioxus")] {
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn app() -> Element {
+///     let transform = use_motion(Transform::identity());
+///     let color = use_motion(Color::from_rgba(59, 130, 246, 255));
+///     let mut timeline = use_signal(move || {
+///         Timeline::new()
+///             .add(
+///                 Duration::ZERO,
+///                 transform,
+///                 Transform::identity().x(120.0).rotate_deg(90.0),
+///                 AnimationConfig::tween_ms(600),
+///             )
+///             .add(
+///                 Duration::from_millis(400),
+///                 color,
+///                 Color::from_rgba(236, 72, 153, 255),
+///                 AnimationConfig::tween_ms(400),
+///             )
+///     });
+///
+///     let t = transform.get_value();
+///     let (r, g, b, _) = color.get_value().to_rgba();
+///     rsx! {
+///         div {
+///             style: "transform: translateX({t.x}px) rotate({t.rotation}rad); background: rgb({r}, {g}, {b})",
+///             onmouseenter: move |_| timeline.write().play(),
+///             onmouseleave: move |_| timeline.write().pause(),
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Timeline {
+    tracks: Vec<Box<dyn TimelineTrack>>,
+    playing: bool,
+    started: bool,
+}
+
+impl Timeline {
+    /// Creates an empty timeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Schedules `handle` to animate to `target` with `config`, starting `at` into the timeline.
+    pub fn add<T: Animatable + Send + 'static>(
+        mut self,
+        at: Duration,
+        handle: MotionHandle<T>,
+        target: T,
+        config: AnimationConfig,
+    ) -> Self {
+        self.tracks.push(Box::new(Track {
+            handle,
+            at,
+            target,
+            config,
+            from: None,
+        }));
+        self
+    }
+
+    /// Plays the timeline, resuming where it was paused or starting from the beginning.
+    pub fn play(&mut self) {
+        if self.started {
+            self.resume();
+        } else {
+            self.started = true;
+            self.playing = true;
+            // Nothing has started yet, so no motion is past its offset and none can fail.
+            for track in &mut self.tracks {
+                let _ = track.seek(Duration::ZERO);
+            }
+        }
+    }
+
+    /// Plays the timeline again from the beginning.
+    pub fn restart(&mut self) {
+        self.started = false;
+        self.play();
+    }
+
+    /// Freezes every motion on the timeline, including ones still waiting for their offset.
+    pub fn pause(&mut self) {
+        if self.playing {
+            self.playing = false;
+            self.tracks.iter_mut().for_each(|track| track.pause());
+        }
+    }
+
+    /// Continues a paused timeline.
+    pub fn resume(&mut self) {
+        if !self.playing {
+            self.playing = true;
+            self.tracks.iter_mut().for_each(|track| track.resume());
+        }
+    }
+
+    /// Whether the timeline is playing rather than paused or not yet started.
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Jumps every motion to where it would be `elapsed` into the timeline.
+    ///
+    /// Motions whose offset is still ahead return to their starting value and wait out
+    /// the rest of it; the others jump into their tween. A paused timeline stays paused.
+    /// Springs have no fixed duration, so seeking past the start of a spring returns
+    /// [`SeekError::SpringNotSeekable`] after the other motions have been seeked.
+    pub fn seek(&mut self, elapsed: Duration) -> Result<(), SeekError> {
+        self.started = true;
+        let mut result = Ok(());
+        for track in &mut self.tracks {
+            if let Err(error) = track.seek(elapsed) {
+                result = Err(error);
+            }
+            if !self.playing {
+                track.pause();
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use dioxus::prelude::{Element, ScopeId, VNode, VirtualDom};
+
+    use super::Timeline;
+    use crate::{
+        Duration,
+        manager::{AnimationManager, MotionHandle},
+        motion::SeekError,
+        prelude::{AnimationConfig, Color, Spring, Transform},
+        use_motion,
+    };
+
+    type HostedMotions = Rc<RefCell<Option<(MotionHandle<Transform>, MotionHandle<Color>)>>>;
+
+    #[allow(non_snake_case)]
+    fn TimelineHost(hosted: HostedMotions) -> Element {
+        let transform = use_motion(Transform::new(0.0, 0.0, 1.0, 0.0));
+        let color = use_motion(Color::BLACK);
+        *hosted.borrow_mut() = Some((transform, color));
+        VNode::empty()
+    }
+
+    fn frames(handles: &mut (MotionHandle<Transform>, MotionHandle<Color>), n: usize) {
+        for _ in 0..n {
+            handles.0.update(0.05);
+            handles.1.update(0.05);
+        }
+    }
+
+    #[test]
+    fn motions_start_at_their_scheduled_offsets() {
+        let hosted = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(TimelineHost, Rc::clone(&hosted));
+        dom.rebuild_in_place();
+        let mut handles = hosted.borrow().expect("host should render");
+        let (transform, color) = handles;
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut timeline = Timeline::new()
+                .add(
+                    Duration::from_millis(200),
+                    transform,
+                    Transform::new(100.0, 0.0, 1.0, 0.0),
+                    AnimationConfig::tween_ms(500),
+                )
+                .add(
+                    Duration::from_millis(500),
+                    color,
+                    Color::WHITE,
+                    AnimationConfig::tween_ms(500),
+                );
+            timeline.play();
+
+            // 0.15s in: neither motion has reached its offset.
+            frames(&mut handles, 3);
+            assert_eq!(transform.get_value().x, 0.0);
+            assert_eq!(color.get_value(), Color::BLACK);
+
+            // 0.45s in: the transform is moving, the color is still waiting.
+            frames(&mut handles, 6);
+            assert!(transform.get_value().x > 0.0);
+            assert_eq!(color.get_value(), Color::BLACK);
+
+            // 0.65s in: both are moving.
+            frames(&mut handles, 4);
+            assert!(color.get_value().r > 0.0);
+
+            timeline.pause();
+            let paused = transform.get_value().x;
+            frames(&mut handles, 4);
+            assert_eq!(transform.get_value().x, paused);
+            assert!(!timeline.is_playing());
+
+            // Seeking to 0.45s puts the transform halfway through its first half
+            // and sends the color back to its starting value.
+            timeline
+                .seek(Duration::from_millis(450))
+                .expect("tweens seek");
+            assert!((transform.get_value().x - 50.0).abs() < 1e-3);
+            assert_eq!(color.get_value(), Color::BLACK);
+
+            timeline.resume();
+            frames(&mut handles, 1);
+            assert_eq!(color.get_value(), Color::BLACK);
+            frames(&mut handles, 3);
+            assert!(color.get_value().r > 0.0);
+
+            frames(&mut handles, 20);
+            assert_eq!(transform.get_value().x, 100.0);
+            assert_eq!(color.get_value(), Color::WHITE);
+        });
+    }
+
+    #[test]
+    fn seeking_past_a_spring_start_is_an_error() {
+        let hosted = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(TimelineHost, Rc::clone(&hosted));
+        dom.rebuild_in_place();
+        let (transform, color) = hosted.borrow().expect("host should render");
+
+        dom.in_scope(ScopeId::ROOT, || {
+            let mut timeline = Timeline::new()
+                .add(
+                    Duration::ZERO,
+                    transform,
+                    Transform::new(100.0, 0.0, 1.0, 0.0),
+                    AnimationConfig::spring(Spring::default()),
+                )
+                .add(
+                    Duration::ZERO,
+                    color,
+                    Color::WHITE,
+                    AnimationConfig::tween_ms(1000),
+                );
+
+            assert_eq!(
+                timeline.seek(Duration::from_millis(500)),
+                Err(SeekError::SpringNotSeekable)
+            );
+            // The tween is still seeked.
+            assert!((color.get_value().r - 0.5).abs() < 1e-3);
+            assert!(timeline.seek(Duration::ZERO).is_ok());
+        });
+    }
+}
@@ -13,6 +13,47 @@ pub struct TransitionConfig {
     // For the page that's entering (TO)
     pub enter_start: Transform, // Starting position of entering page
     pub enter_end: Transform,   // Final position of entering page
+
+    exit_opacity: f32,  // Final opacity of exiting page
+    enter_opacity: f32, // Starting opacity of entering page
+}
+
+impl TransitionConfig {
+    /// Creates a config in which the leaving page fades out and the entering page fades in
+    pub const fn new(
+        exit_start: Transform,
+        exit_end: Transform,
+        enter_start: Transform,
+        enter_end: Transform,
+    ) -> Self {
+        Self {
+            exit_start,
+            exit_end,
+            enter_start,
+            enter_end,
+            exit_opacity: 0.0,
+            enter_opacity: 0.0,
+        }
+    }
+
+    /// Sets the opacity the leaving page ends at and the entering page starts from
+    pub const fn with_opacity(self, exit_opacity: f32, enter_opacity: f32) -> Self {
+        Self {
+            exit_opacity,
+            enter_opacity,
+            ..self
+        }
+    }
+
+    /// Final opacity of the leaving page
+    pub const fn exit_opacity(&self) -> f32 {
+        self.exit_opacity
+    }
+
+    /// Starting opacity of the entering page
+    pub const fn enter_opacity(&self) -> f32 {
+        self.enter_opacity
+    }
 }
 
 #[derive(PartialEq, Clone)]
@@ -75,6 +116,31 @@ pub enum TransitionVariant {
     SlideFadeRotate,
     ScaleFadeFlip,
     RotateScaleSlide,
+
+    /// User-defined states: the entering page starts at `enter_from` with
+    /// `enter_opacity`, the leaving page ends at `exit_to` with `exit_opacity`, and
+    /// both rest at the identity transform, fully opaque
+    ///
+    /// The outlet renders translation (in percent of the page), scale, rotation, the
+    /// X/Y-axis rotations and perspective of each [`Transform`], and the opacity.
+    /// Declare it as a `const` to name it in `#[transition(...)]`:
+    ///
+    /// ```rust
+    /// use dioxus_motion::prelude::{Transform, TransitionVariant};
+    ///
+    /// const DROP_IN: TransitionVariant = TransitionVariant::Custom {
+    ///     enter_from: Transform::new(0.0, -20.0, 0.9, 0.0),
+    ///     enter_opacity: 0.0,
+    ///     exit_to: Transform::new(0.0, 10.0, 1.0, 0.0),
+    ///     exit_opacity: 0.0,
+    /// };
+    /// ```
+    Custom {
+        enter_from: Transform,
+        enter_opacity: f32,
+        exit_to: Transform,
+        exit_opacity: f32,
+    },
 }
 
 // Helper functions to reduce repetition
@@ -85,6 +151,8 @@ fn slide_horizontal(exit_x: f32, enter_x: f32) -> TransitionConfig {
         exit_end: Transform::new(exit_x, 0.0, 1.0, 0.0),
         enter_start: Transform::new(enter_x, 0.0, 1.0, 0.0),
         enter_end: identity,
+        exit_opacity: 0.0,
+        enter_opacity: 0.0,
     }
 }
 
@@ -95,6 +163,8 @@ fn slide_vertical(exit_y: f32, enter_y: f32) -> TransitionConfig {
         exit_end: Transform::new(0.0, exit_y, 1.0, 0.0),
         enter_start: Transform::new(0.0, enter_y, 1.0, 0.0),
         enter_end: identity,
+        exit_opacity: 0.0,
+        enter_opacity: 0.0,
     }
 }
 
@@ -105,16 +175,21 @@ fn scale_transition(exit_scale: f32, enter_scale: f32) -> TransitionConfig {
         exit_end: Transform::new(0.0, 0.0, exit_scale, 0.0),
         enter_start: Transform::new(0.0, 0.0, enter_scale, 0.0),
         enter_end: identity,
+        exit_opacity: 0.0,
+        enter_opacity: 0.0,
     }
 }
 
+/// Rotations are in degrees; [`Transform`] stores radians.
 fn rotate_transition(exit_rotation: f32, enter_rotation: f32) -> TransitionConfig {
     let identity = Transform::identity();
     TransitionConfig {
         exit_start: identity,
-        exit_end: Transform::new(0.0, 0.0, 1.0, exit_rotation),
-        enter_start: Transform::new(0.0, 0.0, 1.0, enter_rotation),
+        exit_end: Transform::identity().rotate_deg(exit_rotation),
+        enter_start: Transform::identity().rotate_deg(enter_rotation),
         enter_end: identity,
+        exit_opacity: 0.0,
+        enter_opacity: 0.0,
     }
 }
 
@@ -125,6 +200,8 @@ fn slide_diagonal(exit_x: f32, exit_y: f32, enter_x: f32, enter_y: f32) -> Trans
         exit_end: Transform::new(exit_x, exit_y, 1.0, 0.0),
         enter_start: Transform::new(enter_x, enter_y, 1.0, 0.0),
         enter_end: identity,
+        exit_opacity: 0.0,
+        enter_opacity: 0.0,
     }
 }
 
@@ -145,6 +222,8 @@ impl TransitionVariant {
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),
                 enter_start: Transform::new(0.0, 0.0, 1.0, 0.0),
                 enter_end: identity,
+                exit_opacity: 0.0,
+                enter_opacity: 0.0,
             },
 
             // Scale transitions
@@ -206,6 +285,20 @@ impl TransitionVariant {
             TransitionVariant::SlideFadeRotate => slide_vertical(0.0, 0.0),
             TransitionVariant::ScaleFadeFlip => scale_transition(1.0, 0.0),
             TransitionVariant::RotateScaleSlide => rotate_transition(0.0, 0.0),
+
+            TransitionVariant::Custom {
+                enter_from,
+                enter_opacity,
+                exit_to,
+                exit_opacity,
+            } => TransitionConfig {
+                exit_start: identity,
+                exit_end: *exit_to,
+                enter_start: *enter_from,
+                enter_end: identity,
+                exit_opacity: *exit_opacity,
+                enter_opacity: *enter_opacity,
+            },
         }
     }
 }
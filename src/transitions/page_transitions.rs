@@ -14,6 +14,7 @@ use crate::{
 
 use super::config::TransitionVariant;
 use crate::animations::core::Animatable;
+use crate::animations::transform::interpolate_rotation;
 use crate::prelude::Transform;
 use wide::f32x4;
 
@@ -54,6 +55,77 @@ impl<R: Routable + PartialEq> AnimatedRouterContext<R> {
     }
 }
 
+/// Which way a route transition moves through the layout hierarchy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionDirection {
+    /// Navigating to a deeper route.
+    Forward,
+    /// Navigating to a shallower route.
+    Backward,
+    /// Navigating between routes at the same depth.
+    Lateral,
+}
+
+impl TransitionDirection {
+    /// Direction of a navigation from `from_depth` to `to_depth`.
+    pub fn between(from_depth: usize, to_depth: usize) -> Self {
+        match to_depth.cmp(&from_depth) {
+            std::cmp::Ordering::Greater => Self::Forward,
+            std::cmp::Ordering::Less => Self::Backward,
+            std::cmp::Ordering::Equal => Self::Lateral,
+        }
+    }
+}
+
+/// How far the active route transition has come, read with [`use_transition_progress`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransitionProgress {
+    /// Fraction of the transition completed, from `0.0` to `1.0`.
+    pub progress: f32,
+    /// Direction of the active transition, or `None` when idle.
+    pub direction: Option<TransitionDirection>,
+}
+
+impl TransitionProgress {
+    /// No transition running: progress is complete and there is no direction.
+    pub const IDLE: Self = Self {
+        progress: 1.0,
+        direction: None,
+    };
+
+    /// Returns true while a route transition is running.
+    pub fn is_active(&self) -> bool {
+        self.direction.is_some()
+    }
+
+    /// Progress of the incoming page animating from `start` towards `end`.
+    ///
+    /// Springs may overshoot the destination, so the result is clamped to `0.0..=1.0`.
+    fn measure(
+        start: PageTransitionAnimation,
+        end: PageTransitionAnimation,
+        current: PageTransitionAnimation,
+        direction: TransitionDirection,
+    ) -> Self {
+        let total = (end - start).magnitude();
+        let progress = if total > 0.0 {
+            (1.0 - (end - current).magnitude() / total).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        Self {
+            progress,
+            direction: Some(direction),
+        }
+    }
+}
+
+impl Default for TransitionProgress {
+    fn default() -> Self {
+        Self::IDLE
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct PageTransitionAnimation {
     pub x: f32,
@@ -61,6 +133,9 @@ pub struct PageTransitionAnimation {
     pub scale: f32,
     pub rotation: f32,
     pub opacity: f32,
+    rotation_x: f32,
+    rotation_y: f32,
+    perspective: f32,
 }
 
 impl PageTransitionAnimation {
@@ -71,20 +146,48 @@ impl PageTransitionAnimation {
             scale: transform.scale,
             rotation: transform.rotation,
             opacity,
+            rotation_x: transform.rotation_x(),
+            rotation_y: transform.rotation_y(),
+            perspective: transform.perspective(),
         }
     }
     pub fn from_exit_start(config: &super::config::TransitionConfig) -> Self {
         Self::from_transform_and_opacity(&config.exit_start, 1.0)
     }
     pub fn from_exit_end(config: &super::config::TransitionConfig) -> Self {
-        Self::from_transform_and_opacity(&config.exit_end, 0.0)
+        Self::from_transform_and_opacity(&config.exit_end, config.exit_opacity())
     }
     pub fn from_enter_start(config: &super::config::TransitionConfig) -> Self {
-        Self::from_transform_and_opacity(&config.enter_start, 0.0)
+        Self::from_transform_and_opacity(&config.enter_start, config.enter_opacity())
     }
     pub fn from_enter_end(config: &super::config::TransitionConfig) -> Self {
         Self::from_transform_and_opacity(&config.enter_end, 1.0)
     }
+
+    /// The `transform` and `opacity` declarations the outlet renders for this state.
+    ///
+    /// Translation is in percent of the page. Rotations are emitted in degrees, with
+    /// perspective and the X/Y-axis rotations only when they are non-zero.
+    fn style(&self) -> String {
+        let mut transform = String::new();
+        if self.perspective > 0.0 {
+            transform.push_str(&format!("perspective({}px) ", self.perspective));
+        }
+        transform.push_str(&format!("translate3d({}% , {}%, 0)", self.x, self.y));
+        if self.rotation_x != 0.0 {
+            transform.push_str(&format!(" rotateX({}deg)", self.rotation_x.to_degrees()));
+        }
+        if self.rotation_y != 0.0 {
+            transform.push_str(&format!(" rotateY({}deg)", self.rotation_y.to_degrees()));
+        }
+        transform.push_str(&format!(
+            " rotate({}deg) scale({})",
+            self.rotation.to_degrees(),
+            self.scale
+        ));
+
+        format!("transform: {transform}; opacity: {};", self.opacity)
+    }
 }
 
 impl Default for PageTransitionAnimation {
@@ -95,6 +198,9 @@ impl Default for PageTransitionAnimation {
             scale: 1.0, // Default scale to 1.0 for identity
             rotation: 0.0,
             opacity: 1.0, // Default to fully opaque
+            rotation_x: 0.0,
+            rotation_y: 0.0,
+            perspective: 0.0,
         }
     }
 }
@@ -109,6 +215,9 @@ impl std::ops::Add for PageTransitionAnimation {
             scale: self.scale + other.scale,
             rotation: self.rotation + other.rotation,
             opacity: self.opacity + other.opacity,
+            rotation_x: self.rotation_x + other.rotation_x,
+            rotation_y: self.rotation_y + other.rotation_y,
+            perspective: self.perspective + other.perspective,
         }
     }
 }
@@ -123,6 +232,9 @@ impl std::ops::Sub for PageTransitionAnimation {
             scale: self.scale - other.scale,
             rotation: self.rotation - other.rotation,
             opacity: self.opacity - other.opacity,
+            rotation_x: self.rotation_x - other.rotation_x,
+            rotation_y: self.rotation_y - other.rotation_y,
+            perspective: self.perspective - other.perspective,
         }
     }
 }
@@ -137,6 +249,9 @@ impl std::ops::Mul<f32> for PageTransitionAnimation {
             scale: self.scale * factor,
             rotation: self.rotation * factor,
             opacity: self.opacity * factor,
+            rotation_x: self.rotation_x * factor,
+            rotation_y: self.rotation_y * factor,
+            perspective: self.perspective * factor,
         }
     }
 }
@@ -151,21 +266,17 @@ impl Animatable for PageTransitionAnimation {
         let result = va + (vb - va) * vt;
         let out = result.to_array();
 
-        // Rotation: shortest path
-        let mut rotation_diff = target.rotation - self.rotation;
-        if rotation_diff > std::f32::consts::PI {
-            rotation_diff -= 2.0 * std::f32::consts::PI;
-        } else if rotation_diff < -std::f32::consts::PI {
-            rotation_diff += 2.0 * std::f32::consts::PI;
-        }
-        let rotation = self.rotation + rotation_diff * t;
-
         Self {
             x: out[0],
             y: out[1],
             scale: out[2],
-            rotation,
+            rotation: interpolate_rotation(self.rotation, target.rotation, t),
             opacity: out[3],
+            rotation_x: interpolate_rotation(self.rotation_x, target.rotation_x, t),
+            rotation_y: interpolate_rotation(self.rotation_y, target.rotation_y, t),
+            perspective: self
+                .perspective
+                .interpolate(&target.perspective, t.clamp(0.0, 1.0)),
         }
     }
 
@@ -174,11 +285,64 @@ impl Animatable for PageTransitionAnimation {
             + self.y * self.y
             + self.scale * self.scale
             + self.rotation * self.rotation
-            + self.opacity * self.opacity)
+            + self.opacity * self.opacity
+            + self.rotation_x * self.rotation_x
+            + self.rotation_y * self.rotation_y
+            + self.perspective * self.perspective)
             .sqrt()
     }
 }
 
+/// Nesting information an [`AnimatedOutlet`] shares with the outlets rendered below it.
+struct AnimatedOutletScope<R: Routable + PartialEq + 'static> {
+    router: Store<AnimatedRouterContext<R>>,
+    level: usize,
+}
+
+impl<R: Routable + PartialEq + 'static> Clone for AnimatedOutletScope<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: Routable + PartialEq + 'static> Copy for AnimatedOutletScope<R> {}
+
+impl<R: AnimatableRoute> AnimatedOutletScope<R> {
+    /// Returns true while this outlet is rendering a route transition.
+    fn is_animating(&self) -> bool {
+        match &*self.router.read() {
+            AnimatedRouterContext::FromTo(from, to) => {
+                outlet_should_animate(from.get_layout_depth(), to.get_layout_depth(), self.level)
+            }
+            AnimatedRouterContext::Settled(_) => false,
+        }
+    }
+}
+
+/// Decides whether an outlet at `level` should animate a `from_depth` to `to_depth` navigation.
+fn outlet_should_animate(from_depth: usize, to_depth: usize, level: usize) -> bool {
+    // Determine if the transition involves the root route (depth 1)
+    let involves_root = from_depth == 1 || to_depth == 1;
+
+    // Check if the depth hasn't changed and the outlet level matches
+    let is_same_depth_and_matching_level = from_depth == to_depth && level == to_depth;
+
+    involves_root || is_same_depth_and_matching_level
+}
+
+/// Decides whether a possibly nested outlet should animate, given its parent's state.
+///
+/// A nested outlet never animates while its parent outlet is already animating,
+/// because the parent transition moves the nested content along with it.
+fn nested_outlet_should_animate(
+    from_depth: usize,
+    to_depth: usize,
+    level: usize,
+    parent_animating: bool,
+) -> bool {
+    !parent_animating && outlet_should_animate(from_depth, to_depth, level)
+}
+
 #[component]
 /// Renders an outlet that supports animated transitions between routes.
 ///
@@ -186,12 +350,30 @@ impl Animatable for PageTransitionAnimation {
 /// determine when an animated transition should occur. When a transition is detected and
 /// the layout depth or route conditions are met, it renders a transition component; otherwise,
 /// it renders a standard outlet.
+///
+/// Outlets may be nested inside layouts rendered by another `AnimatedOutlet`, and only one
+/// of them animates a given navigation:
+/// - When only the child route changes, the parent layout stays put and the innermost
+///   outlet animates between the child routes.
+/// - When the parent route changes, the parent outlet animates the whole subtree and the
+///   nested outlets render their new content without a second, competing transition.
 pub fn AnimatedOutlet<R: AnimatableRoute>() -> Element {
     let route = use_route::<R>();
-    // Create router context only if we're the root AnimatedOutlet
+    // Read the enclosing outlet (if any) before providing our own scope
+    let parent_scope = try_use_context::<AnimatedOutletScope<R>>();
     let mut prev_route = use_store(|| AnimatedRouterContext::Settled(route.clone()));
     use_context_provider(move || prev_route);
 
+    use_shared_transition_progress();
+
+    let outlet: OutletContext<R> = use_outlet_context();
+    // Get the current level of nesting in the outlet
+    let current_level = outlet.level();
+    use_context_provider(move || AnimatedOutletScope {
+        router: prev_route,
+        level: current_level,
+    });
+
     use_effect(move || {
         if prev_route.peek().target_route() != &use_route::<R>() {
             prev_route
@@ -200,30 +382,22 @@ pub fn AnimatedOutlet<R: AnimatableRoute>() -> Element {
         }
     });
 
-    let outlet: OutletContext<R> = use_outlet_context();
-
     let from_route: Option<(R, R)> = match prev_route() {
         AnimatedRouterContext::FromTo(from, to) => Some((from, to)),
         _ => None,
     };
 
     if let Some((from, to)) = from_route {
-        // Get the layout depth of both the previous and current routes
-        let from_depth = from.get_layout_depth();
-        let to_depth = to.get_layout_depth();
-
-        // Get the current level of nesting in the outlet
-        let current_level = outlet.level();
-
-        // Determine if the transition involves the root route (depth 1)
-        let involves_root = from_depth == 1 || to_depth == 1;
-
-        // Check if the depth hasn't changed and the outlet level matches
-        let is_same_depth_and_matching_level = from_depth == to_depth && current_level == to_depth;
+        let parent_animating = parent_scope.is_some_and(|scope| scope.is_animating());
 
         // If we're transitioning from/to root, or the outlet is at the same depth,
         // render the animated transition between routes
-        if involves_root || is_same_depth_and_matching_level {
+        if nested_outlet_should_animate(
+            from.get_layout_depth(),
+            to.get_layout_depth(),
+            current_level,
+            parent_animating,
+        ) {
             return rsx! {
                 FromRouteToCurrent::<R> {
                     route_type: PhantomData,
@@ -250,13 +424,82 @@ pub trait AnimatableRoute: Routable + Clone + PartialEq {
 }
 
 /// Shortcut to get access to the [AnimatedRouterContext].
+///
+/// Use [`use_transition_progress`] to follow how far the current transition has come.
 pub fn use_animated_router<Route: Routable + PartialEq>() -> Store<AnimatedRouterContext<Route>> {
     use_context()
 }
 
+/// Reads the progress and direction of the route transition an [`AnimatedOutlet`] is running.
+///
+/// Reports [`TransitionProgress::IDLE`] while no transition is active. Call it in any
+/// component inside the outlet, or in the layout that renders the outlet, to drive a
+/// progress bar or loading shimmer alongside the page transition.
+///
+/// # Examples
+/// ```rust,ignore
+/// let progress = use_transition_progress();
+/// rsx! {
+///     if progress().is_active() {
+///         div { class: "progress-bar", style: "width: {progress().progress * 100.0}%" }
+///     }
+///     AnimatedOutlet::<Route> {}
+/// }
+/// ```
+pub fn use_transition_progress() -> ReadSignal<TransitionProgress> {
+    use_shared_transition_progress().into()
+}
+
+/// Returns the progress signal of the enclosing outlet or layout, providing one if none exists.
+///
+/// Sharing the signal down the tree lets a layout observe the transition run by the
+/// outlet it renders, and lets nested outlets report into the same signal.
+fn use_shared_transition_progress() -> Signal<TransitionProgress> {
+    use_hook(|| {
+        try_consume_context::<Signal<TransitionProgress>>()
+            .unwrap_or_else(|| provide_context(Signal::new(TransitionProgress::IDLE)))
+    })
+}
+
 // Add a type alias for the resolver
 pub type TransitionVariantResolver<R> = Rc<dyn Fn(&R, &R) -> TransitionVariant>;
 
+/// Builds a resolver that picks a slide direction from each route's depth.
+///
+/// Navigating to a deeper route slides left, navigating to a shallower route slides
+/// right, and routes at the same depth fade. When `depth` returns `None` for either
+/// route, the destination route's declared transition is used instead. `depth` may
+/// capture state, such as a table of route orders built at startup.
+///
+/// # Examples
+/// ```rust,ignore
+/// let resolver = transition_resolver_by_depth(|route: &Route| match route {
+///     Route::Home {} => Some(0),
+///     Route::Docs {} => Some(1),
+///     _ => None,
+/// });
+/// use_context_provider(move || resolver);
+/// ```
+pub fn transition_resolver_by_depth<R: AnimatableRoute>(
+    depth: impl Fn(&R) -> Option<i32> + 'static,
+) -> TransitionVariantResolver<R> {
+    Rc::new(move |from, to| {
+        directional_transition(depth(from), depth(to)).unwrap_or_else(|| to.get_transition())
+    })
+}
+
+fn directional_transition(
+    from_depth: Option<i32>,
+    to_depth: Option<i32>,
+) -> Option<TransitionVariant> {
+    let (from_depth, to_depth) = (from_depth?, to_depth?);
+    Some(match to_depth.cmp(&from_depth) {
+        std::cmp::Ordering::Greater => TransitionVariant::SlideLeft,
+        std::cmp::Ordering::Less => TransitionVariant::SlideRight,
+        std::cmp::Ordering::Equal => TransitionVariant::Fade,
+    })
+}
+
 fn default_transition_spring() -> Spring {
     Spring {
         stiffness: 160.0,
@@ -288,6 +531,11 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
     let mut from_anim = use_motion(PageTransitionAnimation::from_exit_start(&config));
     let mut to_anim = use_motion(PageTransitionAnimation::from_enter_start(&config));
     let default_spring = use_store(default_transition_spring);
+    let mut transition_progress = use_shared_transition_progress();
+    let direction = TransitionDirection::between(from.get_layout_depth(), to.get_layout_depth());
+    let enter_start = PageTransitionAnimation::from_enter_start(&config);
+    let enter_end = PageTransitionAnimation::from_enter_end(&config);
+    use_drop(move || transition_progress.set(TransitionProgress::IDLE));
 
     // Try to get a store-backed animation mode from context, otherwise use the default spring.
     let tween_store = try_use_context::<Store<Tween>>();
@@ -307,8 +555,18 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
         );
     });
 
+    use_effect(move || {
+        transition_progress.set(TransitionProgress::measure(
+            enter_start,
+            enter_end,
+            to_anim.get_value(),
+            direction,
+        ));
+    });
+
     use_effect(move || {
         if !from_anim.is_running() && !to_anim.is_running() {
+            transition_progress.set(TransitionProgress::IDLE);
             animated_router.write().settle();
         }
     });
@@ -323,16 +581,16 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
             div {
                 class: "route-content from",
                 style: format!(
-                    "transform: translate3d({}% , {}%, 0) scale({}); opacity: {}; will-change: transform, opacity; backface-visibility: hidden; -webkit-backface-visibility: hidden; contain: layout style;",
-                    from_val.x, from_val.y, from_val.scale, from_val.opacity
+                    "{} will-change: transform, opacity; backface-visibility: hidden; -webkit-backface-visibility: hidden; contain: layout style;",
+                    from_val.style()
                 ),
                 {from.render(from.get_layout_depth() + 1)}
             }
             div {
                 class: "route-content to",
                 style: format!(
-                    "transform: translate3d({}% , {}%, 0) scale({}); opacity: {}; will-change: transform, opacity; backface-visibility: hidden; -webkit-backface-visibility: hidden;",
-                    to_val.x, to_val.y, to_val.scale, to_val.opacity
+                    "{} will-change: transform, opacity; backface-visibility: hidden; -webkit-backface-visibility: hidden;",
+                    to_val.style()
                 ),
                 Outlet::<R> {}
             }
@@ -344,10 +602,20 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
 mod tests {
     use std::{cell::RefCell, rc::Rc};
 
-    use dioxus::prelude::{Element, Store, VNode, VirtualDom, use_hook, use_store};
+    use dioxus::prelude::{
+        Element, ReadSignal, ScopeId, Signal, Store, VNode, VirtualDom, WritableExt, use_hook,
+        use_store,
+    };
     use instant::Duration;
 
-    use super::{AnimationMode, Spring, Tween, default_transition_spring, resolve_transition_mode};
+    use super::{
+        AnimationConfig, AnimationMode, PageTransitionAnimation, Spring, Transform,
+        TransitionDirection, TransitionProgress, TransitionVariant, Tween,
+        default_transition_spring, directional_transition, nested_outlet_should_animate,
+        outlet_should_animate, resolve_transition_mode, use_shared_transition_progress,
+        use_transition_progress,
+    };
+    use crate::Motion;
 
     #[derive(Clone)]
     struct ResolveModeProps {
@@ -434,4 +702,233 @@ mod tests {
 
         assert_eq!(mode, AnimationMode::Spring(default_spring));
     }
+
+    #[test]
+    fn custom_variant_drives_outlet_states() {
+        let variant = TransitionVariant::Custom {
+            enter_from: Transform::new(0.0, -20.0, 0.9, 0.0),
+            enter_opacity: 0.25,
+            exit_to: Transform::new(10.0, 0.0, 1.1, 0.0),
+            exit_opacity: 0.5,
+        };
+        let config = variant.get_config();
+
+        let enter_start = PageTransitionAnimation::from_enter_start(&config);
+        assert_eq!((enter_start.y, enter_start.scale), (-20.0, 0.9));
+        assert_eq!(enter_start.opacity, 0.25);
+        let exit_end = PageTransitionAnimation::from_exit_end(&config);
+        assert_eq!((exit_end.x, exit_end.scale), (10.0, 1.1));
+        assert_eq!(exit_end.opacity, 0.5);
+
+        let at_rest = PageTransitionAnimation::default();
+        assert_eq!(PageTransitionAnimation::from_exit_start(&config), at_rest);
+        assert_eq!(PageTransitionAnimation::from_enter_end(&config), at_rest);
+        // Built-in variants keep fading fully out and in.
+        assert_eq!(TransitionVariant::Fade.get_config().enter_opacity(), 0.0);
+    }
+
+    #[test]
+    fn outlet_style_renders_rotation_and_3d_parts() {
+        let flat = PageTransitionAnimation::from_transform_and_opacity(
+            &Transform::new(-100.0, 0.0, 0.5, std::f32::consts::FRAC_PI_2),
+            0.25,
+        );
+        assert_eq!(
+            flat.style(),
+            "transform: translate3d(-100% , 0%, 0) rotate(90deg) scale(0.5); opacity: 0.25;"
+        );
+
+        let flip = PageTransitionAnimation::from_transform_and_opacity(
+            &Transform::identity()
+                .rotate_x(std::f32::consts::PI)
+                .rotate_y(std::f32::consts::PI)
+                .with_perspective(800.0),
+            1.0,
+        );
+        assert_eq!(
+            flip.style(),
+            "transform: perspective(800px) translate3d(0% , 0%, 0) rotateX(180deg) rotateY(180deg) rotate(0deg) scale(1); opacity: 1;"
+        );
+
+        // Built-in rotations are declared in degrees
+        let rotate =
+            PageTransitionAnimation::from_exit_end(&TransitionVariant::RotateLeft.get_config());
+        assert!(rotate.style().contains("rotate(90deg)"));
+    }
+
+    #[test]
+    fn directional_transition_follows_depth_changes() {
+        assert!(matches!(
+            directional_transition(Some(0), Some(1)),
+            Some(TransitionVariant::SlideLeft)
+        ));
+        assert!(matches!(
+            directional_transition(Some(2), Some(1)),
+            Some(TransitionVariant::SlideRight)
+        ));
+        assert!(matches!(
+            directional_transition(Some(1), Some(1)),
+            Some(TransitionVariant::Fade)
+        ));
+    }
+
+    #[test]
+    fn directional_transition_defers_unknown_depths() {
+        assert!(directional_transition(None, Some(1)).is_none());
+        assert!(directional_transition(Some(1), None).is_none());
+    }
+
+    #[test]
+    fn outlet_animates_root_and_matching_level_changes() {
+        // Leaving or entering a root route animates at any level.
+        assert!(outlet_should_animate(1, 2, 1));
+        assert!(outlet_should_animate(2, 1, 2));
+        // Sibling routes animate only in the outlet at their depth.
+        assert!(outlet_should_animate(2, 2, 2));
+        assert!(!outlet_should_animate(2, 2, 1));
+        assert!(!outlet_should_animate(2, 3, 2));
+    }
+
+    #[test]
+    fn nested_outlet_defers_to_animating_parent() {
+        // Parent route changed: the parent animates, the nested outlet does not.
+        assert!(outlet_should_animate(1, 2, 1));
+        assert!(!nested_outlet_should_animate(1, 2, 2, true));
+
+        // Only the child route changed: the parent is settled, the nested outlet animates.
+        assert!(!outlet_should_animate(2, 2, 1));
+        assert!(nested_outlet_should_animate(2, 2, 2, false));
+    }
+
+    #[test]
+    fn transition_progress_advances_then_returns_to_idle() {
+        let config = TransitionVariant::SlideLeft.get_config();
+        let start = PageTransitionAnimation::from_enter_start(&config);
+        let end = PageTransitionAnimation::from_enter_end(&config);
+        let direction = TransitionDirection::between(1, 2);
+        assert_eq!(direction, TransitionDirection::Forward);
+
+        let mut motion = Motion::new(start);
+        motion.animate_to(end, AnimationConfig::tween_ms(300));
+        let mut progress = TransitionProgress::measure(start, end, motion.get_value(), direction);
+        assert_eq!(progress.progress, 0.0);
+        assert!(progress.is_active());
+
+        while motion.update(1.0 / 60.0) {
+            let next = TransitionProgress::measure(start, end, motion.get_value(), direction);
+            assert!(next.progress >= progress.progress);
+            progress = next;
+        }
+        let finished = TransitionProgress::measure(start, end, motion.get_value(), direction);
+        assert_eq!(finished.progress, 1.0);
+
+        // Once the outlet settles, the progress goes back to idle.
+        assert!(!TransitionProgress::IDLE.is_active());
+        assert_eq!(TransitionProgress::default(), TransitionProgress::IDLE);
+        assert_eq!(TransitionProgress::IDLE.progress, 1.0);
+    }
+
+    type HostedProgress =
+        Rc<RefCell<Option<(ReadSignal<TransitionProgress>, Signal<TransitionProgress>)>>>;
+
+    #[allow(non_snake_case)]
+    fn ProgressHost(hosted: HostedProgress) -> Element {
+        // The layout reads the progress; the outlet it renders reports into the same signal.
+        let progress = use_transition_progress();
+        let outlet = use_shared_transition_progress();
+        *hosted.borrow_mut() = Some((progress, outlet));
+        VNode::empty()
+    }
+
+    #[test]
+    fn progress_hook_reads_the_outlet_signal_and_starts_idle() {
+        let hosted = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(ProgressHost, Rc::clone(&hosted));
+        dom.rebuild_in_place();
+        let (progress, mut outlet) = hosted.borrow().expect("host should render");
+
+        dom.in_scope(ScopeId::ROOT, || {
+            assert_eq!(progress(), TransitionProgress::IDLE);
+
+            let halfway = TransitionProgress {
+                progress: 0.5,
+                direction: Some(TransitionDirection::Backward),
+            };
+            outlet.set(halfway);
+            assert_eq!(progress(), halfway);
+
+            outlet.set(TransitionProgress::IDLE);
+            assert!(!progress().is_active());
+        });
+    }
+
+    #[allow(non_upper_case_globals)]
+    mod derived_routes {
+        use dioxus::prelude::*;
+
+        use crate::prelude::{MotionTransitions, Transform, TransitionVariant};
+        use crate::transitions::page_transitions::AnimatableRoute;
+
+        const DropIn: TransitionVariant = TransitionVariant::Custom {
+            enter_from: Transform::new(0.0, -20.0, 0.9, 0.0),
+            enter_opacity: 0.0,
+            exit_to: Transform::new(0.0, 10.0, 1.0, 0.0),
+            exit_opacity: 0.0,
+        };
+        const rise_Up: TransitionVariant = TransitionVariant::SlideUp;
+        const SETTLE: TransitionVariant = TransitionVariant::ZoomIn;
+
+        #[derive(Routable, Clone, Debug, PartialEq, MotionTransitions)]
+        enum Route {
+            #[route("/")]
+            #[transition(SlideDown)]
+            Landing {},
+            #[route("/drop")]
+            #[transition(DropIn)]
+            Dropped {},
+            #[route("/rise")]
+            #[transition(rise_Up)]
+            Risen {},
+            #[route("/settle")]
+            #[transition(SETTLE)]
+            Settled {},
+            #[route("/plain")]
+            Plain {},
+        }
+
+        #[component]
+        fn Landing() -> Element {
+            VNode::empty()
+        }
+
+        #[component]
+        fn Dropped() -> Element {
+            VNode::empty()
+        }
+
+        #[component]
+        fn Risen() -> Element {
+            VNode::empty()
+        }
+
+        #[component]
+        fn Settled() -> Element {
+            VNode::empty()
+        }
+
+        #[component]
+        fn Plain() -> Element {
+            VNode::empty()
+        }
+
+        #[test]
+        fn derive_resolves_variants_and_constants_of_any_case() {
+            let resolved = |route: Route| route.get_transition();
+            assert!(resolved(Route::Landing {}) == TransitionVariant::SlideDown);
+            assert!(resolved(Route::Dropped {}) == DropIn);
+            assert!(resolved(Route::Risen {}) == TransitionVariant::SlideUp);
+            assert!(resolved(Route::Settled {}) == TransitionVariant::ZoomIn);
+            assert!(resolved(Route::Plain {}) == TransitionVariant::Fade);
+        }
+    }
 }